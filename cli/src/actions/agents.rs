@@ -0,0 +1,122 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grid_sdk::protocol::pike::payload::{
+    Action, CreateAgentAction, DeleteAgentAction, PikePayloadBuilder, UpdateAgentAction,
+};
+use serde::Deserialize;
+
+use crate::actions::submit_pike_payload;
+use crate::error::CliError;
+use crate::http;
+
+#[derive(Debug, Deserialize)]
+pub struct AgentSlice {
+    pub public_key: String,
+    pub org_id: String,
+    pub active: bool,
+    pub roles: Vec<String>,
+    pub metadata: Vec<KeyValueEntrySlice>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeyValueEntrySlice {
+    pub key: String,
+    pub value: String,
+}
+
+pub fn do_create_agent(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    create_agent: CreateAgentAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::CreateAgent)
+        .with_create_agent(create_agent)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_update_agent(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    update_agent: UpdateAgentAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::UpdateAgent)
+        .with_update_agent(update_agent)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_delete_agent(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    delete_agent: DeleteAgentAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::DeleteAgent)
+        .with_delete_agent(delete_agent)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_list_agents(url: &str) -> Result<(), CliError> {
+    let agents: Vec<AgentSlice> = http::get(url, "/agent")?;
+
+    println!(
+        "{:<66} {:<20} {:<6} ROLES",
+        "PUBLIC_KEY", "ORG_ID", "ACTIVE"
+    );
+    for agent in agents {
+        println!(
+            "{:<66} {:<20} {:<6} {}",
+            agent.public_key,
+            agent.org_id,
+            agent.active,
+            agent.roles.join(",")
+        );
+    }
+
+    Ok(())
+}
+
+/// Fetches the agent with the given public key from the Grid daemon
+pub fn fetch_agent(url: &str, public_key: &str) -> Result<AgentSlice, CliError> {
+    http::get(url, &format!("/agent/{}", public_key))
+}
+
+pub fn do_show_agent(url: &str, public_key: &str) -> Result<(), CliError> {
+    let agent = fetch_agent(url, public_key)?;
+
+    println!("Public Key: {}", agent.public_key);
+    println!("Organization: {}", agent.org_id);
+    println!("Active: {}", agent.active);
+    println!("Roles: {}", agent.roles.join(", "));
+    println!("Metadata:");
+    for entry in agent.metadata {
+        println!("  {}: {}", entry.key, entry.value);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod agents;
+pub mod organizations;
+
+use grid_sdk::protocol::pike::payload::PikePayload;
+use sawtooth_sdk::signing;
+
+use crate::error::CliError;
+use crate::http::submit_batch_list;
+use crate::key::load_signing_key;
+use crate::transaction::pike_batch_list;
+
+/// Signs a Pike payload with the named key and submits it to the daemon at `url`
+fn submit_pike_payload(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    payload: PikePayload,
+) -> Result<(), CliError> {
+    let private_key = load_signing_key(key)?;
+    let context = signing::create_context("secp256k1")?;
+    let factory = signing::CryptoFactory::new(&*context);
+    let signer = factory.new_signer(&private_key);
+
+    let batch_list = pike_batch_list(payload, &signer)?;
+
+    submit_batch_list(url, &batch_list, wait)
+}
@@ -0,0 +1,107 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grid_sdk::protocol::pike::payload::{
+    Action, CreateOrganizationAction, DeleteOrganizationAction, PikePayloadBuilder,
+    UpdateOrganizationAction,
+};
+use serde::Deserialize;
+
+use crate::actions::agents::KeyValueEntrySlice;
+use crate::actions::submit_pike_payload;
+use crate::error::CliError;
+use crate::http;
+
+#[derive(Debug, Deserialize)]
+pub struct OrganizationSlice {
+    pub org_id: String,
+    pub name: String,
+    pub address: String,
+    pub metadata: Vec<KeyValueEntrySlice>,
+    pub status: String,
+}
+
+pub fn do_create_organization(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    create_organization: CreateOrganizationAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::CreateOrganization)
+        .with_create_organization(create_organization)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_update_organization(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    update_organization: UpdateOrganizationAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::UpdateOrganization)
+        .with_update_organization(update_organization)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_delete_organization(
+    url: &str,
+    key: Option<&str>,
+    wait: u64,
+    delete_organization: DeleteOrganizationAction,
+) -> Result<(), CliError> {
+    let payload = PikePayloadBuilder::new()
+        .with_action(Action::DeleteOrganization)
+        .with_delete_organization(delete_organization)
+        .build()
+        .map_err(|err| CliError::PayloadError(err.to_string()))?;
+
+    submit_pike_payload(url, key, wait, payload)
+}
+
+pub fn do_list_organizations(url: &str) -> Result<(), CliError> {
+    let organizations: Vec<OrganizationSlice> = http::get(url, "/organization")?;
+
+    println!("{:<20} {:<30} {:<10} ADDRESS", "ORG_ID", "NAME", "STATUS");
+    for organization in organizations {
+        println!(
+            "{:<20} {:<30} {:<10} {}",
+            organization.org_id, organization.name, organization.status, organization.address
+        );
+    }
+
+    Ok(())
+}
+
+pub fn do_show_organization(url: &str, org_id: &str) -> Result<(), CliError> {
+    let organization: OrganizationSlice = http::get(url, &format!("/organization/{}", org_id))?;
+
+    println!("Organization ID: {}", organization.org_id);
+    println!("Name: {}", organization.name);
+    println!("Address: {}", organization.address);
+    println!("Status: {}", organization.status);
+    println!("Metadata:");
+    for entry in organization.metadata {
+        println!("  {}: {}", entry.key, entry.value);
+    }
+
+    Ok(())
+}
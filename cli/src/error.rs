@@ -14,18 +14,42 @@
 
 use std::error::Error;
 use std::fmt;
+use std::io;
 
 use log;
+use sawtooth_sdk::signing;
 
 #[derive(Debug)]
 pub enum CliError {
     LoggingInitializationError(Box<log::SetLoggerError>),
+    /// The user has provided invalid inputs; the string by this error
+    /// is appropriate for display to the user without additional context
+    UserError(String),
+    IoError(io::Error),
+    SigningError(signing::Error),
+    ProtobufError(protobuf::ProtobufError),
+    /// A payload could not be built from the given inputs
+    PayloadError(String),
+    /// A transaction or batch could not be built and signed
+    BatchError(String),
+    /// The request to the daemon could not be sent or its response could not be read
+    HttpError(reqwest::Error),
+    /// The daemon rejected the request or the submitted batches
+    DaemonError(String),
 }
 
 impl Error for CliError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             CliError::LoggingInitializationError(err) => Some(err),
+            CliError::UserError(_) => None,
+            CliError::IoError(err) => Some(err),
+            CliError::SigningError(err) => Some(err),
+            CliError::ProtobufError(err) => Some(err),
+            CliError::PayloadError(_) => None,
+            CliError::BatchError(_) => None,
+            CliError::HttpError(err) => Some(err),
+            CliError::DaemonError(_) => None,
         }
     }
 }
@@ -36,6 +60,14 @@ impl fmt::Display for CliError {
             CliError::LoggingInitializationError(e) => {
                 write!(f, "Logging initialization error: {}", e)
             }
+            CliError::UserError(e) => write!(f, "Error: {}", e),
+            CliError::IoError(e) => write!(f, "IoError: {}", e),
+            CliError::SigningError(e) => write!(f, "SigningError: {}", e),
+            CliError::ProtobufError(e) => write!(f, "ProtobufError: {}", e),
+            CliError::PayloadError(e) => write!(f, "PayloadError: {}", e),
+            CliError::BatchError(e) => write!(f, "BatchError: {}", e),
+            CliError::HttpError(e) => write!(f, "HttpError: {}", e),
+            CliError::DaemonError(e) => write!(f, "DaemonError: {}", e),
         }
     }
 }
@@ -45,3 +77,27 @@ impl From<log::SetLoggerError> for CliError {
         CliError::LoggingInitializationError(Box::new(err))
     }
 }
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> CliError {
+        CliError::IoError(err)
+    }
+}
+
+impl From<signing::Error> for CliError {
+    fn from(err: signing::Error) -> CliError {
+        CliError::SigningError(err)
+    }
+}
+
+impl From<protobuf::ProtobufError> for CliError {
+    fn from(err: protobuf::ProtobufError) -> CliError {
+        CliError::ProtobufError(err)
+    }
+}
+
+impl From<reqwest::Error> for CliError {
+    fn from(err: reqwest::Error) -> CliError {
+        CliError::HttpError(err)
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::{Duration, Instant};
+
+use grid_sdk::protos::batch::BatchList;
+use protobuf::Message;
+use reqwest::{header::CONTENT_TYPE, Client, Response};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::error::CliError;
+
+#[derive(Deserialize)]
+struct BatchStatusLink {
+    link: String,
+}
+
+#[derive(Deserialize)]
+struct BatchStatusResponse {
+    data: Vec<BatchStatus>,
+}
+
+#[derive(Deserialize)]
+struct BatchStatus {
+    id: String,
+    status: String,
+    invalid_transactions: Vec<InvalidTransaction>,
+}
+
+#[derive(Deserialize)]
+struct InvalidTransaction {
+    message: String,
+}
+
+/// Submits a batch list to the daemon's `/batches` endpoint. If `wait` is non-zero, waits up
+/// to that many seconds for the batches to be committed.
+pub fn submit_batch_list(url: &str, batch_list: &BatchList, wait: u64) -> Result<(), CliError> {
+    let response = Client::new()
+        .post(&format!("{}/batches", url))
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(batch_list.write_to_bytes()?)
+        .send()?;
+    let batch_link: BatchStatusLink = parse_response(response)?;
+
+    info!("Submitted batches: {}", batch_link.link);
+
+    if wait == 0 {
+        return Ok(());
+    }
+
+    wait_for_batches(&batch_link.link, Duration::from_secs(wait))
+}
+
+/// Polls the batch status link until every batch is committed, one is found invalid, or
+/// `wait` has passed
+fn wait_for_batches(link: &str, wait: Duration) -> Result<(), CliError> {
+    let client = Client::new();
+    let deadline = Instant::now() + wait;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let response = client
+            .get(&format!("{}&wait={}", link, remaining.as_secs()))
+            .send()?;
+        let statuses: BatchStatusResponse = parse_response(response)?;
+
+        if let Some(invalid) = statuses.data.iter().find(|batch| batch.status == "INVALID") {
+            let messages = invalid
+                .invalid_transactions
+                .iter()
+                .map(|txn| txn.message.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(CliError::DaemonError(format!(
+                "Batch {} is invalid: {}",
+                invalid.id, messages
+            )));
+        }
+
+        if statuses
+            .data
+            .iter()
+            .all(|batch| batch.status == "COMMITTED")
+        {
+            info!("Batches committed");
+            return Ok(());
+        }
+
+        if Instant::now() >= deadline {
+            return Err(CliError::DaemonError(
+                "Timed out waiting for batches to be committed".to_string(),
+            ));
+        }
+    }
+}
+
+/// Fetches `path` from the daemon and parses the JSON response
+pub fn get<T: DeserializeOwned>(url: &str, path: &str) -> Result<T, CliError> {
+    let response = Client::new().get(&format!("{}{}", url, path)).send()?;
+    parse_response(response)
+}
+
+/// Parses a successful response as JSON; error responses become a `DaemonError` holding the
+/// message sent by the daemon
+fn parse_response<T: DeserializeOwned>(mut response: Response) -> Result<T, CliError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response.json()?);
+    }
+
+    let message = response
+        .json::<String>()
+        .unwrap_or_else(|_| status.to_string());
+    Err(CliError::DaemonError(format!("{}: {}", status, message)))
+}
@@ -0,0 +1,71 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::fs::File;
+use std::io::prelude::*;
+
+use sawtooth_sdk::signing::secp256k1::Secp256k1PrivateKey;
+
+use crate::error::CliError;
+
+/// Loads the private key `~/.grid/keys/<name>.priv`. When no name is given, the key is named
+/// after the current user.
+pub fn load_signing_key(name: Option<&str>) -> Result<Secp256k1PrivateKey, CliError> {
+    let username = match name {
+        Some(name) => name.to_string(),
+        None => env::var("USER").map_err(|_| {
+            CliError::UserError(
+                "Could not load signing key: unable to determine username".to_string(),
+            )
+        })?,
+    };
+
+    let private_key_filename = dirs::home_dir()
+        .ok_or_else(|| {
+            CliError::UserError(
+                "Could not load signing key: unable to determine home directory".to_string(),
+            )
+        })
+        .map(|mut path| {
+            path.push(".grid");
+            path.push("keys");
+            path.push(format!("{}.priv", &username));
+            path
+        })?;
+
+    if !private_key_filename.as_path().exists() {
+        return Err(CliError::UserError(format!(
+            "No such key file: {}",
+            private_key_filename.display()
+        )));
+    }
+
+    let mut f = File::open(&private_key_filename)?;
+
+    let mut contents = String::new();
+    f.read_to_string(&mut contents)?;
+
+    let key_str = match contents.lines().next() {
+        Some(k) => k.trim(),
+        None => {
+            return Err(CliError::UserError(format!(
+                "Empty key file: {}",
+                private_key_filename.display()
+            )));
+        }
+    };
+
+    Ok(Secp256k1PrivateKey::from_hex(key_str)?)
+}
@@ -17,15 +17,29 @@ extern crate clap;
 #[macro_use]
 extern crate log;
 
+mod actions;
 mod error;
+mod http;
+mod key;
+mod transaction;
 
+use clap::ArgMatches;
+use grid_sdk::protocol::pike::payload::{
+    CreateAgentActionBuilder, CreateOrganizationActionBuilder, DeleteAgentActionBuilder,
+    DeleteOrganizationActionBuilder, UpdateAgentActionBuilder, UpdateOrganizationActionBuilder,
+};
+use grid_sdk::protocol::pike::state::{KeyValueEntry, KeyValueEntryBuilder, OrganizationStatus};
 use simple_logger;
 
-use error::CliError;
+use crate::actions::{agents, organizations};
+use crate::error::CliError;
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_URL: &str = "http://localhost:8080";
+const DEFAULT_WAIT: &str = "300";
+
 fn run() -> Result<(), CliError> {
     let matches = clap_app!(myapp =>
         (name: APP_NAME)
@@ -33,6 +47,84 @@ fn run() -> Result<(), CliError> {
         (author: "Contributors to Hyperledger Grid")
         (about: "Command line for Hyperledger Grid")
         (@arg verbose: -v +multiple "Log verbosely")
+        (@arg url: -U --url +takes_value "URL of the Grid daemon's REST API")
+        (@arg key: -k --key +takes_value
+          "name of the signing key in ~/.grid/keys (defaults to the current user)")
+        (@arg wait: --wait +takes_value
+          "seconds to wait for submitted batches to commit; 0 returns immediately")
+        (@setting SubcommandRequiredElseHelp)
+        (@subcommand agent =>
+            (about: "Create, update, delete or view Pike agents")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Create an agent")
+                (@arg org_id: +required "organization the agent belongs to")
+                (@arg public_key: +required "public key of the agent")
+                (@arg active: --active "mark the agent as active")
+                (@arg roles: --roles +takes_value +multiple +use_delimiter
+                  "comma separated roles assigned to the agent")
+                (@arg metadata: --metadata +takes_value +multiple +use_delimiter
+                  "comma separated key=value pairs stored with the agent")
+            )
+            (@subcommand update =>
+                (about: "Update an agent, replacing its roles and metadata")
+                (@arg org_id: +required "organization the agent belongs to")
+                (@arg public_key: +required "public key of the agent")
+                (@arg active: --active conflicts_with[inactive] "mark the agent as active")
+                (@arg inactive: --inactive
+                  "mark the agent as inactive; without either flag the agent keeps its current state")
+                (@arg roles: --roles +takes_value +multiple +use_delimiter
+                  "comma separated roles assigned to the agent")
+                (@arg metadata: --metadata +takes_value +multiple +use_delimiter
+                  "comma separated key=value pairs stored with the agent")
+            )
+            (@subcommand delete =>
+                (about: "Delete an agent")
+                (@arg org_id: +required "organization the agent belongs to")
+                (@arg public_key: +required "public key of the agent")
+            )
+            (@subcommand list =>
+                (about: "List all agents")
+            )
+            (@subcommand show =>
+                (about: "Show a single agent")
+                (@arg public_key: +required "public key of the agent")
+            )
+        )
+        (@subcommand organization =>
+            (about: "Create, update, delete or view Pike organizations")
+            (@setting SubcommandRequiredElseHelp)
+            (@subcommand create =>
+                (about: "Create an organization")
+                (@arg org_id: +required "unique id of the organization")
+                (@arg name: +required "name of the organization")
+                (@arg address: +required "physical address of the organization")
+                (@arg metadata: --metadata +takes_value +multiple +use_delimiter
+                  "comma separated key=value pairs stored with the organization")
+            )
+            (@subcommand update =>
+                (about: "Update an organization")
+                (@arg org_id: +required "unique id of the organization")
+                (@arg name: +required "name of the organization")
+                (@arg address: +required "physical address of the organization")
+                (@arg metadata: --metadata +takes_value +multiple +use_delimiter
+                  "comma separated key=value pairs stored with the organization")
+                (@arg suspend: --suspend conflicts_with[activate]
+                  "suspend the organization, deactivating all of its agents")
+                (@arg activate: --activate "reactivate a suspended organization")
+            )
+            (@subcommand delete =>
+                (about: "Delete an organization and the signer's agent")
+                (@arg org_id: +required "unique id of the organization")
+            )
+            (@subcommand list =>
+                (about: "List all organizations")
+            )
+            (@subcommand show =>
+                (about: "Show a single organization")
+                (@arg org_id: +required "unique id of the organization")
+            )
+        )
     )
     .get_matches();
 
@@ -42,9 +134,153 @@ fn run() -> Result<(), CliError> {
         _ => simple_logger::init_with_level(log::Level::Debug),
     }?;
 
+    let url = matches.value_of("url").unwrap_or(DEFAULT_URL);
+    let key = matches.value_of("key");
+    let wait = matches
+        .value_of("wait")
+        .unwrap_or(DEFAULT_WAIT)
+        .parse::<u64>()
+        .map_err(|err| CliError::UserError(format!("Invalid wait: {}", err)))?;
+
+    match matches.subcommand() {
+        ("agent", Some(matches)) => match matches.subcommand() {
+            ("create", Some(m)) => agents::do_create_agent(
+                url,
+                key,
+                wait,
+                CreateAgentActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_public_key(m.value_of("public_key").unwrap().into())
+                    .with_active(m.is_present("active"))
+                    .with_roles(parse_roles(m))
+                    .with_metadata(parse_metadata(m)?)
+                    .build()
+                    .map_err(|err| CliError::PayloadError(err.to_string()))?,
+            )?,
+            ("update", Some(m)) => {
+                let public_key = m.value_of("public_key").unwrap();
+                let active = if m.is_present("active") {
+                    true
+                } else if m.is_present("inactive") {
+                    false
+                } else {
+                    agents::fetch_agent(url, public_key)?.active
+                };
+
+                agents::do_update_agent(
+                    url,
+                    key,
+                    wait,
+                    UpdateAgentActionBuilder::new()
+                        .with_org_id(m.value_of("org_id").unwrap().into())
+                        .with_public_key(public_key.into())
+                        .with_active(active)
+                        .with_roles(parse_roles(m))
+                        .with_metadata(parse_metadata(m)?)
+                        .build()
+                        .map_err(|err| CliError::PayloadError(err.to_string()))?,
+                )?
+            }
+            ("delete", Some(m)) => agents::do_delete_agent(
+                url,
+                key,
+                wait,
+                DeleteAgentActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_public_key(m.value_of("public_key").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::PayloadError(err.to_string()))?,
+            )?,
+            ("list", Some(_)) => agents::do_list_agents(url)?,
+            ("show", Some(m)) => agents::do_show_agent(url, m.value_of("public_key").unwrap())?,
+            _ => unreachable!(),
+        },
+        ("organization", Some(matches)) => match matches.subcommand() {
+            ("create", Some(m)) => organizations::do_create_organization(
+                url,
+                key,
+                wait,
+                CreateOrganizationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_name(m.value_of("name").unwrap().into())
+                    .with_address(m.value_of("address").unwrap().into())
+                    .with_metadata(parse_metadata(m)?)
+                    .build()
+                    .map_err(|err| CliError::PayloadError(err.to_string()))?,
+            )?,
+            ("update", Some(m)) => {
+                let mut builder = UpdateOrganizationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .with_name(m.value_of("name").unwrap().into())
+                    .with_address(m.value_of("address").unwrap().into())
+                    .with_metadata(parse_metadata(m)?);
+
+                if m.is_present("suspend") {
+                    builder = builder.with_status(OrganizationStatus::Suspended);
+                } else if m.is_present("activate") {
+                    builder = builder.with_status(OrganizationStatus::Active);
+                }
+
+                organizations::do_update_organization(
+                    url,
+                    key,
+                    wait,
+                    builder
+                        .build()
+                        .map_err(|err| CliError::PayloadError(err.to_string()))?,
+                )?
+            }
+            ("delete", Some(m)) => organizations::do_delete_organization(
+                url,
+                key,
+                wait,
+                DeleteOrganizationActionBuilder::new()
+                    .with_org_id(m.value_of("org_id").unwrap().into())
+                    .build()
+                    .map_err(|err| CliError::PayloadError(err.to_string()))?,
+            )?,
+            ("list", Some(_)) => organizations::do_list_organizations(url)?,
+            ("show", Some(m)) => {
+                organizations::do_show_organization(url, m.value_of("org_id").unwrap())?
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
     Ok(())
 }
 
+fn parse_roles(matches: &ArgMatches) -> Vec<String> {
+    matches
+        .values_of("roles")
+        .map(|roles| roles.map(String::from).collect())
+        .unwrap_or_default()
+}
+
+fn parse_metadata(matches: &ArgMatches) -> Result<Vec<KeyValueEntry>, CliError> {
+    matches
+        .values_of("metadata")
+        .map(|entries| entries.collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| {
+            let mut key_value = entry.splitn(2, '=');
+            match (key_value.next(), key_value.next()) {
+                (Some(key), Some(value)) if !key.is_empty() => KeyValueEntryBuilder::new()
+                    .with_key(key.to_string())
+                    .with_value(value.to_string())
+                    .build()
+                    .map_err(|err| CliError::PayloadError(err.to_string())),
+                _ => Err(CliError::UserError(format!(
+                    "Metadata must be formatted as key=value: {}",
+                    entry
+                ))),
+            }
+        })
+        .collect()
+}
+
 fn main() {
     if let Err(e) = run() {
         error!("{:?}", e);
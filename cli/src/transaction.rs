@@ -0,0 +1,94 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grid_sdk::addressing::{compute_pike_agent_address, compute_pike_organization_address};
+use grid_sdk::protocol::pike::payload::{Action, PikePayload};
+use grid_sdk::protos::batch::BatchList;
+use grid_sdk::signing::{self, SigningError};
+use grid_sdk::transaction::{BatchBuilder, TransactionBuilder};
+use sawtooth_sdk::signing::Signer;
+
+use crate::error::CliError;
+
+const PIKE_FAMILY_NAME: &str = "pike";
+const PIKE_FAMILY_VERSION: &str = "0.1";
+
+/// Adapts a Sawtooth signer to the signer used by the grid-sdk transaction builders
+struct SawtoothSigner<'a>(&'a Signer<'a>);
+
+impl<'a> signing::Signer for SawtoothSigner<'a> {
+    fn sign(&self, message: &[u8]) -> Result<String, SigningError> {
+        self.0
+            .sign(message)
+            .map_err(|err| SigningError::SigningFailed(err.to_string()))
+    }
+
+    fn public_key(&self) -> Result<String, SigningError> {
+        self.0
+            .get_public_key()
+            .map(|public_key| public_key.as_hex())
+            .map_err(|err| SigningError::KeyError(err.to_string()))
+    }
+}
+
+/// Builds a batch list holding a single, signed Pike transaction
+pub fn pike_batch_list(payload: PikePayload, signer: &Signer) -> Result<BatchList, CliError> {
+    let public_key = signer.get_public_key()?.as_hex();
+
+    let addresses = match payload.action() {
+        Action::CreateAgent => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_agent_address(payload.create_agent().public_key()),
+            compute_pike_organization_address(payload.create_agent().org_id()),
+        ],
+        Action::UpdateAgent => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_agent_address(payload.update_agent().public_key()),
+            compute_pike_organization_address(payload.update_agent().org_id()),
+        ],
+        Action::CreateOrganization => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_organization_address(payload.create_organization().org_id()),
+        ],
+        Action::UpdateOrganization => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_organization_address(payload.update_organization().org_id()),
+        ],
+        Action::DeleteAgent => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_agent_address(payload.delete_agent().public_key()),
+            compute_pike_organization_address(payload.delete_agent().org_id()),
+        ],
+        Action::DeleteOrganization => vec![
+            compute_pike_agent_address(&public_key),
+            compute_pike_organization_address(payload.delete_organization().org_id()),
+        ],
+    };
+
+    let signer = SawtoothSigner(signer);
+
+    let transaction = TransactionBuilder::new()
+        .with_family_name(PIKE_FAMILY_NAME.to_string())
+        .with_family_version(PIKE_FAMILY_VERSION.to_string())
+        .with_inputs(addresses.clone())
+        .with_outputs(addresses)
+        .with_payload(payload)
+        .build(&signer)
+        .map_err(|err| CliError::BatchError(err.to_string()))?;
+
+    BatchBuilder::new()
+        .with_transactions(vec![transaction])
+        .build_batch_list(&signer)
+        .map_err(|err| CliError::BatchError(err.to_string()))
+}
@@ -0,0 +1,37 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error returned while applying a Pike payload, mirroring the errors a transaction processor
+/// can report back to the validator
+#[derive(Debug, PartialEq)]
+pub enum PikeError {
+    /// The transaction is invalid and should be rejected
+    InvalidTransaction(String),
+    /// State could not be read or written; the transaction may succeed if retried
+    InternalError(String),
+}
+
+impl Error for PikeError {}
+
+impl fmt::Display for PikeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PikeError::InvalidTransaction(msg) => write!(f, "InvalidTransaction: {}", msg),
+            PikeError::InternalError(msg) => write!(f, "InternalError: {}", msg),
+        }
+    }
+}
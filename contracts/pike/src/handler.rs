@@ -0,0 +1,266 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use grid_sdk::addressing::{
+    compute_pike_agent_address, compute_pike_organization_address, PIKE_NAMESPACE,
+};
+use grid_sdk::protocol::pike::payload::PikePayload;
+use grid_sdk::protocol::pike::state::{
+    Agent, AgentList, AgentListBuilder, Organization, OrganizationList, OrganizationListBuilder,
+};
+use grid_sdk::protos::{FromBytes, IntoBytes};
+
+cfg_if! {
+    if #[cfg(target_arch = "wasm32")] {
+        use sabre_sdk::ApplyError;
+        use sabre_sdk::TransactionContext;
+        use sabre_sdk::TransactionHandler;
+        use sabre_sdk::TpProcessRequest;
+    } else {
+        use sawtooth_sdk::messages::processor::TpProcessRequest;
+        use sawtooth_sdk::processor::handler::ApplyError;
+        use sawtooth_sdk::processor::handler::TransactionContext;
+        use sawtooth_sdk::processor::handler::TransactionHandler;
+    }
+}
+
+use crate::error::PikeError;
+use crate::rules::apply_payload;
+use crate::state::PikeState;
+
+/// PikeState backed by the validator's global state
+pub struct SawtoothPikeState<'a> {
+    context: &'a mut dyn TransactionContext,
+}
+
+impl<'a> SawtoothPikeState<'a> {
+    pub fn new(context: &'a mut dyn TransactionContext) -> SawtoothPikeState<'a> {
+        SawtoothPikeState { context }
+    }
+
+    fn get_state(&mut self, address: String) -> Result<Option<Vec<u8>>, PikeError> {
+        self.context
+            .get_state(vec![address])
+            .map_err(|err| PikeError::InternalError(format!("{}", err)))
+    }
+
+    fn set_state(&mut self, address: String, value: Vec<u8>) -> Result<(), PikeError> {
+        let mut sets = HashMap::new();
+        sets.insert(address, value);
+        self.context
+            .set_state(sets)
+            .map_err(|err| PikeError::InternalError(format!("{}", err)))
+    }
+
+    fn delete_state(&mut self, address: String) -> Result<(), PikeError> {
+        self.context
+            .delete_state(vec![address])
+            .map(|_| ())
+            .map_err(|err| PikeError::InternalError(format!("{}", err)))
+    }
+
+    fn set_agents(&mut self, address: String, agents: Vec<Agent>) -> Result<(), PikeError> {
+        if agents.is_empty() {
+            return self.delete_state(address);
+        }
+
+        let agent_list = AgentListBuilder::new()
+            .with_agents(agents)
+            .build()
+            .map_err(|err| PikeError::InternalError(format!("Cannot build agent list: {}", err)))?;
+        let serialized = agent_list.into_bytes().map_err(|err| {
+            PikeError::InternalError(format!("Cannot serialize agent list: {}", err))
+        })?;
+
+        self.set_state(address, serialized)
+    }
+
+    fn set_organizations(
+        &mut self,
+        address: String,
+        organizations: Vec<Organization>,
+    ) -> Result<(), PikeError> {
+        if organizations.is_empty() {
+            return self.delete_state(address);
+        }
+
+        let organization_list = OrganizationListBuilder::new()
+            .with_organizations(organizations)
+            .build()
+            .map_err(|err| {
+                PikeError::InternalError(format!("Cannot build organization list: {}", err))
+            })?;
+        let serialized = organization_list.into_bytes().map_err(|err| {
+            PikeError::InternalError(format!("Cannot serialize organization list: {}", err))
+        })?;
+
+        self.set_state(address, serialized)
+    }
+
+    fn get_agents(&mut self, address: &str) -> Result<Vec<Agent>, PikeError> {
+        match self.get_state(address.to_string())? {
+            Some(packed) => Ok(AgentList::from_bytes(packed.as_slice())
+                .map_err(|err| {
+                    PikeError::InternalError(format!("Cannot deserialize agent list: {}", err))
+                })?
+                .agents()
+                .to_vec()),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_organizations(&mut self, address: &str) -> Result<Vec<Organization>, PikeError> {
+        match self.get_state(address.to_string())? {
+            Some(packed) => Ok(OrganizationList::from_bytes(packed.as_slice())
+                .map_err(|err| {
+                    PikeError::InternalError(format!(
+                        "Cannot deserialize organization list: {}",
+                        err
+                    ))
+                })?
+                .organizations()
+                .to_vec()),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+impl<'a> PikeState for SawtoothPikeState<'a> {
+    fn get_agent(&mut self, public_key: &str) -> Result<Option<Agent>, PikeError> {
+        let address = compute_pike_agent_address(public_key);
+        Ok(self
+            .get_agents(&address)?
+            .into_iter()
+            .find(|agent| agent.public_key() == public_key))
+    }
+
+    fn set_agent(&mut self, agent: Agent) -> Result<(), PikeError> {
+        let address = compute_pike_agent_address(agent.public_key());
+        let mut agents = self.get_agents(&address)?;
+
+        // replace the old agent if it exists and sort the agents by public key
+        agents.retain(|existing| existing.public_key() != agent.public_key());
+        agents.push(agent);
+        agents.sort_by_key(|agent| agent.public_key().to_string());
+
+        self.set_agents(address, agents)
+    }
+
+    fn delete_agent(&mut self, public_key: &str) -> Result<(), PikeError> {
+        let address = compute_pike_agent_address(public_key);
+        let mut agents = self.get_agents(&address)?;
+        agents.retain(|agent| agent.public_key() != public_key);
+
+        // the address is removed from state once no agents are left at it
+        self.set_agents(address, agents)
+    }
+
+    fn get_organization(&mut self, org_id: &str) -> Result<Option<Organization>, PikeError> {
+        let address = compute_pike_organization_address(org_id);
+        Ok(self
+            .get_organizations(&address)?
+            .into_iter()
+            .find(|organization| organization.org_id() == org_id))
+    }
+
+    fn set_organization(&mut self, organization: Organization) -> Result<(), PikeError> {
+        let address = compute_pike_organization_address(organization.org_id());
+        let mut organizations = self.get_organizations(&address)?;
+
+        // replace the old organization if it exists and sort the organizations by ID
+        organizations.retain(|existing| existing.org_id() != organization.org_id());
+        organizations.push(organization);
+        organizations.sort_by_key(|organization| organization.org_id().to_string());
+
+        self.set_organizations(address, organizations)
+    }
+
+    fn delete_organization(&mut self, org_id: &str) -> Result<(), PikeError> {
+        let address = compute_pike_organization_address(org_id);
+        let mut organizations = self.get_organizations(&address)?;
+        organizations.retain(|organization| organization.org_id() != org_id);
+
+        // the address is removed from state once no organizations are left at it
+        self.set_organizations(address, organizations)
+    }
+}
+
+pub struct PikeTransactionHandler {
+    family_name: String,
+    family_versions: Vec<String>,
+    namespaces: Vec<String>,
+}
+
+impl Default for PikeTransactionHandler {
+    fn default() -> Self {
+        PikeTransactionHandler::new()
+    }
+}
+
+impl PikeTransactionHandler {
+    pub fn new() -> PikeTransactionHandler {
+        PikeTransactionHandler {
+            family_name: "pike".to_string(),
+            family_versions: vec!["0.1".to_string()],
+            namespaces: vec![PIKE_NAMESPACE.to_string()],
+        }
+    }
+}
+
+impl TransactionHandler for PikeTransactionHandler {
+    fn family_name(&self) -> String {
+        self.family_name.clone()
+    }
+
+    fn family_versions(&self) -> Vec<String> {
+        self.family_versions.clone()
+    }
+
+    fn namespaces(&self) -> Vec<String> {
+        self.namespaces.clone()
+    }
+
+    fn apply(
+        &self,
+        request: &TpProcessRequest,
+        context: &mut dyn TransactionContext,
+    ) -> Result<(), ApplyError> {
+        let payload = PikePayload::from_bytes(request.get_payload()).map_err(|err| {
+            ApplyError::InvalidTransaction(format!("Cannot deserialize payload: {}", err))
+        })?;
+
+        let signer = request.get_header().get_signer_public_key();
+        let mut state = SawtoothPikeState::new(context);
+
+        info!("payload: {:?}", payload.action());
+
+        apply_payload(&payload, signer, &mut state).map_err(|err| match err {
+            PikeError::InvalidTransaction(msg) => ApplyError::InvalidTransaction(msg),
+            PikeError::InternalError(msg) => ApplyError::InternalError(msg),
+        })
+    }
+}
+
+/// Applies a transaction when the contract runs in Sabre, which expects a bool on success
+#[cfg(target_arch = "wasm32")]
+pub fn apply(
+    request: &TpProcessRequest,
+    context: &mut dyn TransactionContext,
+) -> Result<bool, ApplyError> {
+    PikeTransactionHandler::new()
+        .apply(request, context)
+        .map(|_| true)
+}
@@ -0,0 +1,941 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grid_sdk::protocol::pike::payload::{
+    Action, CreateAgentAction, CreateOrganizationAction, DeleteAgentAction,
+    DeleteOrganizationAction, PikePayload, UpdateAgentAction, UpdateOrganizationAction,
+};
+use grid_sdk::protocol::pike::permissions::is_agent_active;
+use grid_sdk::protocol::pike::state::{
+    Agent, AgentBuilder, Organization, OrganizationBuilder, OrganizationStatus,
+};
+
+use crate::error::PikeError;
+use crate::state::PikeState;
+
+/// Role an agent must hold to manage its organization and the organization's agents
+pub const ADMIN_ROLE: &str = "admin";
+
+/// Applies a Pike payload signed by `signer` to the given state
+pub fn apply_payload(
+    payload: &PikePayload,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    match payload.action() {
+        Action::CreateAgent => create_agent(payload.create_agent(), signer, state),
+        Action::UpdateAgent => update_agent(payload.update_agent(), signer, state),
+        Action::CreateOrganization => {
+            create_organization(payload.create_organization(), signer, state)
+        }
+        Action::UpdateOrganization => {
+            update_organization(payload.update_organization(), signer, state)
+        }
+        Action::DeleteAgent => delete_agent(payload.delete_agent(), signer, state),
+        Action::DeleteOrganization => {
+            delete_organization(payload.delete_organization(), signer, state)
+        }
+    }
+}
+
+pub fn create_agent(
+    payload: &CreateAgentAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.public_key().is_empty() {
+        return Err(PikeError::InvalidTransaction("Public key required".into()));
+    }
+
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to create agent
+    is_admin(signer, payload.org_id(), state)?;
+
+    if state.get_agent(payload.public_key())?.is_some() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Agent already exists: {}",
+            payload.public_key(),
+        )));
+    }
+
+    let agent = AgentBuilder::new()
+        .with_public_key(payload.public_key().to_string())
+        .with_org_id(payload.org_id().to_string())
+        .with_active(*payload.active())
+        .with_roles(payload.roles().to_vec())
+        .with_metadata(payload.metadata().to_vec())
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid agent: {}", err)))?;
+
+    state.set_agent(agent)?;
+    change_agent_count(payload.org_id(), 1, state)
+}
+
+pub fn update_agent(
+    payload: &UpdateAgentAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.public_key().is_empty() {
+        return Err(PikeError::InvalidTransaction("Public key required".into()));
+    }
+
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to update agent
+    is_admin(signer, payload.org_id(), state)?;
+
+    // make sure agent already exists
+    let agent = match state.get_agent(payload.public_key())? {
+        Some(agent) => agent,
+        None => {
+            return Err(PikeError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                payload.public_key(),
+            )))
+        }
+    };
+
+    // an admin may only update the agents of their own organization
+    if agent.org_id() != payload.org_id() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Agent is not associated with the organization: {}",
+            payload.public_key(),
+        )));
+    }
+
+    let roles = if payload.roles().is_empty() {
+        agent.roles().to_vec()
+    } else {
+        payload.roles().to_vec()
+    };
+
+    let metadata = if payload.metadata().is_empty() {
+        agent.metadata().to_vec()
+    } else {
+        payload.metadata().to_vec()
+    };
+
+    if payload.active() != agent.active() && signer == payload.public_key() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Admin may not deactivate themselves: {}",
+            signer,
+        )));
+    }
+
+    let updated_agent = AgentBuilder::new()
+        .with_public_key(agent.public_key().to_string())
+        .with_org_id(agent.org_id().to_string())
+        .with_active(*payload.active())
+        .with_roles(roles)
+        .with_metadata(metadata)
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid agent: {}", err)))?;
+
+    state.set_agent(updated_agent)
+}
+
+pub fn delete_agent(
+    payload: &DeleteAgentAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.public_key().is_empty() {
+        return Err(PikeError::InvalidTransaction("Public key required".into()));
+    }
+
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to delete agent
+    is_admin(signer, payload.org_id(), state)?;
+
+    if signer == payload.public_key() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Admin may not delete themselves: {}",
+            signer,
+        )));
+    }
+
+    match state.get_agent(payload.public_key())? {
+        Some(ref agent) if agent.org_id() == payload.org_id() => (),
+        Some(_) => {
+            return Err(PikeError::InvalidTransaction(format!(
+                "Agent is not associated with the organization: {}",
+                payload.public_key(),
+            )))
+        }
+        None => {
+            return Err(PikeError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                payload.public_key(),
+            )))
+        }
+    }
+
+    state.delete_agent(payload.public_key())?;
+    change_agent_count(payload.org_id(), -1, state)
+}
+
+pub fn create_organization(
+    payload: &CreateOrganizationAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Unique organization ID required".into(),
+        ));
+    }
+
+    if payload.name().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Organization name required".into(),
+        ));
+    }
+
+    if state.get_organization(payload.org_id())?.is_some() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Organization already exists: {}",
+            payload.org_id(),
+        )));
+    }
+
+    // the signer becomes the organization's first admin, so it may not already be an agent
+    if state.get_agent(signer)?.is_some() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Agent already exists: {}",
+            signer,
+        )));
+    }
+
+    let organization = OrganizationBuilder::new()
+        .with_org_id(payload.org_id().to_string())
+        .with_name(payload.name().to_string())
+        .with_address(payload.address().to_string())
+        .with_metadata(payload.metadata().to_vec())
+        .with_status(OrganizationStatus::Active)
+        .with_agent_count(1)
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid organization: {}", err)))?;
+    state.set_organization(organization)?;
+
+    let agent = AgentBuilder::new()
+        .with_public_key(signer.to_string())
+        .with_org_id(payload.org_id().to_string())
+        .with_active(true)
+        .with_roles(vec![ADMIN_ROLE.to_string()])
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid agent: {}", err)))?;
+
+    state.set_agent(agent)
+}
+
+/// Updates an organization. Unlike the other actions, this is permitted while the organization is
+/// suspended so that its admins are able to reactivate it.
+pub fn update_organization(
+    payload: &UpdateOrganizationAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Unique organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to update organization
+    let (_, organization) = get_admin(signer, payload.org_id(), state)?;
+
+    let updated_organization = update_organization_fields(&organization, payload)?;
+
+    state.set_organization(updated_organization)
+}
+
+/// Deletes an organization along with the signing admin's agent. The organization's other agents
+/// must be deleted first, so that none are left behind to act for an organization later created
+/// with the same ID.
+pub fn delete_organization(
+    payload: &DeleteOrganizationAction,
+    signer: &str,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    if payload.org_id().is_empty() {
+        return Err(PikeError::InvalidTransaction(
+            "Unique organization ID required".into(),
+        ));
+    }
+
+    // verify the signer of the transaction is authorized to delete organization
+    is_admin(signer, payload.org_id(), state)?;
+
+    let organization = get_organization(payload.org_id(), state)?;
+    if *organization.agent_count() > 1 {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Organization still has other agents: {}",
+            payload.org_id(),
+        )));
+    }
+
+    state.delete_organization(payload.org_id())?;
+    state.delete_agent(signer)
+}
+
+fn update_organization_fields(
+    organization: &Organization,
+    payload: &UpdateOrganizationAction,
+) -> Result<Organization, PikeError> {
+    let name = if payload.name().is_empty() {
+        organization.name()
+    } else {
+        payload.name()
+    };
+
+    let address = if payload.address().is_empty() {
+        organization.address()
+    } else {
+        payload.address()
+    };
+
+    let metadata = if payload.metadata().is_empty() {
+        organization.metadata()
+    } else {
+        payload.metadata()
+    };
+
+    let status = payload.status().unwrap_or_else(|| organization.status());
+
+    OrganizationBuilder::new()
+        .with_org_id(organization.org_id().to_string())
+        .with_name(name.to_string())
+        .with_address(address.to_string())
+        .with_metadata(metadata.to_vec())
+        .with_status(status.clone())
+        .with_agent_count(*organization.agent_count())
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid organization: {}", err)))
+}
+
+/// Adds `change` to the number of agents recorded on the organization
+fn change_agent_count(
+    org_id: &str,
+    change: i64,
+    state: &mut dyn PikeState,
+) -> Result<(), PikeError> {
+    let organization = get_organization(org_id, state)?;
+    let agent_count = (i64::from(*organization.agent_count()) + change).max(0) as u32;
+
+    let updated_organization = OrganizationBuilder::new()
+        .with_org_id(organization.org_id().to_string())
+        .with_name(organization.name().to_string())
+        .with_address(organization.address().to_string())
+        .with_metadata(organization.metadata().to_vec())
+        .with_status(organization.status().clone())
+        .with_agent_count(agent_count)
+        .build()
+        .map_err(|err| PikeError::InvalidTransaction(format!("Invalid organization: {}", err)))?;
+
+    state.set_organization(updated_organization)
+}
+
+/// Checks that `signer` is an active agent of the organization holding the admin role, and that
+/// the organization exists and is not suspended
+pub fn is_admin(signer: &str, org_id: &str, state: &mut dyn PikeState) -> Result<(), PikeError> {
+    let (admin, organization) = get_admin(signer, org_id, state)?;
+
+    if !is_agent_active(&admin, &organization) {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Organization is suspended: {}",
+            org_id,
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the admin agent of `signer` along with its organization, regardless of whether the
+/// organization is suspended
+fn get_admin(
+    signer: &str,
+    org_id: &str,
+    state: &mut dyn PikeState,
+) -> Result<(Agent, Organization), PikeError> {
+    let admin: Agent = match state.get_agent(signer)? {
+        Some(admin) => admin,
+        None => {
+            return Err(PikeError::InvalidTransaction(format!(
+                "Signer is not an agent: {}",
+                signer,
+            )))
+        }
+    };
+
+    if admin.org_id() != org_id {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Signer is not associated with the organization: {}",
+            signer,
+        )));
+    }
+
+    if !admin.roles().iter().any(|role| role == ADMIN_ROLE) {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Signer is not an admin: {}",
+            signer,
+        )));
+    }
+
+    if !*admin.active() {
+        return Err(PikeError::InvalidTransaction(format!(
+            "Admin is not currently an active agent: {}",
+            signer,
+        )));
+    }
+
+    Ok((admin, get_organization(org_id, state)?))
+}
+
+fn get_organization(org_id: &str, state: &mut dyn PikeState) -> Result<Organization, PikeError> {
+    match state.get_organization(org_id)? {
+        Some(organization) => Ok(organization),
+        None => Err(PikeError::InvalidTransaction(format!(
+            "Organization does not exist: {}",
+            org_id,
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use grid_sdk::protocol::pike::payload::{
+        CreateAgentActionBuilder, CreateOrganizationActionBuilder, DeleteAgentActionBuilder,
+        DeleteOrganizationActionBuilder, PikePayloadBuilder, UpdateAgentActionBuilder,
+        UpdateOrganizationActionBuilder,
+    };
+
+    use crate::state::InMemoryPikeState;
+
+    const ADMIN: &str = "admin_key";
+    const AGENT: &str = "agent_key";
+
+    fn create_organization_payload(org_id: &str) -> PikePayload {
+        let action = CreateOrganizationActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_name("Organization".to_string())
+            .with_address("address".to_string())
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::CreateOrganization)
+            .with_create_organization(action)
+            .build()
+            .unwrap()
+    }
+
+    fn create_agent_payload(org_id: &str, public_key: &str, roles: Vec<String>) -> PikePayload {
+        let action = CreateAgentActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_public_key(public_key.to_string())
+            .with_active(true)
+            .with_roles(roles)
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::CreateAgent)
+            .with_create_agent(action)
+            .build()
+            .unwrap()
+    }
+
+    fn update_agent_payload(org_id: &str, public_key: &str, active: bool) -> PikePayload {
+        let action = UpdateAgentActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_public_key(public_key.to_string())
+            .with_active(active)
+            .with_roles(vec!["member".to_string()])
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::UpdateAgent)
+            .with_update_agent(action)
+            .build()
+            .unwrap()
+    }
+
+    fn delete_agent_payload(org_id: &str, public_key: &str) -> PikePayload {
+        let action = DeleteAgentActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_public_key(public_key.to_string())
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::DeleteAgent)
+            .with_delete_agent(action)
+            .build()
+            .unwrap()
+    }
+
+    fn update_organization_status_payload(org_id: &str, status: OrganizationStatus) -> PikePayload {
+        let action = UpdateOrganizationActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_status(status)
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::UpdateOrganization)
+            .with_update_organization(action)
+            .build()
+            .unwrap()
+    }
+
+    fn delete_organization_payload(org_id: &str) -> PikePayload {
+        let action = DeleteOrganizationActionBuilder::new()
+            .with_org_id(org_id.to_string())
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::DeleteOrganization)
+            .with_delete_organization(action)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a state holding org_01, administered by ADMIN
+    fn state_with_organization() -> InMemoryPikeState {
+        let mut state = InMemoryPikeState::new();
+        apply_payload(&create_organization_payload("org_01"), ADMIN, &mut state).unwrap();
+        state
+    }
+
+    fn assert_invalid(result: Result<(), PikeError>) {
+        match result {
+            Err(PikeError::InvalidTransaction(_)) => (),
+            res => panic!("Expected InvalidTransaction, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that creating an organization makes the signer its active admin
+    fn check_create_organization() {
+        let mut state = state_with_organization();
+
+        let organization = state.get_organization("org_01").unwrap().unwrap();
+        assert_eq!(organization.name(), "Organization");
+        assert_eq!(organization.address(), "address");
+
+        let admin = state.get_agent(ADMIN).unwrap().unwrap();
+        assert_eq!(admin.org_id(), "org_01");
+        assert_eq!(admin.roles(), [ADMIN_ROLE.to_string()]);
+        assert!(*admin.active());
+    }
+
+    #[test]
+    // check that an organization cannot be created twice, or by a signer who is already an agent
+    fn check_create_organization_invalid() {
+        let mut state = state_with_organization();
+
+        assert_invalid(apply_payload(
+            &create_organization_payload("org_01"),
+            "other_key",
+            &mut state,
+        ));
+        assert_invalid(apply_payload(
+            &create_organization_payload("org_02"),
+            ADMIN,
+            &mut state,
+        ));
+        assert!(state.get_organization("org_02").unwrap().is_none());
+    }
+
+    #[test]
+    // check that an admin can add agents to their organization
+    fn check_create_agent() {
+        let mut state = state_with_organization();
+
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec!["member".to_string()]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        let agent = state.get_agent(AGENT).unwrap().unwrap();
+        assert_eq!(agent.org_id(), "org_01");
+        assert_eq!(agent.roles(), ["member".to_string()]);
+
+        // the agent cannot be created twice
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        ));
+    }
+
+    #[test]
+    // check that only active admins of the organization can add agents to it
+    fn check_create_agent_unauthorized() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_organization_payload("org_02"),
+            "admin_02",
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec!["member".to_string()]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        // not an agent
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", "new_key", vec![]),
+            "unknown_key",
+            &mut state,
+        ));
+        // an admin of another organization
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", "new_key", vec![]),
+            "admin_02",
+            &mut state,
+        ));
+        // an agent without the admin role
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", "new_key", vec![]),
+            AGENT,
+            &mut state,
+        ));
+        // an inactive admin
+        apply_payload(
+            &create_agent_payload("org_01", "inactive_admin", vec![ADMIN_ROLE.to_string()]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(
+            &update_agent_payload("org_01", "inactive_admin", false),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", "new_key", vec![]),
+            "inactive_admin",
+            &mut state,
+        ));
+
+        assert!(state.get_agent("new_key").unwrap().is_none());
+    }
+
+    #[test]
+    // check that an admin can update an agent, but not deactivate themselves
+    fn check_update_agent() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        apply_payload(
+            &update_agent_payload("org_01", AGENT, false),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        let agent = state.get_agent(AGENT).unwrap().unwrap();
+        assert_eq!(agent.roles(), ["member".to_string()]);
+        assert!(!*agent.active());
+
+        assert_invalid(apply_payload(
+            &update_agent_payload("org_01", ADMIN, false),
+            ADMIN,
+            &mut state,
+        ));
+        assert!(*state.get_agent(ADMIN).unwrap().unwrap().active());
+
+        // the agent must exist
+        assert_invalid(apply_payload(
+            &update_agent_payload("org_01", "unknown_key", true),
+            ADMIN,
+            &mut state,
+        ));
+    }
+
+    #[test]
+    // check that an admin cannot update the agents of another organization
+    fn check_update_agent_other_organization() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_organization_payload("org_02"),
+            "admin_02",
+            &mut state,
+        )
+        .unwrap();
+
+        assert_invalid(apply_payload(
+            &update_agent_payload("org_01", "admin_02", false),
+            ADMIN,
+            &mut state,
+        ));
+        assert!(*state.get_agent("admin_02").unwrap().unwrap().active());
+    }
+
+    #[test]
+    // check that an admin can update their organization, keeping fields that are not given
+    fn check_update_organization() {
+        let mut state = state_with_organization();
+        let action = UpdateOrganizationActionBuilder::new()
+            .with_org_id("org_01".to_string())
+            .with_name("Renamed".to_string())
+            .with_address("".to_string())
+            .build()
+            .unwrap();
+        let payload = PikePayloadBuilder::new()
+            .with_action(Action::UpdateOrganization)
+            .with_update_organization(action)
+            .build()
+            .unwrap();
+
+        assert_invalid(apply_payload(&payload, "unknown_key", &mut state));
+
+        apply_payload(&payload, ADMIN, &mut state).unwrap();
+        let organization = state.get_organization("org_01").unwrap().unwrap();
+        assert_eq!(organization.name(), "Renamed");
+        assert_eq!(organization.address(), "address");
+    }
+
+    #[test]
+    // check that an admin can delete an agent of their organization, but not themselves
+    fn check_delete_agent() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_organization_payload("org_02"),
+            "admin_02",
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        // only an admin of the agent's organization may delete it
+        assert_invalid(apply_payload(
+            &delete_agent_payload("org_01", AGENT),
+            "admin_02",
+            &mut state,
+        ));
+        assert_invalid(apply_payload(
+            &delete_agent_payload("org_02", AGENT),
+            "admin_02",
+            &mut state,
+        ));
+        assert_invalid(apply_payload(
+            &delete_agent_payload("org_01", ADMIN),
+            ADMIN,
+            &mut state,
+        ));
+
+        apply_payload(&delete_agent_payload("org_01", AGENT), ADMIN, &mut state).unwrap();
+        assert!(state.get_agent(AGENT).unwrap().is_none());
+
+        assert_invalid(apply_payload(
+            &delete_agent_payload("org_01", AGENT),
+            ADMIN,
+            &mut state,
+        ));
+    }
+
+    #[test]
+    // check that adding and deleting agents keeps the organization's agent count
+    fn check_agent_count() {
+        let mut state = state_with_organization();
+        assert_eq!(
+            state
+                .get_organization("org_01")
+                .unwrap()
+                .unwrap()
+                .agent_count(),
+            &1
+        );
+
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        assert_eq!(
+            state
+                .get_organization("org_01")
+                .unwrap()
+                .unwrap()
+                .agent_count(),
+            &2
+        );
+
+        apply_payload(&delete_agent_payload("org_01", AGENT), ADMIN, &mut state).unwrap();
+        assert_eq!(
+            state
+                .get_organization("org_01")
+                .unwrap()
+                .unwrap()
+                .agent_count(),
+            &1
+        );
+    }
+
+    #[test]
+    // check that an organization can only be deleted once its admin is its last agent, and that
+    // deleting it removes the organization and the admin
+    fn check_delete_organization() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_agent_payload("org_01", "admin_2", vec![ADMIN_ROLE.to_string()]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        assert_invalid(apply_payload(
+            &delete_organization_payload("org_01"),
+            "unknown_key",
+            &mut state,
+        ));
+
+        // admin_2 still belongs to the organization
+        assert_invalid(apply_payload(
+            &delete_organization_payload("org_01"),
+            ADMIN,
+            &mut state,
+        ));
+        assert!(state.get_organization("org_01").unwrap().is_some());
+
+        apply_payload(
+            &delete_agent_payload("org_01", "admin_2"),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(&delete_organization_payload("org_01"), ADMIN, &mut state).unwrap();
+        assert!(state.get_organization("org_01").unwrap().is_none());
+        assert!(state.get_agent(ADMIN).unwrap().is_none());
+
+        // the signer is no longer an agent, so it may create a new organization
+        apply_payload(&create_organization_payload("org_03"), ADMIN, &mut state).unwrap();
+    }
+
+    #[test]
+    // check that the agents of a deleted organization cannot act as admins of a new organization
+    // created with the same ID
+    fn check_recreate_deleted_organization() {
+        let mut state = state_with_organization();
+        apply_payload(
+            &create_agent_payload("org_01", "admin_2", vec![ADMIN_ROLE.to_string()]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(
+            &delete_agent_payload("org_01", "admin_2"),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+        apply_payload(&delete_organization_payload("org_01"), ADMIN, &mut state).unwrap();
+
+        apply_payload(
+            &create_organization_payload("org_01"),
+            "new_admin",
+            &mut state,
+        )
+        .unwrap();
+
+        for old_admin in &[ADMIN, "admin_2"] {
+            assert_invalid(is_admin(old_admin, "org_01", &mut state));
+            assert_invalid(apply_payload(
+                &create_agent_payload("org_01", "new_key", vec![]),
+                old_admin,
+                &mut state,
+            ));
+        }
+        assert!(state.get_agent("new_key").unwrap().is_none());
+        is_admin("new_admin", "org_01", &mut state).unwrap();
+    }
+
+    #[test]
+    // check that suspending an organization stops its admins from acting, without changing its
+    // agents, until the organization is reactivated
+    fn check_suspend_organization() {
+        let mut state = state_with_organization();
+
+        apply_payload(
+            &update_organization_status_payload("org_01", OrganizationStatus::Suspended),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        let organization = state.get_organization("org_01").unwrap().unwrap();
+        assert_eq!(organization.status(), &OrganizationStatus::Suspended);
+        assert_eq!(organization.name(), "Organization");
+        assert!(*state.get_agent(ADMIN).unwrap().unwrap().active());
+
+        assert_invalid(is_admin(ADMIN, "org_01", &mut state));
+        assert_invalid(apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        ));
+
+        apply_payload(
+            &update_organization_status_payload("org_01", OrganizationStatus::Active),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+
+        is_admin(ADMIN, "org_01", &mut state).unwrap();
+        apply_payload(
+            &create_agent_payload("org_01", AGENT, vec![]),
+            ADMIN,
+            &mut state,
+        )
+        .unwrap();
+    }
+}
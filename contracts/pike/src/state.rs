@@ -0,0 +1,83 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use grid_sdk::protocol::pike::state::{Agent, Organization};
+
+use crate::error::PikeError;
+
+/// The state the Pike rules read and write. Implemented over a validator's transaction context
+/// by the transaction processor, and in memory for tests.
+pub trait PikeState {
+    fn get_agent(&mut self, public_key: &str) -> Result<Option<Agent>, PikeError>;
+
+    /// Stores the agent, replacing any agent with the same public key
+    fn set_agent(&mut self, agent: Agent) -> Result<(), PikeError>;
+
+    /// Removes the agent with the given public key, if it exists
+    fn delete_agent(&mut self, public_key: &str) -> Result<(), PikeError>;
+
+    fn get_organization(&mut self, org_id: &str) -> Result<Option<Organization>, PikeError>;
+
+    /// Stores the organization, replacing any organization with the same ID
+    fn set_organization(&mut self, organization: Organization) -> Result<(), PikeError>;
+
+    /// Removes the organization with the given ID, if it exists
+    fn delete_organization(&mut self, org_id: &str) -> Result<(), PikeError>;
+}
+
+/// PikeState kept in memory, for running the rules without a validator
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryPikeState {
+    agents: HashMap<String, Agent>,
+    organizations: HashMap<String, Organization>,
+}
+
+impl InMemoryPikeState {
+    pub fn new() -> Self {
+        InMemoryPikeState::default()
+    }
+}
+
+impl PikeState for InMemoryPikeState {
+    fn get_agent(&mut self, public_key: &str) -> Result<Option<Agent>, PikeError> {
+        Ok(self.agents.get(public_key).cloned())
+    }
+
+    fn set_agent(&mut self, agent: Agent) -> Result<(), PikeError> {
+        self.agents.insert(agent.public_key().to_string(), agent);
+        Ok(())
+    }
+
+    fn delete_agent(&mut self, public_key: &str) -> Result<(), PikeError> {
+        self.agents.remove(public_key);
+        Ok(())
+    }
+
+    fn get_organization(&mut self, org_id: &str) -> Result<Option<Organization>, PikeError> {
+        Ok(self.organizations.get(org_id).cloned())
+    }
+
+    fn set_organization(&mut self, organization: Organization) -> Result<(), PikeError> {
+        self.organizations
+            .insert(organization.org_id().to_string(), organization);
+        Ok(())
+    }
+
+    fn delete_organization(&mut self, org_id: &str) -> Result<(), PikeError> {
+        self.organizations.remove(org_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,539 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use grid_sdk::addressing::{
+    compute_pike_agent_address, compute_pike_organization_address, compute_schema_address,
+    GRID_NAMESPACE,
+};
+use grid_sdk::protocol::pike::permissions::is_agent_active;
+use grid_sdk::protocol::pike::state::{Agent, AgentList, Organization, OrganizationList};
+use grid_sdk::protocol::schema::payload::{
+    Action, SchemaCreateAction, SchemaPayload, SchemaUpdateAction,
+};
+use grid_sdk::protocol::schema::state::{
+    PropertyDefinition, Schema, SchemaBuilder, SchemaList, SchemaListBuilder,
+};
+use grid_sdk::protocol::schema::validation::validate_property_definitions;
+use grid_sdk::protos::{FromBytes, IntoBytes};
+use sawtooth_sdk::messages::processor::TpProcessRequest;
+use sawtooth_sdk::processor::handler::ApplyError;
+use sawtooth_sdk::processor::handler::TransactionContext;
+use sawtooth_sdk::processor::handler::TransactionHandler;
+
+/// Pike role an agent must hold to update the schemas owned by its organization
+const SCHEMA_ADMIN_ROLE: &str = "schema_admin";
+
+pub struct GridSchemaState<'a> {
+    context: &'a mut dyn TransactionContext,
+}
+
+impl<'a> GridSchemaState<'a> {
+    pub fn new(context: &'a mut dyn TransactionContext) -> GridSchemaState<'a> {
+        GridSchemaState { context }
+    }
+
+    pub fn get_schema(&mut self, name: &str) -> Result<Option<Schema>, ApplyError> {
+        let address = compute_schema_address(name);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let schemas = SchemaList::from_bytes(packed.as_slice()).map_err(|err| {
+                    ApplyError::InternalError(format!("Cannot deserialize schema list: {}", err))
+                })?;
+
+                Ok(schemas
+                    .schemas()
+                    .iter()
+                    .find(|schema| schema.name() == name)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_schema(&mut self, name: &str, new_schema: Schema) -> Result<(), ApplyError> {
+        let address = compute_schema_address(name);
+        let d = self.context.get_state(vec![address.clone()])?;
+        let mut schemas = match d {
+            Some(packed) => SchemaList::from_bytes(packed.as_slice())
+                .map_err(|err| {
+                    ApplyError::InternalError(format!("Cannot deserialize schema list: {}", err))
+                })?
+                .schemas()
+                .to_vec(),
+            None => vec![],
+        };
+
+        // replace the old schema if it exists and sort the schemas by name
+        schemas.retain(|schema| schema.name() != name);
+        schemas.push(new_schema);
+        schemas.sort_by_key(|schema| schema.name().to_string());
+
+        let schema_list = SchemaListBuilder::new()
+            .with_schemas(schemas)
+            .build()
+            .map_err(|err| {
+                ApplyError::InternalError(format!("Cannot build schema list: {}", err))
+            })?;
+        let serialized = schema_list.into_bytes().map_err(|err| {
+            ApplyError::InternalError(format!("Cannot serialize schema list: {}", err))
+        })?;
+
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context
+            .set_state(sets)
+            .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+        Ok(())
+    }
+
+    pub fn get_agent(&mut self, public_key: &str) -> Result<Option<Agent>, ApplyError> {
+        let address = compute_pike_agent_address(public_key);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let agents = AgentList::from_bytes(packed.as_slice()).map_err(|err| {
+                    ApplyError::InternalError(format!("Cannot deserialize agent list: {}", err))
+                })?;
+
+                Ok(agents
+                    .agents()
+                    .iter()
+                    .find(|agent| agent.public_key() == public_key)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_organization(&mut self, org_id: &str) -> Result<Option<Organization>, ApplyError> {
+        let address = compute_pike_organization_address(org_id);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let organizations =
+                    OrganizationList::from_bytes(packed.as_slice()).map_err(|err| {
+                        ApplyError::InternalError(format!(
+                            "Cannot deserialize organization list: {}",
+                            err
+                        ))
+                    })?;
+
+                Ok(organizations
+                    .organizations()
+                    .iter()
+                    .find(|organization| organization.org_id() == org_id)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+pub struct GridSchemaTransactionHandler {
+    family_name: String,
+    family_versions: Vec<String>,
+    namespaces: Vec<String>,
+}
+
+impl Default for GridSchemaTransactionHandler {
+    fn default() -> Self {
+        GridSchemaTransactionHandler::new()
+    }
+}
+
+impl GridSchemaTransactionHandler {
+    pub fn new() -> GridSchemaTransactionHandler {
+        GridSchemaTransactionHandler {
+            family_name: "grid_schema".to_string(),
+            family_versions: vec!["1".to_string()],
+            namespaces: vec![GRID_NAMESPACE.to_string()],
+        }
+    }
+
+    fn schema_create(
+        &self,
+        payload: &SchemaCreateAction,
+        signer: &str,
+        state: &mut GridSchemaState,
+    ) -> Result<(), ApplyError> {
+        let schema_name = payload.schema_name();
+        if schema_name.is_empty() {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "Schema name cannot be an empty string",
+            )));
+        }
+
+        if state.get_schema(schema_name)?.is_some() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Schema already exists: {}",
+                schema_name
+            )));
+        }
+
+        let agent = get_active_agent(signer, state)?;
+
+        validate_properties(payload.properties())?;
+
+        let schema = SchemaBuilder::new()
+            .with_name(schema_name.to_string())
+            .with_description(payload.description().to_string())
+            .with_owner(agent.org_id().to_string())
+            .with_properties(payload.properties().to_vec())
+            .build()
+            .map_err(|err| ApplyError::InvalidTransaction(format!("Invalid schema: {}", err)))?;
+
+        state.set_schema(schema_name, schema)
+    }
+
+    fn schema_update(
+        &self,
+        payload: &SchemaUpdateAction,
+        signer: &str,
+        state: &mut GridSchemaState,
+    ) -> Result<(), ApplyError> {
+        let schema_name = payload.schema_name();
+        let schema = match state.get_schema(schema_name)? {
+            Some(schema) => schema,
+            None => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Schema does not exist: {}",
+                    schema_name
+                )));
+            }
+        };
+
+        let agent = get_active_agent(signer, state)?;
+        if agent.org_id() != schema.owner() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer is not associated with the organization that owns the schema: {}",
+                signer
+            )));
+        }
+        if !agent.roles().iter().any(|role| role == SCHEMA_ADMIN_ROLE) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer does not have the {} role: {}",
+                SCHEMA_ADMIN_ROLE, signer
+            )));
+        }
+
+        if payload.properties().is_empty() {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "Schema update must add at least one property",
+            )));
+        }
+
+        let mut properties = schema.properties().to_vec();
+        properties.extend_from_slice(payload.properties());
+        validate_properties(&properties)?;
+
+        let updated_schema = SchemaBuilder::new()
+            .with_name(schema.name().to_string())
+            .with_description(schema.description().to_string())
+            .with_owner(schema.owner().to_string())
+            .with_properties(properties)
+            .build()
+            .map_err(|err| ApplyError::InvalidTransaction(format!("Invalid schema: {}", err)))?;
+
+        state.set_schema(schema_name, updated_schema)
+    }
+}
+
+impl TransactionHandler for GridSchemaTransactionHandler {
+    fn family_name(&self) -> String {
+        self.family_name.clone()
+    }
+
+    fn family_versions(&self) -> Vec<String> {
+        self.family_versions.clone()
+    }
+
+    fn namespaces(&self) -> Vec<String> {
+        self.namespaces.clone()
+    }
+
+    fn apply(
+        &self,
+        request: &TpProcessRequest,
+        context: &mut dyn TransactionContext,
+    ) -> Result<(), ApplyError> {
+        let payload = SchemaPayload::from_bytes(request.get_payload()).map_err(|err| {
+            ApplyError::InvalidTransaction(format!("Cannot deserialize payload: {}", err))
+        })?;
+
+        let signer = request.get_header().get_signer_public_key();
+        let mut state = GridSchemaState::new(context);
+
+        info!("payload: {:?}", payload.action());
+
+        match payload.action() {
+            Action::SchemaCreate => self.schema_create(payload.schema_create(), signer, &mut state),
+            Action::SchemaUpdate => self.schema_update(payload.schema_update(), signer, &mut state),
+        }
+    }
+}
+
+/// Returns the Pike agent for the signer, which must exist and be active in an active
+/// organization
+fn get_active_agent(signer: &str, state: &mut GridSchemaState) -> Result<Agent, ApplyError> {
+    let agent = match state.get_agent(signer)? {
+        Some(agent) => agent,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer is not an agent: {}",
+                signer
+            )));
+        }
+    };
+
+    let organization = match state.get_organization(agent.org_id())? {
+        Some(organization) => organization,
+        None => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization does not exist: {}",
+                agent.org_id()
+            )));
+        }
+    };
+
+    if !is_agent_active(&agent, &organization) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Signer is not an active agent: {}",
+            signer
+        )));
+    }
+
+    Ok(agent)
+}
+
+/// Checks that a list of property definitions is not empty, that every property is named and
+/// that no name is used twice, including within the properties of a STRUCT
+fn validate_properties(properties: &[PropertyDefinition]) -> Result<(), ApplyError> {
+    validate_property_definitions(properties)
+        .map_err(|err| ApplyError::InvalidTransaction(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use grid_sdk::protocol::pike::state::OrganizationStatus;
+    use grid_sdk::protocol::schema::payload::{SchemaCreateBuilder, SchemaUpdateBuilder};
+    use grid_sdk::protocol::schema::state::{DataType, PropertyDefinitionBuilder};
+    use grid_sdk::test_utils::{add_agent, add_organization, MockTransactionContext};
+
+    const ORG: &str = "org_01";
+
+    fn property(name: &str) -> PropertyDefinition {
+        PropertyDefinitionBuilder::new()
+            .with_name(name.to_string())
+            .with_data_type(DataType::String)
+            .build()
+            .unwrap()
+    }
+
+    fn struct_property(
+        name: &str,
+        struct_properties: Vec<PropertyDefinition>,
+    ) -> PropertyDefinition {
+        PropertyDefinitionBuilder::new()
+            .with_name(name.to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_properties(struct_properties)
+            .build()
+            .unwrap()
+    }
+
+    fn schema_create(name: &str, properties: Vec<PropertyDefinition>) -> SchemaCreateAction {
+        SchemaCreateBuilder::new()
+            .with_schema_name(name.to_string())
+            .with_description("description".to_string())
+            .with_properties(properties)
+            .build()
+            .unwrap()
+    }
+
+    fn schema_update(name: &str, properties: Vec<PropertyDefinition>) -> SchemaUpdateAction {
+        SchemaUpdateBuilder::new()
+            .with_schema_name(name.to_string())
+            .with_properties(properties)
+            .build()
+            .unwrap()
+    }
+
+    fn assert_invalid(result: Result<(), ApplyError>) {
+        match result {
+            Err(ApplyError::InvalidTransaction(_)) => (),
+            res => panic!("Expected InvalidTransaction, got {:?}", res),
+        }
+    }
+
+    /// Returns a context in which ORG owns the schema "schema" and has an active agent
+    /// "member" and an active schema admin "admin"
+    fn context_with_schema() -> MockTransactionContext {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(&mut context, "member", ORG, true, vec![]);
+        add_agent(
+            &mut context,
+            "admin",
+            ORG,
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+
+        let handler = GridSchemaTransactionHandler::new();
+        let mut state = GridSchemaState::new(&mut context);
+        handler
+            .schema_create(
+                &schema_create("schema", vec![property("weight")]),
+                "member",
+                &mut state,
+            )
+            .unwrap();
+
+        context
+    }
+
+    #[test]
+    // check that a list of uniquely named properties is valid
+    fn check_validate_properties() {
+        assert!(validate_properties(&[property("weight"), property("color")]).is_ok());
+    }
+
+    #[test]
+    // check that a schema must have at least one property
+    fn check_validate_properties_empty() {
+        assert_invalid(validate_properties(&[]));
+    }
+
+    #[test]
+    // check that every property must be named
+    fn check_validate_properties_empty_name() {
+        assert_invalid(validate_properties(&[property("weight"), property("")]));
+    }
+
+    #[test]
+    // check that two properties cannot have the same name
+    fn check_validate_properties_duplicate_names() {
+        assert_invalid(validate_properties(&[
+            property("weight"),
+            property("weight"),
+        ]));
+    }
+
+    #[test]
+    // check that the properties of a STRUCT are checked for duplicates, and that a nested
+    // property may share its name with a property outside of the STRUCT
+    fn check_validate_properties_struct() {
+        assert!(validate_properties(&[
+            property("weight"),
+            struct_property("shipment", vec![property("weight")]),
+        ])
+        .is_ok());
+
+        assert_invalid(validate_properties(&[struct_property(
+            "shipment",
+            vec![property("weight"), property("weight")],
+        )]));
+    }
+
+    #[test]
+    // check that creating a schema makes the signer's organization its owner
+    fn check_schema_create() {
+        let mut context = context_with_schema();
+        let mut state = GridSchemaState::new(&mut context);
+
+        let schema = state.get_schema("schema").unwrap().unwrap();
+        assert_eq!(schema.owner(), ORG);
+        assert_eq!(schema.properties(), [property("weight")]);
+    }
+
+    #[test]
+    // check that only active agents can create schemas, and that a schema cannot be created
+    // twice
+    fn check_schema_create_unauthorized() {
+        let mut context = context_with_schema();
+        add_agent(&mut context, "inactive", ORG, false, vec![]);
+        let handler = GridSchemaTransactionHandler::new();
+        let mut state = GridSchemaState::new(&mut context);
+
+        let action = schema_create("other", vec![property("weight")]);
+        assert_invalid(handler.schema_create(&action, "unknown", &mut state));
+        assert_invalid(handler.schema_create(&action, "inactive", &mut state));
+
+        let action = schema_create("schema", vec![property("weight")]);
+        assert_invalid(handler.schema_create(&action, "admin", &mut state));
+    }
+
+    #[test]
+    // check that a schema admin of the owning organization can add properties to a schema
+    fn check_schema_update() {
+        let mut context = context_with_schema();
+        let handler = GridSchemaTransactionHandler::new();
+        let mut state = GridSchemaState::new(&mut context);
+
+        handler
+            .schema_update(
+                &schema_update("schema", vec![property("color")]),
+                "admin",
+                &mut state,
+            )
+            .unwrap();
+
+        let schema = state.get_schema("schema").unwrap().unwrap();
+        assert_eq!(schema.properties(), [property("weight"), property("color")]);
+    }
+
+    #[test]
+    // check that a schema can only be updated by a schema admin of the owning organization,
+    // and that an update cannot redefine an existing property
+    fn check_schema_update_unauthorized() {
+        let mut context = context_with_schema();
+        add_organization(&mut context, "org_02", OrganizationStatus::Active);
+        add_agent(
+            &mut context,
+            "other_admin",
+            "org_02",
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+        let handler = GridSchemaTransactionHandler::new();
+        let mut state = GridSchemaState::new(&mut context);
+
+        let action = schema_update("schema", vec![property("color")]);
+        assert_invalid(handler.schema_update(&action, "member", &mut state));
+        assert_invalid(handler.schema_update(&action, "other_admin", &mut state));
+
+        let action = schema_update("schema", vec![property("weight")]);
+        assert_invalid(handler.schema_update(&action, "admin", &mut state));
+    }
+
+    #[test]
+    // check that the agents of a suspended organization can neither create nor update schemas
+    fn check_suspended_organization() {
+        let mut context = context_with_schema();
+        add_organization(&mut context, ORG, OrganizationStatus::Suspended);
+        let handler = GridSchemaTransactionHandler::new();
+        let mut state = GridSchemaState::new(&mut context);
+
+        let action = schema_create("other", vec![property("weight")]);
+        assert_invalid(handler.schema_create(&action, "admin", &mut state));
+
+        let action = schema_update("schema", vec![property("color")]);
+        assert_invalid(handler.schema_update(&action, "admin", &mut state));
+    }
+}
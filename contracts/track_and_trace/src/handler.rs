@@ -18,13 +18,29 @@ use protobuf::RepeatedField;
 
 use std::collections::HashMap;
 
-use grid_sdk::protos::track_and_trace_agent::TrackAndTraceAgent as Agent;
-use grid_sdk::protos::track_and_trace_agent::TrackAndTraceAgentContainer as AgentContainer;
-use grid_sdk::protos::track_and_trace_payload::CreateTrackAndTraceAgentAction as CreateAgentAction;
+use grid_sdk::addressing::{
+    compute_block_info_address, compute_block_info_config_address, compute_pike_agent_address,
+    compute_pike_organization_address, compute_schema_address, compute_setting_address,
+    compute_track_and_trace_property_address, compute_track_and_trace_proposal_address,
+    compute_track_and_trace_record_address, compute_track_and_trace_record_type_address,
+    TRACK_AND_TRACE_NAMESPACE,
+};
+use grid_sdk::protocol::pike::permissions::{has_role, is_agent_active};
+use grid_sdk::protocol::pike::state::{Agent, AgentList, Organization, OrganizationList};
+use grid_sdk::protocol::schema::state::{
+    DataType, LatLongBuilder, PropertyDefinition, PropertyDefinitionBuilder, PropertyValue,
+    PropertyValueBuilder, Schema, SchemaBuilder, SchemaList, SchemaListBuilder,
+};
+use grid_sdk::protocol::schema::validation::{
+    validate_against_definitions, validate_property_definitions, validate_property_values,
+};
+use grid_sdk::protocol::track_and_trace::proposal::Proposal as NativeProposal;
+use grid_sdk::protos::block_info::{BlockInfo, BlockInfoConfig};
 use grid_sdk::protos::track_and_trace_payload::{
     AnswerProposalAction, AnswerProposalAction_Response, CreateProposalAction, CreateRecordAction,
-    CreateRecordTypeAction, FinalizeRecordAction, RevokeReporterAction, SCPayload,
-    SCPayload_Action, UpdatePropertiesAction,
+    ExpireProposalsAction, FinalizeRecordAction, MigrateRecordTypeAction, RevokeReporterAction,
+    SCPayload, SCPayload_Action, TransferCustodyAction, TransferCustodyHandoff,
+    UpdatePropertiesAction,
 };
 use grid_sdk::protos::track_and_trace_property::{
     Property, PropertyContainer, PropertyPage, PropertyPageContainer, PropertyPage_ReportedValue,
@@ -36,25 +52,48 @@ use grid_sdk::protos::track_and_trace_proposal::{
 use grid_sdk::protos::track_and_trace_record::{
     Record, RecordContainer, RecordType, RecordTypeContainer, Record_AssociatedAgent,
 };
+use grid_sdk::protos::{FromBytes, FromProto, IntoBytes};
 use sawtooth_sdk::messages::processor::TpProcessRequest;
+use sawtooth_sdk::messages::setting::Setting;
 use sawtooth_sdk::processor::handler::ApplyError;
 use sawtooth_sdk::processor::handler::TransactionContext;
 use sawtooth_sdk::processor::handler::TransactionHandler;
-
-use addressing::*;
+use sawtooth_sdk::signing;
+use sawtooth_sdk::signing::secp256k1::Secp256k1PublicKey;
 
 const PROPERTY_PAGE_MAX_LENGTH: usize = 256;
 
+// Settings holding comma-separated lists of the Pike roles allowed to perform an action; if a
+// setting is unset, any active Pike agent may perform it
+const CREATE_RECORD_ROLES_SETTING: &str = "grid.track_and_trace.create_record_roles";
+const UPDATE_PROPERTIES_ROLES_SETTING: &str = "grid.track_and_trace.update_properties_roles";
+const PROPOSAL_ROLES_SETTING: &str = "grid.track_and_trace.proposal_roles";
+
+// Setting selecting the timestamp written to records: "payload" (the default) uses the
+// payload's timestamp and "block" uses the timestamp of the latest block in BlockInfo state
+const TIMESTAMP_MODE_SETTING: &str = "grid.track_and_trace.timestamp_mode";
+// Setting holding the number of seconds a payload timestamp may differ from the block
+// timestamp; if unset, DEFAULT_TIMESTAMP_SKEW is used
+const TIMESTAMP_SKEW_SETTING: &str = "grid.track_and_trace.timestamp_skew";
+const DEFAULT_TIMESTAMP_SKEW: u64 = 300;
+
+// Setting holding a comma-separated list of the public keys allowed to migrate record types;
+// if it is unset, no record type can be migrated
+const MIGRATION_ADMINS_SETTING: &str = "grid.track_and_trace.migration_admins";
+// Pike role an agent must hold to migrate a record type into a schema owned by its organization
+const SCHEMA_ADMIN_ROLE: &str = "schema_admin";
+
 #[derive(Debug, Clone)]
 enum Action {
-    CreateAgent(CreateAgentAction),
     CreateRecord(CreateRecordAction),
     FinalizeRecord(FinalizeRecordAction),
-    CreateRecordType(CreateRecordTypeAction),
     UpdateProperties(UpdatePropertiesAction),
     CreateProposal(CreateProposalAction),
     AnswerProposal(AnswerProposalAction),
     RevokeReporter(RevokeReporterAction),
+    MigrateRecordType(MigrateRecordTypeAction),
+    ExpireProposals(ExpireProposalsAction),
+    TransferCustody(TransferCustodyAction),
 }
 
 struct SupplyChainPayload {
@@ -76,13 +115,9 @@ impl SupplyChainPayload {
         let supply_chain_action = payload.get_action();
         let action = match supply_chain_action {
             SCPayload_Action::CREATE_AGENT => {
-                let create_agent = payload.get_create_agent();
-                if create_agent.get_name() == "" {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Agent name cannot be an empty string",
-                    )));
-                }
-                Action::CreateAgent(create_agent.clone())
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Agents are managed by Pike; register the signer as a Pike agent instead",
+                )));
             }
             SCPayload_Action::CREATE_RECORD => {
                 let create_record = payload.get_create_record();
@@ -97,27 +132,9 @@ impl SupplyChainPayload {
                 Action::FinalizeRecord(payload.get_finalize_record().clone())
             }
             SCPayload_Action::CREATE_RECORD_TYPE => {
-                let create_record_type = payload.get_create_record_type();
-                if create_record_type.get_name() == "" {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Record Type name cannot be an empty string",
-                    )));
-                };
-                let properties = create_record_type.get_properties();
-                if properties.len() == 0 {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Record type must have at least one property",
-                    )));
-                }
-                for prop in properties {
-                    if prop.name == "" {
-                        return Err(ApplyError::InvalidTransaction(String::from(
-                            "Property name cannot be an empty string",
-                        )));
-                    }
-                }
-
-                Action::CreateRecordType(create_record_type.clone())
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Record types are defined by Grid schemas; create a schema instead",
+                )));
             }
             SCPayload_Action::UPDATE_PROPERTIES => {
                 Action::UpdateProperties(payload.get_update_properties().clone())
@@ -131,6 +148,27 @@ impl SupplyChainPayload {
             SCPayload_Action::REVOKE_REPORTER => {
                 Action::RevokeReporter(payload.get_revoke_reporter().clone())
             }
+            SCPayload_Action::MIGRATE_RECORD_TYPE => {
+                let migrate_record_type = payload.get_migrate_record_type();
+                if migrate_record_type.get_name() == "" {
+                    return Err(ApplyError::InvalidTransaction(String::from(
+                        "Record Type name cannot be an empty string",
+                    )));
+                }
+                Action::MigrateRecordType(migrate_record_type.clone())
+            }
+            SCPayload_Action::EXPIRE_PROPOSALS => {
+                Action::ExpireProposals(payload.get_expire_proposals().clone())
+            }
+            SCPayload_Action::TRANSFER_CUSTODY => {
+                let transfer_custody = payload.get_transfer_custody();
+                if transfer_custody.get_receiving_agent_signature() == "" {
+                    return Err(ApplyError::InvalidTransaction(String::from(
+                        "Receiving agent signature cannot be an empty string",
+                    )));
+                }
+                Action::TransferCustody(transfer_custody.clone())
+            }
         };
         let timestamp = match payload.get_timestamp() {
             0 => {
@@ -166,7 +204,7 @@ impl<'a> SupplyChainState<'a> {
     }
 
     pub fn get_record(&mut self, record_id: &str) -> Result<Option<Record>, ApplyError> {
-        let address = make_record_address(record_id);
+        let address = compute_track_and_trace_record_address(record_id);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
@@ -191,7 +229,7 @@ impl<'a> SupplyChainState<'a> {
     }
 
     pub fn set_record(&mut self, record_id: &str, record: Record) -> Result<(), ApplyError> {
-        let address = make_record_address(record_id);
+        let address = compute_track_and_trace_record_address(record_id);
         let d = self.context.get_state(vec![address.clone()])?;
         let mut record_container = match d {
             Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
@@ -243,7 +281,7 @@ impl<'a> SupplyChainState<'a> {
     }
 
     pub fn get_record_type(&mut self, type_name: &str) -> Result<Option<RecordType>, ApplyError> {
-        let address = make_record_type_address(type_name);
+        let address = compute_track_and_trace_record_type_address(type_name);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
@@ -268,27 +306,31 @@ impl<'a> SupplyChainState<'a> {
         }
     }
 
-    pub fn set_record_type(
-        &mut self,
-        type_name: &str,
-        record_type: RecordType,
-    ) -> Result<(), ApplyError> {
-        let address = make_record_type_address(type_name);
+    pub fn delete_record_type(&mut self, type_name: &str) -> Result<(), ApplyError> {
+        let address = compute_track_and_trace_record_type_address(type_name);
         let d = self.context.get_state(vec![address.clone()])?;
-        let mut record_types = match d {
+        let mut record_types: RecordTypeContainer = match d {
             Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
                 Ok(record_types) => record_types,
                 Err(_) => {
                     return Err(ApplyError::InternalError(String::from(
-                        "Cannot deserialize record container",
+                        "Cannot deserialize record type container",
                     )));
                 }
             },
-            None => RecordTypeContainer::new(),
+            None => return Ok(()),
         };
 
-        record_types.entries.push(record_type);
-        record_types.entries.sort_by_key(|rt| rt.clone().name);
+        // other record types may share the address, so only remove the state entry when
+        // no record types are left
+        record_types.entries.retain(|rt| rt.name != type_name);
+        if record_types.entries.is_empty() {
+            self.context
+                .delete_state(vec![address])
+                .map_err(|err| ApplyError::InternalError(format!("{}", err)))?;
+            return Ok(());
+        }
+
         let serialized = match record_types.write_to_bytes() {
             Ok(serialized) => serialized,
             Err(_) => {
@@ -305,53 +347,59 @@ impl<'a> SupplyChainState<'a> {
         Ok(())
     }
 
-    pub fn get_agent(&mut self, agent_id: &str) -> Result<Option<Agent>, ApplyError> {
-        let address = make_agent_address(agent_id);
+    pub fn get_schema(&mut self, name: &str) -> Result<Option<Schema>, ApplyError> {
+        let address = compute_schema_address(name);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
-                let agents: AgentContainer = match protobuf::parse_from_bytes(packed.as_slice()) {
-                    Ok(agents) => agents,
+                let schemas = match SchemaList::from_bytes(packed.as_slice()) {
+                    Ok(schemas) => schemas,
                     Err(_) => {
                         return Err(ApplyError::InternalError(String::from(
-                            "Cannot deserialize agent container",
+                            "Cannot deserialize schema list",
                         )));
                     }
                 };
 
-                for agent in agents.get_entries() {
-                    if agent.public_key == agent_id {
-                        return Ok(Some(agent.clone()));
-                    }
-                }
-                Ok(None)
+                Ok(schemas
+                    .schemas()
+                    .iter()
+                    .find(|schema| schema.name() == name)
+                    .cloned())
             }
             None => Ok(None),
         }
     }
 
-    pub fn set_agent(&mut self, agent_id: &str, agent: Agent) -> Result<(), ApplyError> {
-        let address = make_agent_address(agent_id);
+    pub fn set_schema(&mut self, name: &str, schema: Schema) -> Result<(), ApplyError> {
+        let address = compute_schema_address(name);
         let d = self.context.get_state(vec![address.clone()])?;
-        let mut agents = match d {
-            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
-                Ok(agents) => agents,
+        let mut schemas = match d {
+            Some(packed) => match SchemaList::from_bytes(packed.as_slice()) {
+                Ok(schemas) => schemas.schemas().to_vec(),
                 Err(_) => {
                     return Err(ApplyError::InternalError(String::from(
-                        "Cannot deserialize agent container",
+                        "Cannot deserialize schema list",
                     )));
                 }
             },
-            None => AgentContainer::new(),
+            None => vec![],
         };
 
-        agents.entries.push(agent);
-        agents.entries.sort_by_key(|a| a.clone().public_key);
-        let serialized = match agents.write_to_bytes() {
+        schemas.retain(|s| s.name() != name);
+        schemas.push(schema);
+        schemas.sort_by_key(|s| s.name().to_string());
+        let serialized = match SchemaListBuilder::new()
+            .with_schemas(schemas)
+            .build()
+            .map_err(|err| err.to_string())
+            .and_then(|schema_list| schema_list.into_bytes().map_err(|err| err.to_string()))
+        {
             Ok(serialized) => serialized,
-            Err(_) => {
-                return Err(ApplyError::InternalError(String::from(
-                    "Cannot serialize agent container",
+            Err(err) => {
+                return Err(ApplyError::InternalError(format!(
+                    "Cannot serialize schema list: {}",
+                    err
                 )));
             }
         };
@@ -363,12 +411,129 @@ impl<'a> SupplyChainState<'a> {
         Ok(())
     }
 
+    pub fn get_agent(&mut self, public_key: &str) -> Result<Option<Agent>, ApplyError> {
+        let address = compute_pike_agent_address(public_key);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let agents = match AgentList::from_bytes(packed.as_slice()) {
+                    Ok(agents) => agents,
+                    Err(_) => {
+                        return Err(ApplyError::InternalError(String::from(
+                            "Cannot deserialize Pike agent list",
+                        )));
+                    }
+                };
+
+                Ok(agents
+                    .agents()
+                    .iter()
+                    .find(|agent| agent.public_key() == public_key)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_organization(&mut self, org_id: &str) -> Result<Option<Organization>, ApplyError> {
+        let address = compute_pike_organization_address(org_id);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let organizations = match OrganizationList::from_bytes(packed.as_slice()) {
+                    Ok(organizations) => organizations,
+                    Err(_) => {
+                        return Err(ApplyError::InternalError(String::from(
+                            "Cannot deserialize Pike organization list",
+                        )));
+                    }
+                };
+
+                Ok(organizations
+                    .organizations()
+                    .iter()
+                    .find(|organization| organization.org_id() == org_id)
+                    .cloned())
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_setting(&mut self, key: &str) -> Result<Option<String>, ApplyError> {
+        let address = compute_setting_address(key);
+        let d = self.context.get_state(vec![address])?;
+        match d {
+            Some(packed) => {
+                let setting: Setting = match protobuf::parse_from_bytes(packed.as_slice()) {
+                    Ok(setting) => setting,
+                    Err(_) => {
+                        return Err(ApplyError::InternalError(String::from(
+                            "Cannot deserialize setting",
+                        )));
+                    }
+                };
+
+                for entry in setting.get_entries() {
+                    if entry.get_key() == key {
+                        return Ok(Some(entry.get_value().to_string()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the timestamp of the latest block recorded by the BlockInfo transaction processor
+    pub fn get_block_timestamp(&mut self) -> Result<u64, ApplyError> {
+        let config: BlockInfoConfig = match self
+            .context
+            .get_state(vec![compute_block_info_config_address()])?
+        {
+            Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
+                Ok(config) => config,
+                Err(_) => {
+                    return Err(ApplyError::InternalError(String::from(
+                        "Cannot deserialize block info config",
+                    )));
+                }
+            },
+            None => {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Block info is not available in state",
+                )));
+            }
+        };
+
+        let block_num = config.get_latest_block();
+        match self
+            .context
+            .get_state(vec![compute_block_info_address(block_num)])?
+        {
+            Some(packed) => {
+                let block_info: BlockInfo = match protobuf::parse_from_bytes(packed.as_slice()) {
+                    Ok(block_info) => block_info,
+                    Err(_) => {
+                        return Err(ApplyError::InternalError(String::from(
+                            "Cannot deserialize block info",
+                        )));
+                    }
+                };
+                Ok(block_info.get_timestamp())
+            }
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Block info does not exist for block {}",
+                block_num
+            ))),
+        }
+    }
+
     pub fn get_property(
         &mut self,
         record_id: &str,
         property_name: &str,
     ) -> Result<Option<Property>, ApplyError> {
-        let address = make_property_address(record_id, property_name, 0);
+        let address = compute_track_and_trace_property_address(record_id, property_name, 0);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
@@ -399,7 +564,7 @@ impl<'a> SupplyChainState<'a> {
         property_name: &str,
         property: Property,
     ) -> Result<(), ApplyError> {
-        let address = make_property_address(record_id, property_name, 0);
+        let address = compute_track_and_trace_property_address(record_id, property_name, 0);
         let d = self.context.get_state(vec![address.clone()])?;
         let mut property_container = match d {
             Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
@@ -454,7 +619,7 @@ impl<'a> SupplyChainState<'a> {
         property_name: &str,
         page: u32,
     ) -> Result<Option<PropertyPage>, ApplyError> {
-        let address = make_property_address(record_id, property_name, page);
+        let address = compute_track_and_trace_property_address(record_id, property_name, page);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
@@ -486,7 +651,7 @@ impl<'a> SupplyChainState<'a> {
         page_num: u32,
         property_page: PropertyPage,
     ) -> Result<(), ApplyError> {
-        let address = make_property_address(record_id, property_name, page_num);
+        let address = compute_track_and_trace_property_address(record_id, property_name, page_num);
         let d = self.context.get_state(vec![address.clone()])?;
         let mut property_pages = match d {
             Some(packed) => match protobuf::parse_from_bytes(packed.as_slice()) {
@@ -540,7 +705,7 @@ impl<'a> SupplyChainState<'a> {
         record_id: &str,
         agent_id: &str,
     ) -> Result<Option<ProposalContainer>, ApplyError> {
-        let address = make_proposal_address(record_id, agent_id);
+        let address = compute_track_and_trace_proposal_address(record_id, agent_id);
         let d = self.context.get_state(vec![address])?;
         match d {
             Some(packed) => {
@@ -566,7 +731,7 @@ impl<'a> SupplyChainState<'a> {
         agent_id: &str,
         proposals: ProposalContainer,
     ) -> Result<(), ApplyError> {
-        let address = make_proposal_address(record_id, agent_id);
+        let address = compute_track_and_trace_proposal_address(record_id, agent_id);
         let serialized = match proposals.write_to_bytes() {
             Ok(serialized) => serialized,
             Err(_) => {
@@ -595,38 +760,143 @@ impl SupplyChainTransactionHandler {
         SupplyChainTransactionHandler {
             family_name: "grid_track_and_trace".to_string(),
             family_versions: vec!["1.0".to_string()],
-            namespaces: vec![get_supply_chain_prefix().to_string()],
+            namespaces: vec![TRACK_AND_TRACE_NAMESPACE.to_string()],
         }
     }
 
-    fn _create_agent(
+    /// Returns the signer's Pike agent and organization if the agent may act for the
+    /// organization and, when `roles_setting` lists any roles, holds one of them
+    fn _authorize_agent(
         &self,
-        payload: CreateAgentAction,
-        mut state: SupplyChainState,
+        state: &mut SupplyChainState,
         signer: &str,
-        timestamp: u64,
-    ) -> Result<(), ApplyError> {
-        let name = payload.get_name();
-        match state.get_agent(signer) {
-            Ok(Some(_)) => {
+        roles_setting: Option<&str>,
+    ) -> Result<(Agent, Organization), ApplyError> {
+        let agent = match state.get_agent(signer) {
+            Ok(Some(agent)) => agent,
+            Ok(None) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Agent already exists: {}",
-                    name
+                    "Agent is not registered with Pike: {}",
+                    signer
                 )));
             }
-            Ok(None) => (),
             Err(err) => return Err(err),
+        };
+
+        let organization = match state.get_organization(agent.org_id()) {
+            Ok(Some(organization)) => organization,
+            Ok(None) => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Organization does not exist: {}",
+                    agent.org_id()
+                )));
+            }
+            Err(err) => return Err(err),
+        };
+
+        if !is_agent_active(&agent, &organization) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not active: {}",
+                signer
+            )));
+        }
+
+        if let Some(key) = roles_setting {
+            let setting = state.get_setting(key)?.unwrap_or_default();
+            let roles: Vec<&str> = setting
+                .split(',')
+                .map(str::trim)
+                .filter(|role| !role.is_empty())
+                .collect();
+            if !roles.is_empty()
+                && !roles
+                    .iter()
+                    .any(|role| has_role(&agent, &organization, role))
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Agent {} must have one of the roles: {}",
+                    signer,
+                    roles.join(", ")
+                )));
+            }
+        }
+
+        Ok((agent, organization))
+    }
+
+    /// Returns the timestamp to record for a transaction, according to the timestamp mode
+    /// and skew settings
+    fn _get_timestamp(
+        &self,
+        state: &mut SupplyChainState,
+        payload_timestamp: u64,
+    ) -> Result<u64, ApplyError> {
+        let mode = state.get_setting(TIMESTAMP_MODE_SETTING)?;
+        match mode.as_ref().map(|mode| mode.trim()) {
+            None | Some("") | Some("payload") => (),
+            Some("block") => return state.get_block_timestamp(),
+            Some(mode) => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Unknown timestamp mode in setting {}: {}",
+                    TIMESTAMP_MODE_SETTING, mode
+                )));
+            }
         }
 
-        let mut new_agent = Agent::new();
-        new_agent.set_public_key(signer.to_string());
-        new_agent.set_name(name.to_string());
-        new_agent.set_timestamp(timestamp);
+        self._check_timestamp_skew(state, payload_timestamp)?;
+
+        Ok(payload_timestamp)
+    }
+
+    /// Checks that a signer-chosen timestamp is within the timestamp skew of the latest block's
+    /// timestamp
+    fn _check_timestamp_skew(
+        &self,
+        state: &mut SupplyChainState,
+        timestamp: u64,
+    ) -> Result<(), ApplyError> {
+        let skew = match state.get_setting(TIMESTAMP_SKEW_SETTING)? {
+            Some(skew) => match skew.trim().parse::<u64>() {
+                Ok(skew) => skew,
+                Err(_) => {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Setting {} must be a number of seconds: {}",
+                        TIMESTAMP_SKEW_SETTING, skew
+                    )));
+                }
+            },
+            None => DEFAULT_TIMESTAMP_SKEW,
+        };
+
+        let block_timestamp = state.get_block_timestamp()?;
+        let difference = if timestamp > block_timestamp {
+            timestamp - block_timestamp
+        } else {
+            block_timestamp - timestamp
+        };
+        if difference > skew {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Timestamp {} is more than {} seconds from the block timestamp {}",
+                timestamp, skew, block_timestamp
+            )));
+        }
 
-        state.set_agent(signer, new_agent)?;
         Ok(())
     }
 
+    /// Returns the ID of the organization of the Pike agent with the given public key, or an
+    /// empty string if there is no such agent
+    fn _get_org_id(
+        &self,
+        state: &mut SupplyChainState,
+        public_key: &str,
+    ) -> Result<String, ApplyError> {
+        Ok(state
+            .get_agent(public_key)?
+            .map(|agent| agent.org_id().to_string())
+            .unwrap_or_default())
+    }
+
     fn _create_record(
         &self,
         payload: CreateRecordAction,
@@ -634,16 +904,8 @@ impl SupplyChainTransactionHandler {
         signer: &str,
         timestamp: u64,
     ) -> Result<(), ApplyError> {
-        match state.get_agent(signer) {
-            Ok(Some(_)) => (),
-            Ok(None) => {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Agent is not register: {}",
-                    signer
-                )));
-            }
-            Err(err) => return Err(err),
-        }
+        let (agent, _) =
+            self._authorize_agent(&mut state, signer, Some(CREATE_RECORD_ROLES_SETTING))?;
         let record_id = payload.get_record_id();
         match state.get_record(record_id) {
             Ok(Some(_)) => {
@@ -657,69 +919,28 @@ impl SupplyChainTransactionHandler {
         }
 
         let type_name = payload.get_record_type();
-        let record_type = match state.get_record_type(type_name) {
-            Ok(Some(record_type)) => record_type,
+        let schema = match state.get_schema(type_name) {
+            Ok(Some(schema)) => schema,
             Ok(None) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Record Type does not exist {}",
+                    "Schema does not exist {}",
                     type_name
                 )));
             }
             Err(err) => return Err(err),
         };
 
-        let mut type_schemata: HashMap<&str, PropertySchema> = HashMap::new();
-        let mut required_properties: HashMap<&str, PropertySchema> = HashMap::new();
         let mut provided_properties: HashMap<&str, TrackAndTracePropertyValue> = HashMap::new();
-        for property in record_type.get_properties() {
-            type_schemata.insert(property.get_name(), property.clone());
-            if property.get_required() {
-                required_properties.insert(property.get_name(), property.clone());
-            }
-        }
-
+        let mut schema_values = Vec::new();
         for property in payload.get_properties() {
+            schema_values.push(to_schema_value(property, schema.properties())?);
             provided_properties.insert(property.get_name(), property.clone());
         }
 
-        for name in required_properties.keys() {
-            if !provided_properties.contains_key(name) {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Required property {} not provided",
-                    name
-                )));
-            }
+        if let Err(err) = validate_property_values(&schema, &schema_values) {
+            return Err(ApplyError::InvalidTransaction(err.to_string()));
         }
 
-        for (provided_name, provided_properties) in provided_properties.clone() {
-            let required_type = match type_schemata.get(provided_name) {
-                Some(required_type) => required_type.data_type,
-                None => {
-                    return Err(ApplyError::InvalidTransaction(format!(
-                        "Provided property {} is not in schemata",
-                        provided_name
-                    )));
-                }
-            };
-            let provided_type = provided_properties.data_type;
-            if provided_type != required_type {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Value provided for {} is the wrong type",
-                    provided_name
-                )));
-            };
-
-            let is_delayed = match type_schemata.get(provided_name) {
-                Some(property_schema) => property_schema.delayed,
-                None => false,
-            };
-            if is_delayed {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Property is 'delayed', and cannot be set at record creation: {}",
-                    provided_name
-                )));
-            };
-        }
         let mut new_record = Record::new();
         new_record.set_record_id(record_id.to_string());
         new_record.set_record_type(type_name.to_string());
@@ -728,6 +949,7 @@ impl SupplyChainTransactionHandler {
         let mut owner = Record_AssociatedAgent::new();
         owner.set_agent_id(signer.to_string());
         owner.set_timestamp(timestamp);
+        owner.set_org_id(agent.org_id().to_string());
         new_record.owners.push(owner.clone());
         new_record.custodians.push(owner.clone());
 
@@ -738,23 +960,26 @@ impl SupplyChainTransactionHandler {
         reporter.set_authorized(true);
         reporter.set_index(0);
 
-        for (property_name, property) in type_schemata {
+        for definition in schema.properties() {
+            let property_name = definition.name();
             let mut new_property = Property::new();
             new_property.set_name(property_name.to_string());
             new_property.set_record_id(record_id.to_string());
-            new_property.set_data_type(property.get_data_type());
+            new_property.set_data_type(to_property_data_type(definition.data_type()));
             new_property.reporters.push(reporter.clone());
             new_property.set_current_page(1);
             new_property.set_wrapped(false);
-            new_property.set_fixed(property.get_fixed());
-            new_property.set_number_exponent(property.get_number_exponent());
-            new_property.set_enum_options(RepeatedField::from_vec(
-                property.get_enum_options().to_vec(),
-            ));
+            new_property.set_fixed(false);
+            new_property.set_number_exponent(*definition.number_exponent());
+            new_property
+                .set_enum_options(RepeatedField::from_vec(definition.enum_options().to_vec()));
             new_property.set_struct_properties(RepeatedField::from_vec(
-                property.get_struct_properties().to_vec(),
+                definition
+                    .struct_properties()
+                    .iter()
+                    .map(to_property_schema)
+                    .collect(),
             ));
-            new_property.set_unit(property.get_unit().to_string());
 
             state.set_property(record_id, property_name, new_property.clone())?;
 
@@ -788,6 +1013,7 @@ impl SupplyChainTransactionHandler {
         mut state: SupplyChainState,
         signer: &str,
     ) -> Result<(), ApplyError> {
+        let (agent, _) = self._authorize_agent(&mut state, signer, None)?;
         let record_id = payload.get_record_id();
         let final_record = match state.get_record(record_id) {
             Ok(Some(final_record)) => final_record,
@@ -816,7 +1042,9 @@ impl SupplyChainTransactionHandler {
             }
         };
 
-        if owner.agent_id != signer || custodian.agent_id != signer {
+        if !is_associated(owner, signer, agent.org_id())
+            || !is_associated(custodian, signer, agent.org_id())
+        {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Must be owner and custodian to finalize record"
             )));
@@ -835,42 +1063,86 @@ impl SupplyChainTransactionHandler {
         Ok(())
     }
 
-    fn _create_record_type(
+    /// Converts a record type into a schema owned by the signer's organization
+    ///
+    /// The schema is written to the Grid schema namespace (621dee), outside this family's own
+    /// namespace, so the transaction must list the schema address as an input and output.
+    fn _migrate_record_type(
         &self,
-        payload: CreateRecordTypeAction,
+        payload: MigrateRecordTypeAction,
         mut state: SupplyChainState,
         signer: &str,
     ) -> Result<(), ApplyError> {
-        match state.get_agent(signer) {
-            Ok(Some(_)) => (),
+        let admins = state
+            .get_setting(MIGRATION_ADMINS_SETTING)?
+            .unwrap_or_default();
+        if !admins
+            .split(',')
+            .map(str::trim)
+            .any(|admin| admin == signer)
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer is not listed in {}: {}",
+                MIGRATION_ADMINS_SETTING, signer
+            )));
+        }
+
+        let (agent, organization) = self._authorize_agent(&mut state, signer, None)?;
+        if !has_role(&agent, &organization, SCHEMA_ADMIN_ROLE) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer does not have the {} role: {}",
+                SCHEMA_ADMIN_ROLE, signer
+            )));
+        }
+
+        let name = payload.get_name();
+        let record_type = match state.get_record_type(name) {
+            Ok(Some(record_type)) => record_type,
             Ok(None) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Agent is not register: {}",
-                    signer
+                    "Record Type does not exist {}",
+                    name
                 )));
             }
             Err(err) => return Err(err),
-        }
-        let name = payload.get_name();
-        let mut provided_properties: HashMap<&str, PropertySchema> = HashMap::new();
-        for property in payload.get_properties() {
-            provided_properties.insert(property.get_name(), property.clone());
-        }
-        match state.get_record_type(name) {
+        };
+        match state.get_schema(name) {
             Ok(Some(_)) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Record type already exists: {}",
-                    signer
+                    "Schema already exists: {}",
+                    name
                 )));
             }
             Ok(None) => (),
             Err(err) => return Err(err),
         }
-        let mut record_type = RecordType::new();
-        record_type.set_name(name.to_string());
-        record_type.set_properties(RepeatedField::from_vec(payload.get_properties().to_vec()));
 
-        state.set_record_type(name, record_type)?;
+        let properties = record_type
+            .get_properties()
+            .iter()
+            .map(to_property_definition)
+            .collect::<Result<Vec<PropertyDefinition>, ApplyError>>()?;
+        // the schema must pass the same checks as one created through the schema contract
+        validate_property_definitions(&properties).map_err(|err| {
+            ApplyError::InvalidTransaction(format!("Cannot migrate record type {}: {}", name, err))
+        })?;
+        let schema = match SchemaBuilder::new()
+            .with_name(name.to_string())
+            .with_owner(agent.org_id().to_string())
+            .with_properties(properties)
+            .build()
+        {
+            Ok(schema) => schema,
+            Err(err) => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Cannot build schema from record type: {}",
+                    err
+                )));
+            }
+        };
+
+        state.set_schema(name, schema)?;
+        state.delete_record_type(name)?;
 
         Ok(())
     }
@@ -882,6 +1154,7 @@ impl SupplyChainTransactionHandler {
         signer: &str,
         timestamp: u64,
     ) -> Result<(), ApplyError> {
+        self._authorize_agent(&mut state, signer, Some(UPDATE_PROPERTIES_ROLES_SETTING))?;
         let record_id = payload.get_record_id();
         let update_record = match state.get_record(record_id) {
             Ok(Some(update_record)) => update_record,
@@ -901,6 +1174,17 @@ impl SupplyChainTransactionHandler {
             )));
         }
 
+        let schema = match state.get_schema(update_record.get_record_type()) {
+            Ok(Some(schema)) => schema,
+            Ok(None) => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Schema does not exist: {}",
+                    update_record.get_record_type()
+                )));
+            }
+            Err(err) => return Err(err),
+        };
+
         let updates = payload.get_properties();
 
         for update in updates {
@@ -948,6 +1232,17 @@ impl SupplyChainTransactionHandler {
                 )));
             }
 
+            let definitions: Vec<PropertyDefinition> = schema
+                .properties()
+                .iter()
+                .filter(|definition| definition.name() == name)
+                .cloned()
+                .collect();
+            let value = to_schema_value(update, &definitions)?;
+            if let Err(err) = validate_against_definitions(&definitions, &[value]) {
+                return Err(ApplyError::InvalidTransaction(err.to_string()));
+            }
+
             let page_number = prop.get_current_page();
             let mut page = match state.get_property_page(record_id, name, page_number) {
                 Ok(Some(page)) => page,
@@ -1011,23 +1306,35 @@ impl SupplyChainTransactionHandler {
         let receiving_agent = payload.receiving_agent;
         let role = payload.role;
         let properties = payload.properties;
+        let terms = payload.terms;
+        let expiry = payload.expiry;
+
+        let (agent, _) = self._authorize_agent(&mut state, signer, Some(PROPOSAL_ROLES_SETTING))?;
+
+        // expiry is measured against block time, which the signer cannot choose
+        let block_timestamp = state.get_block_timestamp()?;
+        if expiry != 0 && expiry <= block_timestamp {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Proposal expiry must be later than the block timestamp {}: {}",
+                block_timestamp, expiry
+            )));
+        }
 
-        match state.get_agent(signer) {
+        let receiver = match state.get_agent(&receiving_agent) {
             Ok(Some(agent)) => agent,
             Ok(None) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Issuing agent does not exist: {}",
-                    signer
+                    "Receiving agent does not exist: {}",
+                    receiving_agent
                 )));
             }
             Err(err) => return Err(err),
         };
-
-        match state.get_agent(&receiving_agent) {
-            Ok(Some(agent)) => agent,
-            Ok(None) => {
+        match state.get_organization(receiver.org_id()) {
+            Ok(Some(ref organization)) if is_agent_active(&receiver, organization) => (),
+            Ok(_) => {
                 return Err(ApplyError::InvalidTransaction(format!(
-                    "Receiving agent does not exist: {}",
+                    "Receiving agent is not active: {}",
                     receiving_agent
                 )));
             }
@@ -1040,8 +1347,17 @@ impl SupplyChainTransactionHandler {
             Err(err) => return Err(err),
         };
 
-        let mut open_proposals = Vec::<Proposal>::new();
-        for prop in proposals.get_entries() {
+        // open proposals that have expired no longer block a new proposal
+        for prop in proposals.entries.iter_mut() {
+            if prop.status == Proposal_Status::OPEN
+                && to_native_proposal(prop)?.is_expired(block_timestamp)
+            {
+                prop.set_status(Proposal_Status::EXPIRED);
+            }
+        }
+
+        let mut open_proposals = Vec::<Proposal>::new();
+        for prop in proposals.get_entries() {
             if prop.status == Proposal_Status::OPEN {
                 open_proposals.push(prop.clone());
             }
@@ -1085,7 +1401,7 @@ impl SupplyChainTransactionHandler {
                     )));
                 }
             };
-            if owner.get_agent_id() != signer {
+            if !is_associated(owner, signer, agent.org_id()) {
                 return Err(ApplyError::InvalidTransaction(String::from(
                     "Only the owner can create a proposal to change ownership",
                 )));
@@ -1102,7 +1418,7 @@ impl SupplyChainTransactionHandler {
                 }
             };
 
-            if custodian.get_agent_id() != signer {
+            if !is_associated(custodian, signer, agent.org_id()) {
                 return Err(ApplyError::InvalidTransaction(String::from(
                     "Only the custodian can create a proposal to change custodianship",
                 )));
@@ -1117,6 +1433,8 @@ impl SupplyChainTransactionHandler {
         new_proposal.set_role(role);
         new_proposal.set_properties(properties);
         new_proposal.set_status(Proposal_Status::OPEN);
+        new_proposal.set_terms(terms);
+        new_proposal.set_expiry(expiry);
 
         proposals.entries.push(new_proposal);
         proposals.entries.sort_by_key(|p| {
@@ -1211,6 +1529,17 @@ impl SupplyChainTransactionHandler {
                         "Only the receiving agent can Accept a proposal",
                     )));
                 };
+                let block_timestamp = state.get_block_timestamp()?;
+                if to_native_proposal(&current_proposal)?.is_expired(block_timestamp) {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Proposal expired at {}",
+                        current_proposal.get_expiry()
+                    )));
+                }
+                let (agent, _) =
+                    self._authorize_agent(&mut state, signer, Some(PROPOSAL_ROLES_SETTING))?;
+                let issuing_org_id =
+                    self._get_org_id(&mut state, current_proposal.get_issuing_agent())?;
 
                 let mut proposal_record = match state.get_record(record_id) {
                     Ok(Some(record)) => record,
@@ -1243,7 +1572,11 @@ impl SupplyChainTransactionHandler {
 
                 match role {
                     Proposal_Role::OWNER => {
-                        if owner.get_agent_id() != current_proposal.get_issuing_agent() {
+                        if !is_associated(
+                            &owner,
+                            current_proposal.get_issuing_agent(),
+                            &issuing_org_id,
+                        ) {
                             current_proposal.status = Proposal_Status::CANCELED;
                             info!("Record owner does not match the issuing agent of the proposal");
                             // remove old proposal and replace with new one
@@ -1267,47 +1600,60 @@ impl SupplyChainTransactionHandler {
                         let mut new_owner = Record_AssociatedAgent::new();
                         new_owner.set_agent_id(receiving_agent.to_string());
                         new_owner.set_timestamp(timestamp);
+                        new_owner.set_org_id(agent.org_id().to_string());
                         proposal_record.owners.push(new_owner);
                         state.set_record(record_id, proposal_record.clone())?;
 
-                        let record_type =
-                            match state.get_record_type(proposal_record.get_record_type()) {
-                                Ok(Some(record_type)) => record_type,
+                        let schema = match state.get_schema(proposal_record.get_record_type()) {
+                            Ok(Some(schema)) => schema,
+                            Ok(None) => {
+                                return Err(ApplyError::InvalidTransaction(format!(
+                                    "Schema does not exist: {}",
+                                    proposal_record.get_record_type()
+                                )));
+                            }
+                            Err(err) => return Err(err),
+                        };
+
+                        // ownership passing within an organization keeps its reporters
+                        let same_organization =
+                            is_associated(&owner, receiving_agent, agent.org_id());
+
+                        for definition in schema.properties() {
+                            let mut prop = match state.get_property(record_id, definition.name()) {
+                                Ok(Some(prop)) => prop,
                                 Ok(None) => {
-                                    return Err(ApplyError::InvalidTransaction(format!(
-                                        "RecordType does not exist: {}",
-                                        proposal_record.get_record_type()
+                                    return Err(ApplyError::InvalidTransaction(String::from(
+                                        "Property does not exist",
                                     )));
                                 }
                                 Err(err) => return Err(err),
                             };
 
-                        for prop_schema in record_type.get_properties() {
-                            let mut prop =
-                                match state.get_property(record_id, prop_schema.get_name()) {
-                                    Ok(Some(prop)) => prop,
-                                    Ok(None) => {
-                                        return Err(ApplyError::InvalidTransaction(String::from(
-                                            "Property does not exist",
-                                        )));
-                                    }
-                                    Err(err) => return Err(err),
-                                };
-
                             let mut authorized = false;
                             let mut new_reporters: Vec<Property_Reporter> = Vec::new();
                             let temp_prob = prop.clone();
                             let reporters = temp_prob.get_reporters();
                             for reporter in reporters {
-                                if reporter.get_public_key() == owner.get_agent_id() {
-                                    let mut new_reporter = reporter.clone();
-                                    new_reporter.set_authorized(false);
-                                    new_reporters.push(new_reporter);
-                                } else if reporter.get_public_key() == receiving_agent {
+                                // reporters from the previous owner's organization lose their
+                                // authorization along with the ownership
+                                let reporter_org_id =
+                                    self._get_org_id(&mut state, reporter.get_public_key())?;
+                                if reporter.get_public_key() == receiving_agent {
                                     let mut new_reporter = reporter.clone();
                                     new_reporter.set_authorized(true);
                                     authorized = true;
                                     new_reporters.push(new_reporter);
+                                } else if !same_organization
+                                    && is_associated(
+                                        &owner,
+                                        reporter.get_public_key(),
+                                        &reporter_org_id,
+                                    )
+                                {
+                                    let mut new_reporter = reporter.clone();
+                                    new_reporter.set_authorized(false);
+                                    new_reporters.push(new_reporter);
                                 } else {
                                     new_reporters.push(reporter.clone());
                                 }
@@ -1327,14 +1673,18 @@ impl SupplyChainTransactionHandler {
                         current_proposal.status = Proposal_Status::ACCEPTED;
                     }
                     Proposal_Role::CUSTODIAN => {
-                        if custodian.get_agent_id() != current_proposal.get_issuing_agent() {
+                        if !is_associated(
+                            &custodian,
+                            current_proposal.get_issuing_agent(),
+                            &issuing_org_id,
+                        ) {
                             current_proposal.status = Proposal_Status::CANCELED;
                             info!(
                                 "Record custodian does not match the issuing agent of the proposal"
                             );
                             // remove old proposal and replace with new one
                             proposals.entries.remove(proposal_index);
-                            proposals.entries.push(current_proposal.clone());
+                            proposals.entries.push(current_proposal);
                             proposals.entries.sort_by_key(|p| {
                                 (
                                     p.clone().record_id,
@@ -1345,19 +1695,25 @@ impl SupplyChainTransactionHandler {
                             state.set_proposal_container(
                                 &record_id,
                                 &receiving_agent,
-                                proposals.clone(),
+                                proposals,
                             )?;
+                            return Ok(());
                         }
 
                         let mut new_custodian = Record_AssociatedAgent::new();
                         new_custodian.set_agent_id(receiving_agent.to_string());
                         new_custodian.set_timestamp(timestamp);
+                        new_custodian.set_org_id(agent.org_id().to_string());
                         proposal_record.custodians.push(new_custodian.clone());
                         state.set_record(record_id, proposal_record)?;
                         current_proposal.status = Proposal_Status::ACCEPTED;
                     }
                     Proposal_Role::REPORTER => {
-                        if owner.get_agent_id() != current_proposal.get_issuing_agent() {
+                        if !is_associated(
+                            &owner,
+                            current_proposal.get_issuing_agent(),
+                            &issuing_org_id,
+                        ) {
                             current_proposal.status = Proposal_Status::CANCELED;
                             info!("Record owner does not match the issuing agent of the proposal");
                             // remove old proposal and replace with new one
@@ -1416,12 +1772,155 @@ impl SupplyChainTransactionHandler {
         Ok(())
     }
 
+    fn _expire_proposals(
+        &self,
+        payload: ExpireProposalsAction,
+        mut state: SupplyChainState,
+    ) -> Result<(), ApplyError> {
+        let record_id = payload.get_record_id();
+        let receiving_agent = payload.get_receiving_agent();
+
+        let mut proposals = match state.get_proposal_container(record_id, receiving_agent) {
+            Ok(Some(proposals)) => proposals,
+            Ok(None) => {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Proposal does not exist",
+                )));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let block_timestamp = state.get_block_timestamp()?;
+        let mut expired = false;
+        for prop in proposals.entries.iter_mut() {
+            if prop.get_record_id() == record_id
+                && prop.get_receiving_agent() == receiving_agent
+                && prop.status == Proposal_Status::OPEN
+                && to_native_proposal(prop)?.is_expired(block_timestamp)
+            {
+                prop.set_status(Proposal_Status::EXPIRED);
+                expired = true;
+            }
+        }
+
+        if !expired {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "No expired proposals found for record {} for {}",
+                record_id, receiving_agent
+            )));
+        }
+
+        state.set_proposal_container(record_id, receiving_agent, proposals)?;
+
+        Ok(())
+    }
+
+    fn _transfer_custody(
+        &self,
+        payload: TransferCustodyAction,
+        mut state: SupplyChainState,
+        signer: &str,
+        timestamp: u64,
+    ) -> Result<(), ApplyError> {
+        let handoff: TransferCustodyHandoff =
+            match protobuf::parse_from_bytes(payload.get_handoff()) {
+                Ok(handoff) => handoff,
+                Err(_) => {
+                    return Err(ApplyError::InvalidTransaction(String::from(
+                        "Cannot deserialize custody handoff",
+                    )));
+                }
+            };
+        let record_id = handoff.get_record_id();
+        let receiving_agent = handoff.get_receiving_agent();
+
+        if receiving_agent == signer {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "Cannot transfer custody to the signer",
+            )));
+        }
+
+        // the receiving agent agrees to take custody by signing the exact handoff bytes
+        if !verify_signature(
+            receiving_agent,
+            payload.get_receiving_agent_signature(),
+            payload.get_handoff(),
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Custody handoff is not signed by the receiving agent: {}",
+                receiving_agent
+            )));
+        }
+
+        let (agent, _) = self._authorize_agent(&mut state, signer, Some(PROPOSAL_ROLES_SETTING))?;
+        let (receiving, _) =
+            self._authorize_agent(&mut state, receiving_agent, Some(PROPOSAL_ROLES_SETTING))?;
+
+        let mut record = match state.get_record(record_id) {
+            Ok(Some(record)) => record,
+            Ok(None) => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Record does not exist: {}",
+                    record_id
+                )));
+            }
+            Err(err) => return Err(err),
+        };
+
+        if record.get_field_final() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Record is final: {}",
+                record_id
+            )));
+        }
+
+        let custodian = match record.custodians.last() {
+            Some(custodian) => custodian.clone(),
+            None => {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Custodian not found",
+                )));
+            }
+        };
+
+        if !is_associated(&custodian, signer, agent.org_id()) {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "Only the custodian can transfer custody",
+            )));
+        }
+
+        // a handoff is bound to the custodian it hands over from: custodians are only ever
+        // added, so once it has been applied the count no longer matches and it cannot be
+        // replayed, even if custody later returns to the same agent
+        if handoff.get_custodian_count() as usize != record.custodians.len() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Custody handoff is for custodian {} of record {}, which has {} custodians",
+                handoff.get_custodian_count(),
+                record_id,
+                record.custodians.len()
+            )));
+        }
+
+        // the receiving agent's agreement is only good within the skew of block time
+        self._check_timestamp_skew(&mut state, handoff.get_timestamp())?;
+
+        let mut new_custodian = Record_AssociatedAgent::new();
+        new_custodian.set_agent_id(receiving_agent.to_string());
+        new_custodian.set_timestamp(timestamp);
+        new_custodian.set_org_id(receiving.org_id().to_string());
+        record.custodians.push(new_custodian);
+        state.set_record(record_id, record)?;
+
+        Ok(())
+    }
+
     fn _revoke_reporter(
         &self,
         payload: RevokeReporterAction,
         mut state: SupplyChainState,
         signer: &str,
     ) -> Result<(), ApplyError> {
+        let (agent, _) = self._authorize_agent(&mut state, signer, None)?;
         let record_id = payload.get_record_id();
         let reporter_id = payload.get_reporter_id();
         let properties = payload.get_properties();
@@ -1446,7 +1945,7 @@ impl SupplyChainTransactionHandler {
             }
         };
 
-        if owner.get_agent_id() != signer {
+        if !is_associated(owner, signer, agent.org_id()) {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Must be owner to revoke reporters"
             )));
@@ -1548,13 +2047,6 @@ impl SupplyChainTransactionHandler {
                 reported_value.set_enum_value(enum_index as u32)
             }
             PropertySchema_DataType::STRUCT => {
-                match self
-                    ._validate_struct_values(&value.struct_values, &property.struct_properties)
-                {
-                    Ok(_) => (),
-                    Err(e) => return Err(e),
-                }
-
                 let struct_values = RepeatedField::from_vec(value.get_struct_values().to_vec());
                 reported_value.set_struct_values(struct_values)
             }
@@ -1564,51 +2056,201 @@ impl SupplyChainTransactionHandler {
         };
         Ok(reported_value)
     }
+}
 
-    fn _validate_struct_values(
-        &self,
-        struct_values: &RepeatedField<TrackAndTracePropertyValue>,
-        schema_values: &RepeatedField<PropertySchema>,
-    ) -> Result<(), ApplyError> {
-        if struct_values.len() != schema_values.len() {
+/// Returns whether `signature` is a valid secp256k1 signature of `message` by the holder of
+/// `public_key`; malformed keys and signatures are treated as invalid
+fn verify_signature(public_key: &str, signature: &str, message: &[u8]) -> bool {
+    let context = match signing::create_context("secp256k1") {
+        Ok(context) => context,
+        Err(_) => return false,
+    };
+    let public_key = match Secp256k1PublicKey::from_hex(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    context
+        .verify(signature, message, &public_key)
+        .unwrap_or(false)
+}
+
+/// Converts a stored proposal into the SDK's native proposal
+fn to_native_proposal(proposal: &Proposal) -> Result<NativeProposal, ApplyError> {
+    NativeProposal::from_proto(proposal.clone())
+        .map_err(|err| ApplyError::InternalError(format!("Cannot convert proposal: {}", err)))
+}
+
+/// Returns whether an owner or custodian entry belongs to the given agent's organization.
+/// Entries written before records were held by Pike organizations only name the agent.
+fn is_associated(associated: &Record_AssociatedAgent, public_key: &str, org_id: &str) -> bool {
+    if associated.get_org_id().is_empty() {
+        associated.get_agent_id() == public_key
+    } else {
+        associated.get_org_id() == org_id
+    }
+}
+
+/// Converts a Track and Trace property value into a schema property value so it can be
+/// checked against the schema's property definitions; enum values are given by name and
+/// become the index of the option in the property's definition
+fn to_schema_value(
+    value: &TrackAndTracePropertyValue,
+    definitions: &[PropertyDefinition],
+) -> Result<PropertyValue, ApplyError> {
+    let definition = match definitions
+        .iter()
+        .find(|def| def.name() == value.get_name())
+    {
+        Some(definition) => definition,
+        None => {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Provided struct does not match schema length: {:?} != {:?}",
-                struct_values.len(),
-                schema_values.len(),
+                "Provided property {} is not in schema",
+                value.get_name()
             )));
         }
+    };
 
-        for schema in schema_values.iter() {
-            let value = match struct_values.iter().find(|val| val.name == schema.name) {
-                Some(val) => val,
+    let builder = PropertyValueBuilder::new().with_name(value.get_name().to_string());
+    let builder = match value.get_data_type() {
+        PropertySchema_DataType::TYPE_UNSET => {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "DataType is not set",
+            )));
+        }
+        PropertySchema_DataType::BYTES => builder
+            .with_data_type(DataType::Bytes)
+            .with_bytes_value(value.get_bytes_value().to_vec()),
+        PropertySchema_DataType::BOOLEAN => builder
+            .with_data_type(DataType::Boolean)
+            .with_boolean_value(value.get_boolean_value()),
+        PropertySchema_DataType::NUMBER => builder
+            .with_data_type(DataType::Number)
+            .with_number_value(value.get_number_value()),
+        PropertySchema_DataType::STRING => builder
+            .with_data_type(DataType::String)
+            .with_string_value(value.get_string_value().to_string()),
+        PropertySchema_DataType::ENUM => {
+            let enum_name = value.get_enum_value();
+            let enum_index = match definition
+                .enum_options()
+                .iter()
+                .position(|name| name == enum_name)
+            {
+                Some(index) => index,
                 None => {
                     return Err(ApplyError::InvalidTransaction(format!(
-                        "Provided struct missing required property from schema: {}",
-                        schema.name,
+                        "Provided enum name is not a valid option: {}",
+                        enum_name,
                     )));
                 }
             };
-
-            if value.data_type != schema.data_type {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Struct property \"{}\" must have data type: {:?}",
-                    schema.name, schema.data_type,
-                )));
-            }
-
-            if schema.data_type == PropertySchema_DataType::STRUCT {
-                match self._validate_struct_values(&value.struct_values, &schema.struct_properties)
-                {
-                    Ok(_) => (),
-                    Err(e) => return Err(e),
-                }
-            }
+            builder
+                .with_data_type(DataType::Enum)
+                .with_enum_value(enum_index as u32)
         }
+        PropertySchema_DataType::STRUCT => {
+            builder.with_data_type(DataType::Struct).with_struct_values(
+                value
+                    .get_struct_values()
+                    .iter()
+                    .map(|struct_value| {
+                        to_schema_value(struct_value, definition.struct_properties())
+                    })
+                    .collect::<Result<Vec<PropertyValue>, ApplyError>>()?,
+            )
+        }
+        PropertySchema_DataType::LOCATION => {
+            let location = LatLongBuilder::new()
+                .with_latitude(value.get_location_value().get_latitude())
+                .with_longitude(value.get_location_value().get_longitude())
+                .build()
+                .map_err(|err| ApplyError::InvalidTransaction(err.to_string()))?;
+            builder
+                .with_data_type(DataType::Location)
+                .with_location_value(location)
+        }
+    };
 
-        Ok(())
+    builder
+        .build()
+        .map_err(|err| ApplyError::InvalidTransaction(err.to_string()))
+}
+
+fn to_property_data_type(data_type: &DataType) -> PropertySchema_DataType {
+    match data_type {
+        DataType::Bytes => PropertySchema_DataType::BYTES,
+        DataType::Boolean => PropertySchema_DataType::BOOLEAN,
+        DataType::Number => PropertySchema_DataType::NUMBER,
+        DataType::String => PropertySchema_DataType::STRING,
+        DataType::Enum => PropertySchema_DataType::ENUM,
+        DataType::Struct => PropertySchema_DataType::STRUCT,
+        DataType::Location => PropertySchema_DataType::LOCATION,
     }
 }
 
+/// Describes the members of a STRUCT property of a record from their schema definitions
+fn to_property_schema(definition: &PropertyDefinition) -> PropertySchema {
+    let mut property_schema = PropertySchema::new();
+    property_schema.set_name(definition.name().to_string());
+    property_schema.set_data_type(to_property_data_type(definition.data_type()));
+    property_schema.set_required(*definition.required());
+    property_schema.set_number_exponent(*definition.number_exponent());
+    property_schema.set_enum_options(RepeatedField::from_vec(definition.enum_options().to_vec()));
+    property_schema.set_struct_properties(RepeatedField::from_vec(
+        definition
+            .struct_properties()
+            .iter()
+            .map(to_property_schema)
+            .collect(),
+    ));
+    property_schema
+}
+
+/// Converts a property of a record type into a schema property definition; the property's
+/// unit, if any, becomes the definition's description
+fn to_property_definition(
+    property_schema: &PropertySchema,
+) -> Result<PropertyDefinition, ApplyError> {
+    let data_type = match property_schema.get_data_type() {
+        PropertySchema_DataType::TYPE_UNSET => {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "DataType is not set for property: {}",
+                property_schema.get_name()
+            )));
+        }
+        PropertySchema_DataType::BYTES => DataType::Bytes,
+        PropertySchema_DataType::BOOLEAN => DataType::Boolean,
+        PropertySchema_DataType::NUMBER => DataType::Number,
+        PropertySchema_DataType::STRING => DataType::String,
+        PropertySchema_DataType::ENUM => DataType::Enum,
+        PropertySchema_DataType::STRUCT => DataType::Struct,
+        PropertySchema_DataType::LOCATION => DataType::Location,
+    };
+
+    let struct_properties = property_schema
+        .get_struct_properties()
+        .iter()
+        .map(to_property_definition)
+        .collect::<Result<Vec<PropertyDefinition>, ApplyError>>()?;
+
+    PropertyDefinitionBuilder::new()
+        .with_name(property_schema.get_name().to_string())
+        .with_data_type(data_type)
+        .with_required(property_schema.get_required())
+        .with_description(property_schema.get_unit().to_string())
+        .with_number_exponent(property_schema.get_number_exponent())
+        .with_enum_options(property_schema.get_enum_options().to_vec())
+        .with_struct_properties(struct_properties)
+        .build()
+        .map_err(|err| {
+            ApplyError::InvalidTransaction(format!(
+                "Cannot convert property {}: {}",
+                property_schema.get_name(),
+                err
+            ))
+        })
+}
+
 impl TransactionHandler for SupplyChainTransactionHandler {
     fn family_name(&self) -> String {
         return self.family_name.clone();
@@ -1642,48 +2284,861 @@ impl TransactionHandler for SupplyChainTransactionHandler {
         };
 
         let signer = request.get_header().get_signer_public_key();
-        let state = SupplyChainState::new(context);
+        let mut state = SupplyChainState::new(context);
+        let timestamp = self._get_timestamp(&mut state, payload.get_timestamp())?;
 
         info!(
             "payload: {:?} {} {} {}",
             payload.get_action(),
-            payload.get_timestamp(),
+            timestamp,
             request.get_header().get_inputs()[0],
             request.get_header().get_outputs()[0]
         );
 
         match payload.get_action() {
-            Action::CreateAgent(agent_payload) => {
-                self._create_agent(agent_payload, state, signer, payload.get_timestamp())?
-            }
             Action::CreateRecord(record_payload) => {
-                self._create_record(record_payload, state, signer, payload.get_timestamp())?
+                self._create_record(record_payload, state, signer, timestamp)?
             }
             Action::FinalizeRecord(finalize_payload) => {
                 self._finalize_record(finalize_payload, state, signer)?
             }
-            Action::CreateRecordType(record_type_payload) => {
-                self._create_record_type(record_type_payload, state, signer)?
+            Action::UpdateProperties(update_properties_payload) => {
+                self._update_properties(update_properties_payload, state, signer, timestamp)?
             }
-            Action::UpdateProperties(update_properties_payload) => self._update_properties(
-                update_properties_payload,
-                state,
-                signer,
-                payload.get_timestamp(),
-            )?,
             Action::CreateProposal(proposal_payload) => {
-                self._create_proposal(proposal_payload, state, signer, payload.get_timestamp())?
-            }
-            Action::AnswerProposal(answer_proposal_payload) => self._answer_proposal(
-                answer_proposal_payload,
-                state,
-                signer,
-                payload.get_timestamp(),
-            )?,
+                self._create_proposal(proposal_payload, state, signer, timestamp)?
+            }
+            Action::AnswerProposal(answer_proposal_payload) => {
+                self._answer_proposal(answer_proposal_payload, state, signer, timestamp)?
+            }
             Action::RevokeReporter(revoke_reporter_payload) => {
                 self._revoke_reporter(revoke_reporter_payload, state, signer)?
             }
+            Action::MigrateRecordType(migrate_record_type_payload) => {
+                self._migrate_record_type(migrate_record_type_payload, state, signer)?
+            }
+            Action::ExpireProposals(expire_proposals_payload) => {
+                self._expire_proposals(expire_proposals_payload, state)?
+            }
+            Action::TransferCustody(transfer_custody_payload) => {
+                self._transfer_custody(transfer_custody_payload, state, signer, timestamp)?
+            }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use grid_sdk::protocol::pike::state::OrganizationStatus;
+    use grid_sdk::test_utils::{add_agent, add_organization, MockTransactionContext};
+    use sawtooth_sdk::messages::setting::Setting_Entry;
+    use sawtooth_sdk::signing::secp256k1::Secp256k1PrivateKey;
+
+    const ORG: &str = "org_01";
+    const OTHER_ORG: &str = "org_02";
+    const RECORD_ID: &str = "record_01";
+    const RECORD_TYPE: &str = "type_01";
+    const PRIVATE_KEY: &str = "2f1e7b7a130d7ba9da0068b3bb0ba1d79e7e77110302c9f746c3c2a63fe40088";
+    const OTHER_PRIVATE_KEY: &str =
+        "8f4b2a0ee5d1c6b3a7e9f0d2c4b6a8e1f3d5c7b9a0e2f4d6c8b0a1e3f5d7c9b2";
+
+    fn set_setting(context: &mut MockTransactionContext, key: &str, value: &str) {
+        let mut entry = Setting_Entry::new();
+        entry.set_key(key.to_string());
+        entry.set_value(value.to_string());
+        let mut setting = Setting::new();
+        setting.set_entries(RepeatedField::from_vec(vec![entry]));
+
+        let mut entries = HashMap::new();
+        entries.insert(
+            compute_setting_address(key),
+            setting.write_to_bytes().unwrap(),
+        );
+        context.set_state(entries).unwrap();
+    }
+
+    fn set_block_timestamp(context: &mut MockTransactionContext, timestamp: u64) {
+        let mut config = BlockInfoConfig::new();
+        config.set_latest_block(10);
+        let mut block_info = BlockInfo::new();
+        block_info.set_block_num(10);
+        block_info.set_timestamp(timestamp);
+
+        let mut entries = HashMap::new();
+        entries.insert(
+            compute_block_info_config_address(),
+            config.write_to_bytes().unwrap(),
+        );
+        entries.insert(
+            compute_block_info_address(10),
+            block_info.write_to_bytes().unwrap(),
+        );
+        context.set_state(entries).unwrap();
+    }
+
+    fn get_timestamp(
+        context: &mut MockTransactionContext,
+        payload_timestamp: u64,
+    ) -> Result<u64, ApplyError> {
+        let handler = SupplyChainTransactionHandler::new();
+        let mut state = SupplyChainState::new(context);
+        handler._get_timestamp(&mut state, payload_timestamp)
+    }
+
+    fn associated_agent(agent_id: &str, org_id: &str) -> Record_AssociatedAgent {
+        let mut associated = Record_AssociatedAgent::new();
+        associated.set_agent_id(agent_id.to_string());
+        associated.set_org_id(org_id.to_string());
+        associated
+    }
+
+    fn reporter(public_key: &str, index: u32) -> Property_Reporter {
+        let mut reporter = Property_Reporter::new();
+        reporter.set_public_key(public_key.to_string());
+        reporter.set_authorized(true);
+        reporter.set_index(index);
+        reporter
+    }
+
+    /// Adds a record owned and held by `owner` of the organization ORG
+    fn add_record(context: &mut MockTransactionContext, owner: &str) {
+        let mut record = Record::new();
+        record.set_record_id(RECORD_ID.to_string());
+        record.set_record_type(RECORD_TYPE.to_string());
+        record.owners.push(associated_agent(owner, ORG));
+        record.custodians.push(associated_agent(owner, ORG));
+
+        let mut state = SupplyChainState::new(context);
+        state.set_record(RECORD_ID, record).unwrap();
+    }
+
+    /// Adds a record owned by `owner` with a single property reported by `reporters`, and an
+    /// open proposal expiring at `expiry` to make `receiving_agent` its owner
+    fn add_owner_proposal(
+        context: &mut MockTransactionContext,
+        owner: &str,
+        receiving_agent: &str,
+        reporters: Vec<Property_Reporter>,
+        expiry: u64,
+    ) {
+        add_record(context, owner);
+        let mut state = SupplyChainState::new(context);
+
+        let definition = PropertyDefinitionBuilder::new()
+            .with_name("weight".to_string())
+            .with_data_type(DataType::String)
+            .build()
+            .unwrap();
+        let schema = SchemaBuilder::new()
+            .with_name(RECORD_TYPE.to_string())
+            .with_owner(ORG.to_string())
+            .with_properties(vec![definition])
+            .build()
+            .unwrap();
+        state.set_schema(RECORD_TYPE, schema).unwrap();
+
+        let mut property = Property::new();
+        property.set_name("weight".to_string());
+        property.set_record_id(RECORD_ID.to_string());
+        property.set_reporters(RepeatedField::from_vec(reporters));
+        state.set_property(RECORD_ID, "weight", property).unwrap();
+
+        let mut proposal = Proposal::new();
+        proposal.set_record_id(RECORD_ID.to_string());
+        proposal.set_issuing_agent(owner.to_string());
+        proposal.set_receiving_agent(receiving_agent.to_string());
+        proposal.set_role(Proposal_Role::OWNER);
+        proposal.set_status(Proposal_Status::OPEN);
+        proposal.set_expiry(expiry);
+        let mut proposals = ProposalContainer::new();
+        proposals.entries.push(proposal);
+        state
+            .set_proposal_container(RECORD_ID, receiving_agent, proposals)
+            .unwrap();
+    }
+
+    /// Adds an open proposal from `issuing_agent` to make `receiving_agent` a custodian of the
+    /// record
+    fn add_custodian_proposal(
+        context: &mut MockTransactionContext,
+        issuing_agent: &str,
+        receiving_agent: &str,
+    ) {
+        let mut proposal = Proposal::new();
+        proposal.set_record_id(RECORD_ID.to_string());
+        proposal.set_issuing_agent(issuing_agent.to_string());
+        proposal.set_receiving_agent(receiving_agent.to_string());
+        proposal.set_role(Proposal_Role::CUSTODIAN);
+        proposal.set_status(Proposal_Status::OPEN);
+        let mut proposals = ProposalContainer::new();
+        proposals.entries.push(proposal);
+
+        let mut state = SupplyChainState::new(context);
+        state
+            .set_proposal_container(RECORD_ID, receiving_agent, proposals)
+            .unwrap();
+    }
+
+    fn accept_owner_proposal(
+        context: &mut MockTransactionContext,
+        receiving_agent: &str,
+        timestamp: u64,
+    ) -> Result<(), ApplyError> {
+        accept_proposal(context, receiving_agent, Proposal_Role::OWNER, timestamp)
+    }
+
+    fn accept_proposal(
+        context: &mut MockTransactionContext,
+        receiving_agent: &str,
+        role: Proposal_Role,
+        timestamp: u64,
+    ) -> Result<(), ApplyError> {
+        let mut answer = AnswerProposalAction::new();
+        answer.set_record_id(RECORD_ID.to_string());
+        answer.set_receiving_agent(receiving_agent.to_string());
+        answer.set_role(role);
+        answer.set_response(AnswerProposalAction_Response::ACCEPT);
+
+        let handler = SupplyChainTransactionHandler::new();
+        let state = SupplyChainState::new(context);
+        handler._answer_proposal(answer, state, receiving_agent, timestamp)
+    }
+
+    fn expire_proposals(
+        context: &mut MockTransactionContext,
+        receiving_agent: &str,
+    ) -> Result<(), ApplyError> {
+        let mut expire = ExpireProposalsAction::new();
+        expire.set_record_id(RECORD_ID.to_string());
+        expire.set_receiving_agent(receiving_agent.to_string());
+
+        let handler = SupplyChainTransactionHandler::new();
+        let state = SupplyChainState::new(context);
+        handler._expire_proposals(expire, state)
+    }
+
+    fn get_proposal_status(
+        context: &mut MockTransactionContext,
+        receiving_agent: &str,
+    ) -> Proposal_Status {
+        let mut state = SupplyChainState::new(context);
+        let proposals = state
+            .get_proposal_container(RECORD_ID, receiving_agent)
+            .unwrap()
+            .unwrap();
+        proposals.get_entries()[0].get_status()
+    }
+
+    fn is_authorized_reporter(context: &mut MockTransactionContext, public_key: &str) -> bool {
+        let mut state = SupplyChainState::new(context);
+        let property = state.get_property(RECORD_ID, "weight").unwrap().unwrap();
+        property
+            .get_reporters()
+            .iter()
+            .any(|reporter| reporter.get_public_key() == public_key && reporter.get_authorized())
+    }
+
+    fn authorize(
+        context: &mut MockTransactionContext,
+        signer: &str,
+        roles_setting: Option<&str>,
+    ) -> Result<(Agent, Organization), ApplyError> {
+        let handler = SupplyChainTransactionHandler::new();
+        let mut state = SupplyChainState::new(context);
+        handler._authorize_agent(&mut state, signer, roles_setting)
+    }
+
+    fn property_schema(name: &str) -> PropertySchema {
+        let mut property_schema = PropertySchema::new();
+        property_schema.set_name(name.to_string());
+        property_schema.set_data_type(PropertySchema_DataType::STRING);
+        property_schema
+    }
+
+    fn add_record_type(context: &mut MockTransactionContext, properties: Vec<PropertySchema>) {
+        let mut record_type = RecordType::new();
+        record_type.set_name(RECORD_TYPE.to_string());
+        record_type.set_properties(RepeatedField::from_vec(properties));
+        let mut record_types = RecordTypeContainer::new();
+        record_types.entries.push(record_type);
+
+        let mut entries = HashMap::new();
+        entries.insert(
+            compute_track_and_trace_record_type_address(RECORD_TYPE),
+            record_types.write_to_bytes().unwrap(),
+        );
+        context.set_state(entries).unwrap();
+    }
+
+    fn migrate_record_type(
+        context: &mut MockTransactionContext,
+        signer: &str,
+    ) -> Result<(), ApplyError> {
+        let mut migrate = MigrateRecordTypeAction::new();
+        migrate.set_name(RECORD_TYPE.to_string());
+
+        let handler = SupplyChainTransactionHandler::new();
+        let state = SupplyChainState::new(context);
+        handler._migrate_record_type(migrate, state, signer)
+    }
+
+    fn public_key(private_key: &str) -> String {
+        let context = signing::create_context("secp256k1").unwrap();
+        let private_key = Secp256k1PrivateKey::from_hex(private_key).unwrap();
+        context.get_public_key(&private_key).unwrap().as_hex()
+    }
+
+    fn sign(private_key: &str, message: &[u8]) -> String {
+        let context = signing::create_context("secp256k1").unwrap();
+        let private_key = Secp256k1PrivateKey::from_hex(private_key).unwrap();
+        context.sign(message, &private_key).unwrap()
+    }
+
+    /// Returns a handoff of the record to the agent holding `private_key`, with the agent's
+    /// signature over it
+    fn custody_handoff(
+        private_key: &str,
+        timestamp: u64,
+        custodian_count: u32,
+    ) -> TransferCustodyAction {
+        let mut handoff = TransferCustodyHandoff::new();
+        handoff.set_record_id(RECORD_ID.to_string());
+        handoff.set_receiving_agent(public_key(private_key));
+        handoff.set_timestamp(timestamp);
+        handoff.set_custodian_count(custodian_count);
+        let handoff_bytes = handoff.write_to_bytes().unwrap();
+
+        let mut transfer_custody = TransferCustodyAction::new();
+        transfer_custody.set_receiving_agent_signature(sign(private_key, &handoff_bytes));
+        transfer_custody.set_handoff(handoff_bytes);
+        transfer_custody
+    }
+
+    fn transfer_custody(
+        context: &mut MockTransactionContext,
+        transfer_custody: TransferCustodyAction,
+        signer: &str,
+    ) -> Result<(), ApplyError> {
+        let handler = SupplyChainTransactionHandler::new();
+        let state = SupplyChainState::new(context);
+        handler._transfer_custody(transfer_custody, state, signer, 10_000)
+    }
+
+    fn get_custodians(context: &mut MockTransactionContext) -> Vec<String> {
+        let mut state = SupplyChainState::new(context);
+        let record = state.get_record(RECORD_ID).unwrap().unwrap();
+        record
+            .get_custodians()
+            .iter()
+            .map(|custodian| custodian.get_agent_id().to_string())
+            .collect()
+    }
+
+    fn assert_invalid<T: ::std::fmt::Debug>(result: Result<T, ApplyError>) {
+        match result {
+            Err(ApplyError::InvalidTransaction(_)) => (),
+            res => panic!("Expected InvalidTransaction, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that an entry with an organization is associated with every agent of that
+    // organization
+    fn check_is_associated() {
+        let entry = associated_agent("agent_key", ORG);
+
+        assert!(is_associated(&entry, "agent_key", ORG));
+        assert!(is_associated(&entry, "other_key", ORG));
+        assert!(!is_associated(&entry, "agent_key", OTHER_ORG));
+    }
+
+    #[test]
+    // check that an entry written before organizations were recorded is only associated with
+    // its own agent
+    fn check_is_associated_legacy() {
+        let entry = associated_agent("agent_key", "");
+
+        assert!(is_associated(&entry, "agent_key", ORG));
+        assert!(is_associated(&entry, "agent_key", ""));
+        assert!(!is_associated(&entry, "other_key", ORG));
+        assert!(!is_associated(&entry, "other_key", ""));
+    }
+
+    #[test]
+    // check that an active agent of an active organization is authorized
+    fn check_authorize_agent() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(&mut context, "agent_key", ORG, true, vec![]);
+
+        let (agent, organization) = authorize(&mut context, "agent_key", None).unwrap();
+        assert_eq!(agent.public_key(), "agent_key");
+        assert_eq!(organization.org_id(), ORG);
+    }
+
+    #[test]
+    // check that agents that are not registered, are inactive or whose organization is missing
+    // or suspended are not authorized
+    fn check_authorize_agent_inactive() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Suspended);
+        add_agent(&mut context, "inactive_key", ORG, false, vec![]);
+        add_agent(&mut context, "suspended_key", OTHER_ORG, true, vec![]);
+        add_agent(&mut context, "orphan_key", "missing_org", true, vec![]);
+
+        assert_invalid(authorize(&mut context, "unknown_key", None));
+        assert_invalid(authorize(&mut context, "inactive_key", None));
+        assert_invalid(authorize(&mut context, "suspended_key", None));
+        assert_invalid(authorize(&mut context, "orphan_key", None));
+    }
+
+    #[test]
+    // check that an agent must hold one of the roles listed in a roles setting, and that an
+    // unset setting allows any active agent
+    fn check_authorize_agent_roles() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(
+            &mut context,
+            "reporter_key",
+            ORG,
+            true,
+            vec!["reporter".to_string()],
+        );
+        add_agent(&mut context, "agent_key", ORG, true, vec![]);
+
+        assert!(authorize(&mut context, "agent_key", Some(CREATE_RECORD_ROLES_SETTING)).is_ok());
+
+        set_setting(&mut context, CREATE_RECORD_ROLES_SETTING, "admin, reporter");
+        assert!(authorize(
+            &mut context,
+            "reporter_key",
+            Some(CREATE_RECORD_ROLES_SETTING)
+        )
+        .is_ok());
+        assert_invalid(authorize(
+            &mut context,
+            "agent_key",
+            Some(CREATE_RECORD_ROLES_SETTING),
+        ));
+    }
+
+    #[test]
+    // check that accepting ownership from another organization de-authorizes the reporters of
+    // the previous owner's organization
+    fn check_accept_owner_other_organization() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(&mut context, "owner_key", ORG, true, vec![]);
+        add_agent(&mut context, "reporter_key", ORG, true, vec![]);
+        add_agent(&mut context, "new_owner_key", OTHER_ORG, true, vec![]);
+        add_owner_proposal(
+            &mut context,
+            "owner_key",
+            "new_owner_key",
+            vec![reporter("owner_key", 0), reporter("reporter_key", 1)],
+            0,
+        );
+        set_block_timestamp(&mut context, 10_000);
+
+        accept_owner_proposal(&mut context, "new_owner_key", 10_000).unwrap();
+
+        assert!(!is_authorized_reporter(&mut context, "owner_key"));
+        assert!(!is_authorized_reporter(&mut context, "reporter_key"));
+        assert!(is_authorized_reporter(&mut context, "new_owner_key"));
+    }
+
+    #[test]
+    // check that accepting ownership within the owner's organization keeps its reporters
+    fn check_accept_owner_same_organization() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(&mut context, "owner_key", ORG, true, vec![]);
+        add_agent(&mut context, "reporter_key", ORG, true, vec![]);
+        add_agent(&mut context, "new_owner_key", ORG, true, vec![]);
+        add_owner_proposal(
+            &mut context,
+            "owner_key",
+            "new_owner_key",
+            vec![reporter("owner_key", 0), reporter("reporter_key", 1)],
+            0,
+        );
+        set_block_timestamp(&mut context, 10_000);
+
+        accept_owner_proposal(&mut context, "new_owner_key", 10_000).unwrap();
+
+        assert!(is_authorized_reporter(&mut context, "owner_key"));
+        assert!(is_authorized_reporter(&mut context, "reporter_key"));
+        assert!(is_authorized_reporter(&mut context, "new_owner_key"));
+    }
+
+    #[test]
+    // check that a record type is migrated into a schema owned by the signer's organization
+    fn check_migrate_record_type() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(
+            &mut context,
+            "admin_key",
+            ORG,
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+        set_setting(&mut context, MIGRATION_ADMINS_SETTING, "admin_key");
+        add_record_type(
+            &mut context,
+            vec![property_schema("weight"), property_schema("color")],
+        );
+
+        migrate_record_type(&mut context, "admin_key").unwrap();
+
+        let mut state = SupplyChainState::new(&mut context);
+        let schema = state.get_schema(RECORD_TYPE).unwrap().unwrap();
+        assert_eq!(schema.owner(), ORG);
+        assert_eq!(schema.properties().len(), 2);
+        assert!(state.get_record_type(RECORD_TYPE).unwrap().is_none());
+    }
+
+    #[test]
+    // check that a record type whose properties would make an invalid schema is not migrated
+    fn check_migrate_record_type_invalid_properties() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(
+            &mut context,
+            "admin_key",
+            ORG,
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+        set_setting(&mut context, MIGRATION_ADMINS_SETTING, "admin_key");
+
+        add_record_type(
+            &mut context,
+            vec![property_schema("weight"), property_schema("weight")],
+        );
+        assert_invalid(migrate_record_type(&mut context, "admin_key"));
+
+        add_record_type(&mut context, vec![property_schema("")]);
+        assert_invalid(migrate_record_type(&mut context, "admin_key"));
+
+        let mut dimensions = property_schema("dimensions");
+        dimensions.set_data_type(PropertySchema_DataType::STRUCT);
+        dimensions.set_struct_properties(RepeatedField::from_vec(vec![
+            property_schema("unit"),
+            property_schema("unit"),
+        ]));
+        add_record_type(&mut context, vec![dimensions]);
+        assert_invalid(migrate_record_type(&mut context, "admin_key"));
+
+        let mut state = SupplyChainState::new(&mut context);
+        assert!(state.get_schema(RECORD_TYPE).unwrap().is_none());
+        assert!(state.get_record_type(RECORD_TYPE).unwrap().is_some());
+    }
+
+    #[test]
+    // check that only the agents listed in the migration admins setting can migrate a record
+    // type, whatever roles other organizations' agents hold
+    fn check_migrate_record_type_unauthorized() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(
+            &mut context,
+            "admin_key",
+            ORG,
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+        add_agent(
+            &mut context,
+            "other_admin_key",
+            OTHER_ORG,
+            true,
+            vec![SCHEMA_ADMIN_ROLE.to_string()],
+        );
+        add_record_type(&mut context, vec![property_schema("weight")]);
+
+        assert_invalid(migrate_record_type(&mut context, "admin_key"));
+
+        set_setting(&mut context, MIGRATION_ADMINS_SETTING, "admin_key");
+        assert_invalid(migrate_record_type(&mut context, "other_admin_key"));
+
+        let mut state = SupplyChainState::new(&mut context);
+        assert!(state.get_schema(RECORD_TYPE).unwrap().is_none());
+        assert!(state.get_record_type(RECORD_TYPE).unwrap().is_some());
+    }
+
+    #[test]
+    // check that without a skew setting, payload timestamps must be within the default skew
+    // of the block timestamp
+    fn check_timestamp_default_skew() {
+        let mut context = MockTransactionContext::default();
+        set_block_timestamp(&mut context, 10_000);
+
+        assert_eq!(get_timestamp(&mut context, 10_000).unwrap(), 10_000);
+        assert_eq!(
+            get_timestamp(&mut context, 10_000 + DEFAULT_TIMESTAMP_SKEW).unwrap(),
+            10_000 + DEFAULT_TIMESTAMP_SKEW
+        );
+        assert_invalid(get_timestamp(&mut context, 10_001 + DEFAULT_TIMESTAMP_SKEW));
+        assert_invalid(get_timestamp(&mut context, 9_999 - DEFAULT_TIMESTAMP_SKEW));
+    }
+
+    #[test]
+    // check that payload timestamps inside the configured skew window are used and those
+    // outside it are rejected
+    fn check_timestamp_skew() {
+        let mut context = MockTransactionContext::default();
+        set_block_timestamp(&mut context, 10_000);
+        set_setting(&mut context, TIMESTAMP_SKEW_SETTING, " 60 ");
+
+        assert_eq!(get_timestamp(&mut context, 9_940).unwrap(), 9_940);
+        assert_eq!(get_timestamp(&mut context, 10_060).unwrap(), 10_060);
+        assert_invalid(get_timestamp(&mut context, 9_939));
+        assert_invalid(get_timestamp(&mut context, 10_061));
+    }
+
+    #[test]
+    // check that a skew setting that is not a number of seconds is rejected
+    fn check_timestamp_skew_not_a_number() {
+        let mut context = MockTransactionContext::default();
+        set_block_timestamp(&mut context, 10_000);
+
+        set_setting(&mut context, TIMESTAMP_SKEW_SETTING, "one minute");
+        assert_invalid(get_timestamp(&mut context, 10_000));
+
+        set_setting(&mut context, TIMESTAMP_SKEW_SETTING, "-60");
+        assert_invalid(get_timestamp(&mut context, 10_000));
+    }
+
+    #[test]
+    // check that the block mode uses the block timestamp whatever the payload timestamp is
+    fn check_timestamp_block_mode() {
+        let mut context = MockTransactionContext::default();
+        set_block_timestamp(&mut context, 10_000);
+        set_setting(&mut context, TIMESTAMP_MODE_SETTING, "block");
+
+        assert_eq!(get_timestamp(&mut context, 1).unwrap(), 10_000);
+    }
+
+    #[test]
+    // check that an unknown timestamp mode is rejected
+    fn check_timestamp_unknown_mode() {
+        let mut context = MockTransactionContext::default();
+        set_block_timestamp(&mut context, 10_000);
+        set_setting(&mut context, TIMESTAMP_MODE_SETTING, "validator");
+
+        assert_invalid(get_timestamp(&mut context, 10_000));
+    }
+
+    #[test]
+    // check that a proposal past its expiry by block time cannot be accepted, even with a
+    // backdated payload timestamp
+    fn check_accept_expired_proposal() {
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_agent(&mut context, "owner_key", ORG, true, vec![]);
+        add_agent(&mut context, "new_owner_key", ORG, true, vec![]);
+        add_owner_proposal(&mut context, "owner_key", "new_owner_key", vec![], 9_000);
+        set_block_timestamp(&mut context, 10_000);
+
+        assert_invalid(accept_owner_proposal(&mut context, "new_owner_key", 8_000));
+        assert_eq!(
+            get_proposal_status(&mut context, "new_owner_key"),
+            Proposal_Status::OPEN
+        );
+    }
+
+    #[test]
+    // check that proposals are only expired once block time is past their expiry
+    fn check_expire_proposals() {
+        let mut context = MockTransactionContext::default();
+        add_owner_proposal(&mut context, "owner_key", "new_owner_key", vec![], 11_000);
+
+        set_block_timestamp(&mut context, 11_000);
+        assert_invalid(expire_proposals(&mut context, "new_owner_key"));
+        assert_eq!(
+            get_proposal_status(&mut context, "new_owner_key"),
+            Proposal_Status::OPEN
+        );
+
+        set_block_timestamp(&mut context, 11_001);
+        expire_proposals(&mut context, "new_owner_key").unwrap();
+        assert_eq!(
+            get_proposal_status(&mut context, "new_owner_key"),
+            Proposal_Status::EXPIRED
+        );
+    }
+
+    #[test]
+    // check that a signature by the holder of the public key over the message is valid
+    fn check_verify_signature() {
+        let signature = sign(PRIVATE_KEY, b"handoff");
+
+        assert!(verify_signature(
+            &public_key(PRIVATE_KEY),
+            &signature,
+            b"handoff"
+        ));
+    }
+
+    #[test]
+    // check that a signature by another key or over other bytes is invalid
+    fn check_verify_signature_mismatch() {
+        let signature = sign(PRIVATE_KEY, b"handoff");
+
+        assert!(!verify_signature(
+            &public_key(OTHER_PRIVATE_KEY),
+            &signature,
+            b"handoff"
+        ));
+        assert!(!verify_signature(
+            &public_key(PRIVATE_KEY),
+            &signature,
+            b"handoff!"
+        ));
+    }
+
+    #[test]
+    // check that malformed public keys and signatures are invalid rather than errors
+    fn check_verify_signature_malformed() {
+        let public_key = public_key(PRIVATE_KEY);
+        let signature = sign(PRIVATE_KEY, b"handoff");
+
+        assert!(!verify_signature("not hex", &signature, b"handoff"));
+        assert!(!verify_signature(&public_key[2..], &signature, b"handoff"));
+        assert!(!verify_signature(&public_key, "not hex", b"handoff"));
+        assert!(!verify_signature(&public_key, &signature[2..], b"handoff"));
+        assert!(!verify_signature(&public_key, "", b"handoff"));
+    }
+
+    #[test]
+    // check that a handoff signed by the receiving agent moves custody to its organization
+    fn check_transfer_custody() {
+        let custodian = public_key(PRIVATE_KEY);
+        let receiver = public_key(OTHER_PRIVATE_KEY);
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(&mut context, &custodian, ORG, true, vec![]);
+        add_agent(&mut context, &receiver, OTHER_ORG, true, vec![]);
+        add_record(&mut context, &custodian);
+        set_block_timestamp(&mut context, 10_000);
+
+        transfer_custody(
+            &mut context,
+            custody_handoff(OTHER_PRIVATE_KEY, 10_000, 1),
+            &custodian,
+        )
+        .unwrap();
+
+        assert_eq!(get_custodians(&mut context), vec![custodian, receiver]);
+    }
+
+    #[test]
+    // check that a custodian proposal is canceled, without changing the custodians, if its
+    // issuer has since handed custody to another organization
+    fn check_accept_custodian_proposal_from_former_custodian() {
+        let custodian = public_key(PRIVATE_KEY);
+        let receiver = public_key(OTHER_PRIVATE_KEY);
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(&mut context, &custodian, ORG, true, vec![]);
+        add_agent(&mut context, &receiver, OTHER_ORG, true, vec![]);
+        add_agent(&mut context, "proposed_key", ORG, true, vec![]);
+        add_record(&mut context, &custodian);
+        add_custodian_proposal(&mut context, &custodian, "proposed_key");
+        set_block_timestamp(&mut context, 10_000);
+
+        transfer_custody(
+            &mut context,
+            custody_handoff(OTHER_PRIVATE_KEY, 10_000, 1),
+            &custodian,
+        )
+        .unwrap();
+
+        accept_proposal(
+            &mut context,
+            "proposed_key",
+            Proposal_Role::CUSTODIAN,
+            10_000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_proposal_status(&mut context, "proposed_key"),
+            Proposal_Status::CANCELED
+        );
+        assert_eq!(get_custodians(&mut context), vec![custodian, receiver]);
+    }
+
+    #[test]
+    // check that a handoff is rejected if it was signed for another custodian or is outside
+    // the timestamp skew of block time
+    fn check_transfer_custody_stale_handoff() {
+        let custodian = public_key(PRIVATE_KEY);
+        let receiver = public_key(OTHER_PRIVATE_KEY);
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(&mut context, &custodian, ORG, true, vec![]);
+        add_agent(&mut context, &receiver, OTHER_ORG, true, vec![]);
+        add_record(&mut context, &custodian);
+        set_block_timestamp(&mut context, 10_000);
+
+        assert_invalid(transfer_custody(
+            &mut context,
+            custody_handoff(OTHER_PRIVATE_KEY, 10_000, 2),
+            &custodian,
+        ));
+        assert_invalid(transfer_custody(
+            &mut context,
+            custody_handoff(OTHER_PRIVATE_KEY, 10_001 + DEFAULT_TIMESTAMP_SKEW, 1),
+            &custodian,
+        ));
+        assert_invalid(transfer_custody(
+            &mut context,
+            custody_handoff(OTHER_PRIVATE_KEY, 9_999 - DEFAULT_TIMESTAMP_SKEW, 1),
+            &custodian,
+        ));
+
+        assert_eq!(get_custodians(&mut context), vec![custodian]);
+    }
+
+    #[test]
+    // check that a handoff cannot be replayed once custody has returned to the agent that
+    // gave it away
+    fn check_transfer_custody_replay() {
+        let custodian = public_key(PRIVATE_KEY);
+        let receiver = public_key(OTHER_PRIVATE_KEY);
+        let mut context = MockTransactionContext::default();
+        add_organization(&mut context, ORG, OrganizationStatus::Active);
+        add_organization(&mut context, OTHER_ORG, OrganizationStatus::Active);
+        add_agent(&mut context, &custodian, ORG, true, vec![]);
+        add_agent(&mut context, &receiver, OTHER_ORG, true, vec![]);
+        add_record(&mut context, &custodian);
+        set_block_timestamp(&mut context, 10_000);
+
+        let handoff = custody_handoff(OTHER_PRIVATE_KEY, 10_000, 1);
+        transfer_custody(&mut context, handoff.clone(), &custodian).unwrap();
+        transfer_custody(
+            &mut context,
+            custody_handoff(PRIVATE_KEY, 10_000, 2),
+            &receiver,
+        )
+        .unwrap();
+
+        assert_invalid(transfer_custody(&mut context, handoff, &custodian));
+        assert_eq!(
+            get_custodians(&mut context),
+            vec![custodian.clone(), receiver, custodian]
+        );
+    }
+}
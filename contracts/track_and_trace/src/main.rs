@@ -14,7 +14,6 @@
 
 #[macro_use]
 extern crate clap;
-extern crate crypto;
 extern crate log4rs;
 #[macro_use]
 extern crate log;
@@ -23,7 +22,6 @@ extern crate protobuf;
 extern crate rustc_serialize;
 extern crate sawtooth_sdk;
 
-mod addressing;
 mod handler;
 
 use log::LogLevelFilter;
@@ -0,0 +1,77 @@
+-- Copyright 2019 Cargill Incorporated
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+-- -----------------------------------------------------------------------------
+
+CREATE TABLE IF NOT EXISTS agent (
+    id SERIAL PRIMARY KEY,
+    public_key TEXT NOT NULL,
+    org_id TEXT NOT NULL,
+    active BOOLEAN NOT NULL,
+    roles TEXT NOT NULL,
+    metadata TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS agent_public_key_idx ON agent (public_key);
+
+CREATE TABLE IF NOT EXISTS organization (
+    id SERIAL PRIMARY KEY,
+    org_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    address TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS organization_org_id_idx ON organization (org_id);
+
+CREATE TABLE IF NOT EXISTS grid_schema (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    owner TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS grid_schema_name_idx ON grid_schema (name);
+
+CREATE TABLE IF NOT EXISTS grid_property_definition (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    schema_name TEXT NOT NULL,
+    data_type TEXT NOT NULL,
+    required BOOLEAN NOT NULL,
+    description TEXT NOT NULL,
+    number_exponent BIGINT NOT NULL,
+    enum_options TEXT NOT NULL,
+    parent_name TEXT
+);
+
+CREATE INDEX IF NOT EXISTS grid_property_definition_schema_name_idx
+    ON grid_property_definition (schema_name);
+
+CREATE TABLE IF NOT EXISTS record (
+    id SERIAL PRIMARY KEY,
+    record_id TEXT NOT NULL,
+    schema TEXT NOT NULL,
+    finalized BOOLEAN NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS record_record_id_idx ON record (record_id);
+
+CREATE TABLE IF NOT EXISTS associated_agent (
+    id SERIAL PRIMARY KEY,
+    record_id TEXT NOT NULL,
+    role TEXT NOT NULL,
+    agent_id TEXT NOT NULL,
+    timestamp BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS associated_agent_record_id_idx ON associated_agent (record_id);
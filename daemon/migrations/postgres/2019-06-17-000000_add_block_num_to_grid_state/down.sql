@@ -0,0 +1,27 @@
+-- Copyright 2019 Cargill Incorporated
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+-- -----------------------------------------------------------------------------
+
+ALTER TABLE agent DROP COLUMN start_block_num;
+ALTER TABLE agent DROP COLUMN end_block_num;
+ALTER TABLE organization DROP COLUMN start_block_num;
+ALTER TABLE organization DROP COLUMN end_block_num;
+ALTER TABLE grid_schema DROP COLUMN start_block_num;
+ALTER TABLE grid_schema DROP COLUMN end_block_num;
+ALTER TABLE grid_property_definition DROP COLUMN start_block_num;
+ALTER TABLE grid_property_definition DROP COLUMN end_block_num;
+ALTER TABLE record DROP COLUMN start_block_num;
+ALTER TABLE record DROP COLUMN end_block_num;
+ALTER TABLE associated_agent DROP COLUMN start_block_num;
+ALTER TABLE associated_agent DROP COLUMN end_block_num;
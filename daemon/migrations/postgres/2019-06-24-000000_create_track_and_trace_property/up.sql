@@ -0,0 +1,54 @@
+-- Copyright 2019 Cargill Incorporated
+--
+-- Licensed under the Apache License, Version 2.0 (the "License");
+-- you may not use this file except in compliance with the License.
+-- You may obtain a copy of the License at
+--
+--     http://www.apache.org/licenses/LICENSE-2.0
+--
+-- Unless required by applicable law or agreed to in writing, software
+-- distributed under the License is distributed on an "AS IS" BASIS,
+-- WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+-- See the License for the specific language governing permissions and
+-- limitations under the License.
+-- -----------------------------------------------------------------------------
+
+CREATE TABLE IF NOT EXISTS property (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    data_type TEXT NOT NULL,
+    reporters TEXT NOT NULL,
+    current_page INTEGER NOT NULL,
+    wrapped BOOLEAN NOT NULL,
+    fixed BOOLEAN NOT NULL,
+    number_exponent BIGINT NOT NULL,
+    enum_options TEXT NOT NULL,
+    unit TEXT NOT NULL,
+    start_block_num BIGINT NOT NULL,
+    end_block_num BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS property_record_id_idx ON property (record_id);
+
+CREATE TABLE IF NOT EXISTS reported_value (
+    id SERIAL PRIMARY KEY,
+    record_id TEXT NOT NULL,
+    property_name TEXT NOT NULL,
+    page_num INTEGER NOT NULL,
+    reporter_index INTEGER NOT NULL,
+    timestamp BIGINT NOT NULL,
+    bytes_value TEXT NOT NULL,
+    boolean_value BOOLEAN NOT NULL,
+    number_value BIGINT NOT NULL,
+    string_value TEXT NOT NULL,
+    enum_value INTEGER NOT NULL,
+    struct_values TEXT NOT NULL,
+    latitude BIGINT NOT NULL,
+    longitude BIGINT NOT NULL,
+    start_block_num BIGINT NOT NULL,
+    end_block_num BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS reported_value_property_idx
+    ON reported_value (record_id, property_name);
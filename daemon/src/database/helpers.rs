@@ -0,0 +1,556 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use grid_sdk::addressing::{compute_pike_agent_address, compute_pike_organization_address};
+
+use super::models::{
+    Agent, AssociatedAgent, Block, GridPropertyDefinition, GridSchema, NewAgent,
+    NewAssociatedAgent, NewGridPropertyDefinition, NewGridSchema, NewOrganization, NewProperty,
+    NewRecord, NewReportedValue, Organization, Property, PropertyPageId, Record, ReportedValue,
+};
+use super::schema::{
+    agent, associated_agent, block, grid_property_definition, grid_schema, organization, property,
+    record, reported_value,
+};
+use super::{Connection, DatabaseError, MAX_BLOCK_NUM};
+
+/// Ends the current version of each agent and inserts the new versions
+pub fn insert_agents(conn: &Connection, agents: &[NewAgent]) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for new_agent in agents {
+            diesel::update(
+                agent::table
+                    .filter(agent::public_key.eq(&new_agent.public_key))
+                    .filter(agent::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(agent::end_block_num.eq(new_agent.start_block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(agent::table)
+            .values(agents)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each organization and inserts the new versions
+pub fn insert_organizations(
+    conn: &Connection,
+    organizations: &[NewOrganization],
+) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for new_organization in organizations {
+            diesel::update(
+                organization::table
+                    .filter(organization::org_id.eq(&new_organization.org_id))
+                    .filter(organization::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(organization::end_block_num.eq(new_organization.start_block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(organization::table)
+            .values(organizations)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each agent that is no longer stored at its address.
+///
+/// `addresses` maps each changed agent address to the public keys it now holds; a deleted
+/// address holds none.
+pub fn end_removed_agents(
+    conn: &Connection,
+    addresses: &HashMap<String, Vec<String>>,
+    block_num: i64,
+) -> Result<(), DatabaseError> {
+    if addresses.is_empty() {
+        return Ok(());
+    }
+
+    with_connection!(conn, |conn| {
+        let removed = agent::table
+            .filter(agent::end_block_num.eq(MAX_BLOCK_NUM))
+            .select(agent::public_key)
+            .load::<String>(conn)?
+            .into_iter()
+            .filter(
+                |public_key| match addresses.get(&compute_pike_agent_address(public_key)) {
+                    Some(public_keys) => !public_keys.contains(public_key),
+                    None => false,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        diesel::update(
+            agent::table
+                .filter(agent::public_key.eq_any(&removed))
+                .filter(agent::end_block_num.eq(MAX_BLOCK_NUM)),
+        )
+        .set(agent::end_block_num.eq(block_num))
+        .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each organization that is no longer stored at its address.
+///
+/// `addresses` maps each changed organization address to the ids it now holds; a deleted
+/// address holds none.
+pub fn end_removed_organizations(
+    conn: &Connection,
+    addresses: &HashMap<String, Vec<String>>,
+    block_num: i64,
+) -> Result<(), DatabaseError> {
+    if addresses.is_empty() {
+        return Ok(());
+    }
+
+    with_connection!(conn, |conn| {
+        let removed = organization::table
+            .filter(organization::end_block_num.eq(MAX_BLOCK_NUM))
+            .select(organization::org_id)
+            .load::<String>(conn)?
+            .into_iter()
+            .filter(
+                |org_id| match addresses.get(&compute_pike_organization_address(org_id)) {
+                    Some(org_ids) => !org_ids.contains(org_id),
+                    None => false,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        diesel::update(
+            organization::table
+                .filter(organization::org_id.eq_any(&removed))
+                .filter(organization::end_block_num.eq(MAX_BLOCK_NUM)),
+        )
+        .set(organization::end_block_num.eq(block_num))
+        .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each schema, along with its property definitions, and inserts
+/// the new versions
+pub fn insert_schemas(
+    conn: &Connection,
+    schemas: &[NewGridSchema],
+    definitions: &[NewGridPropertyDefinition],
+) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for new_schema in schemas {
+            diesel::update(
+                grid_schema::table
+                    .filter(grid_schema::name.eq(&new_schema.name))
+                    .filter(grid_schema::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(grid_schema::end_block_num.eq(new_schema.start_block_num))
+            .execute(conn)?;
+            diesel::update(
+                grid_property_definition::table
+                    .filter(grid_property_definition::schema_name.eq(&new_schema.name))
+                    .filter(grid_property_definition::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(grid_property_definition::end_block_num.eq(new_schema.start_block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(grid_schema::table)
+            .values(schemas)
+            .execute(conn)?;
+        diesel::insert_into(grid_property_definition::table)
+            .values(definitions)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each record, along with its owners and custodians, and inserts
+/// the new versions
+pub fn insert_records(
+    conn: &Connection,
+    records: &[NewRecord],
+    associated_agents: &[NewAssociatedAgent],
+) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for new_record in records {
+            diesel::update(
+                record::table
+                    .filter(record::record_id.eq(&new_record.record_id))
+                    .filter(record::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(record::end_block_num.eq(new_record.start_block_num))
+            .execute(conn)?;
+            diesel::update(
+                associated_agent::table
+                    .filter(associated_agent::record_id.eq(&new_record.record_id))
+                    .filter(associated_agent::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(associated_agent::end_block_num.eq(new_record.start_block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(record::table)
+            .values(records)
+            .execute(conn)?;
+        diesel::insert_into(associated_agent::table)
+            .values(associated_agents)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the current version of each property and inserts the new versions
+pub fn insert_properties(
+    conn: &Connection,
+    properties: &[NewProperty],
+) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for new_property in properties {
+            diesel::update(
+                property::table
+                    .filter(property::record_id.eq(&new_property.record_id))
+                    .filter(property::name.eq(&new_property.name))
+                    .filter(property::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(property::end_block_num.eq(new_property.start_block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(property::table)
+            .values(properties)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Ends the values currently held by each of the given property pages and inserts the values
+/// they hold now
+pub fn insert_reported_values(
+    conn: &Connection,
+    pages: &[PropertyPageId],
+    reported_values: &[NewReportedValue],
+    block_num: i64,
+) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        for page in pages {
+            diesel::update(
+                reported_value::table
+                    .filter(reported_value::record_id.eq(&page.record_id))
+                    .filter(reported_value::property_name.eq(&page.property_name))
+                    .filter(reported_value::page_num.eq(page.page_num))
+                    .filter(reported_value::end_block_num.eq(MAX_BLOCK_NUM)),
+            )
+            .set(reported_value::end_block_num.eq(block_num))
+            .execute(conn)?;
+        }
+        diesel::insert_into(reported_value::table)
+            .values(reported_values)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+/// Returns the current version of every agent
+pub fn list_agents(conn: &Connection) -> Result<Vec<Agent>, DatabaseError> {
+    let agents = with_connection!(conn, |conn| {
+        agent::table
+            .filter(agent::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(agent::public_key)
+            .load::<Agent>(conn)?
+    });
+
+    Ok(agents)
+}
+
+/// Returns the current version of the agent with the given public key, if it exists
+pub fn fetch_agent(conn: &Connection, public_key: &str) -> Result<Option<Agent>, DatabaseError> {
+    let agent = with_connection!(conn, |conn| {
+        agent::table
+            .filter(agent::public_key.eq(public_key))
+            .filter(agent::end_block_num.eq(MAX_BLOCK_NUM))
+            .first::<Agent>(conn)
+            .optional()?
+    });
+
+    Ok(agent)
+}
+
+/// Returns the current version of every organization
+pub fn list_organizations(conn: &Connection) -> Result<Vec<Organization>, DatabaseError> {
+    let organizations = with_connection!(conn, |conn| {
+        organization::table
+            .filter(organization::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(organization::org_id)
+            .load::<Organization>(conn)?
+    });
+
+    Ok(organizations)
+}
+
+/// Returns the current version of the organization with the given id, if it exists
+pub fn fetch_organization(
+    conn: &Connection,
+    org_id: &str,
+) -> Result<Option<Organization>, DatabaseError> {
+    let organization = with_connection!(conn, |conn| {
+        organization::table
+            .filter(organization::org_id.eq(org_id))
+            .filter(organization::end_block_num.eq(MAX_BLOCK_NUM))
+            .first::<Organization>(conn)
+            .optional()?
+    });
+
+    Ok(organization)
+}
+
+/// Returns the current version of every schema
+pub fn list_schemas(conn: &Connection) -> Result<Vec<GridSchema>, DatabaseError> {
+    let schemas = with_connection!(conn, |conn| {
+        grid_schema::table
+            .filter(grid_schema::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(grid_schema::name)
+            .load::<GridSchema>(conn)?
+    });
+
+    Ok(schemas)
+}
+
+/// Returns the current version of the schema with the given name, if it exists
+pub fn fetch_schema(conn: &Connection, name: &str) -> Result<Option<GridSchema>, DatabaseError> {
+    let schema = with_connection!(conn, |conn| {
+        grid_schema::table
+            .filter(grid_schema::name.eq(name))
+            .filter(grid_schema::end_block_num.eq(MAX_BLOCK_NUM))
+            .first::<GridSchema>(conn)
+            .optional()?
+    });
+
+    Ok(schema)
+}
+
+/// Returns the current property definitions of the given schemas, in the order they were
+/// defined
+pub fn list_property_definitions(
+    conn: &Connection,
+    schema_names: &[String],
+) -> Result<Vec<GridPropertyDefinition>, DatabaseError> {
+    let definitions = with_connection!(conn, |conn| {
+        grid_property_definition::table
+            .filter(grid_property_definition::schema_name.eq_any(schema_names))
+            .filter(grid_property_definition::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(grid_property_definition::id)
+            .load::<GridPropertyDefinition>(conn)?
+    });
+
+    Ok(definitions)
+}
+
+/// Returns the current version of every record
+pub fn list_records(conn: &Connection) -> Result<Vec<Record>, DatabaseError> {
+    let records = with_connection!(conn, |conn| {
+        record::table
+            .filter(record::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(record::record_id)
+            .load::<Record>(conn)?
+    });
+
+    Ok(records)
+}
+
+/// Returns the current version of the record with the given id, if it exists
+pub fn fetch_record(conn: &Connection, record_id: &str) -> Result<Option<Record>, DatabaseError> {
+    let found = with_connection!(conn, |conn| {
+        record::table
+            .filter(record::record_id.eq(record_id))
+            .filter(record::end_block_num.eq(MAX_BLOCK_NUM))
+            .first::<Record>(conn)
+            .optional()?
+    });
+
+    Ok(found)
+}
+
+/// Returns the current owners and custodians of the given records, oldest first
+pub fn list_associated_agents(
+    conn: &Connection,
+    record_ids: &[String],
+) -> Result<Vec<AssociatedAgent>, DatabaseError> {
+    let associated_agents = with_connection!(conn, |conn| {
+        associated_agent::table
+            .filter(associated_agent::record_id.eq_any(record_ids))
+            .filter(associated_agent::end_block_num.eq(MAX_BLOCK_NUM))
+            .order(associated_agent::id)
+            .load::<AssociatedAgent>(conn)?
+    });
+
+    Ok(associated_agents)
+}
+
+/// Returns the current properties of the given records
+pub fn list_properties(
+    conn: &Connection,
+    record_ids: &[String],
+) -> Result<Vec<Property>, DatabaseError> {
+    let properties = with_connection!(conn, |conn| {
+        property::table
+            .filter(property::record_id.eq_any(record_ids))
+            .filter(property::end_block_num.eq(MAX_BLOCK_NUM))
+            .order((property::record_id, property::name))
+            .load::<Property>(conn)?
+    });
+
+    Ok(properties)
+}
+
+/// Returns the current version of a record's property, if it exists
+pub fn fetch_property(
+    conn: &Connection,
+    record_id: &str,
+    name: &str,
+) -> Result<Option<Property>, DatabaseError> {
+    let found = with_connection!(conn, |conn| {
+        property::table
+            .filter(property::record_id.eq(record_id))
+            .filter(property::name.eq(name))
+            .filter(property::end_block_num.eq(MAX_BLOCK_NUM))
+            .first::<Property>(conn)
+            .optional()?
+    });
+
+    Ok(found)
+}
+
+/// Returns the values currently reported for a record's property, oldest first. If given,
+/// `start` and `end` bound the timestamps of the returned values (inclusive).
+pub fn list_reported_values(
+    conn: &Connection,
+    record_id: &str,
+    property_name: &str,
+    start: Option<i64>,
+    end: Option<i64>,
+) -> Result<Vec<ReportedValue>, DatabaseError> {
+    let reported_values = with_connection!(conn, |conn| {
+        let mut query = reported_value::table
+            .filter(reported_value::record_id.eq(record_id))
+            .filter(reported_value::property_name.eq(property_name))
+            .filter(reported_value::end_block_num.eq(MAX_BLOCK_NUM))
+            .into_boxed();
+        if let Some(start) = start {
+            query = query.filter(reported_value::timestamp.ge(start));
+        }
+        if let Some(end) = end {
+            query = query.filter(reported_value::timestamp.le(end));
+        }
+
+        query
+            .order((
+                reported_value::timestamp,
+                reported_value::reporter_index,
+                reported_value::id,
+            ))
+            .load::<ReportedValue>(conn)?
+    });
+
+    Ok(reported_values)
+}
+
+pub fn insert_block(conn: &Connection, new_block: &Block) -> Result<(), DatabaseError> {
+    with_connection!(conn, |conn| {
+        diesel::insert_into(block::table)
+            .values(new_block)
+            .execute(conn)?;
+    });
+
+    Ok(())
+}
+
+pub fn get_block(conn: &Connection, block_num: i64) -> Result<Option<Block>, DatabaseError> {
+    let found = with_connection!(conn, |conn| {
+        block::table
+            .filter(block::block_num.eq(block_num))
+            .first::<Block>(conn)
+            .optional()?
+    });
+
+    Ok(found)
+}
+
+/// Returns the number of the newest block that has been recorded, if any
+pub fn get_current_block_num(conn: &Connection) -> Result<Option<i64>, DatabaseError> {
+    let block_num = with_connection!(conn, |conn| {
+        block::table
+            .select(diesel::dsl::max(block::block_num))
+            .first::<Option<i64>>(conn)?
+    });
+
+    Ok(block_num)
+}
+
+/// Returns the ids of the most recently committed blocks, newest first
+pub fn get_last_known_block_ids(
+    conn: &Connection,
+    count: i64,
+) -> Result<Vec<String>, DatabaseError> {
+    let block_ids = with_connection!(conn, |conn| {
+        block::table
+            .select(block::block_id)
+            .order(block::block_num.desc())
+            .limit(count)
+            .load::<String>(conn)?
+    });
+
+    Ok(block_ids)
+}
+
+/// Removes everything committed at or after `block_num`: rows introduced by those blocks are
+/// deleted, rows they ended become current again, and the blocks themselves are forgotten.
+pub fn rollback_to_block(conn: &Connection, block_num: i64) -> Result<(), DatabaseError> {
+    macro_rules! rollback_table {
+        ($conn:expr, $table:ident) => {
+            diesel::delete($table::table.filter($table::start_block_num.ge(block_num)))
+                .execute($conn)?;
+            diesel::update($table::table.filter($table::end_block_num.ge(block_num)))
+                .set($table::end_block_num.eq(MAX_BLOCK_NUM))
+                .execute($conn)?;
+        };
+    }
+
+    with_connection!(conn, |conn| {
+        rollback_table!(conn, agent);
+        rollback_table!(conn, organization);
+        rollback_table!(conn, grid_schema);
+        rollback_table!(conn, grid_property_definition);
+        rollback_table!(conn, record);
+        rollback_table!(conn, associated_agent);
+        rollback_table!(conn, property);
+        rollback_table!(conn, reported_value);
+
+        diesel::delete(block::table.filter(block::block_num.ge(block_num))).execute(conn)?;
+    });
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+/// Evaluates `$body` with `$conn` bound to the concrete diesel connection behind a
+/// `Connection`, so the same query can be written once for every supported backend.
+macro_rules! with_connection {
+    ($connection:expr, |$conn:ident| $body:block) => {
+        match $connection {
+            $crate::database::Connection::Postgres(pooled) => {
+                let $conn: &diesel::pg::PgConnection = &**pooled;
+                $body
+            }
+            $crate::database::Connection::Sqlite(pooled) => {
+                let $conn: &diesel::sqlite::SqliteConnection = &**pooled;
+                $body
+            }
+        }
+    };
+}
+
+mod error;
+pub mod helpers;
+pub mod models;
+mod schema;
+
+use diesel::pg::PgConnection;
+use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
+use diesel::sqlite::SqliteConnection;
+use diesel::Connection as _;
+
+pub use super::database::error::DatabaseError;
+
+/// The end block number of rows that are still part of current state
+pub const MAX_BLOCK_NUM: i64 = i64::MAX;
+
+const POSTGRES_URL_PREFIXES: [&str; 2] = ["postgres://", "postgresql://"];
+const SQLITE_URL_PREFIX: &str = "sqlite://";
+
+mod postgres_migrations {
+    embed_migrations!("./migrations/postgres");
+
+    pub use self::embedded_migrations::run;
+}
+
+mod sqlite_migrations {
+    embed_migrations!("./migrations/sqlite");
+
+    pub use self::embedded_migrations::run;
+}
+
+/// A pool of connections to one of the supported database backends
+#[derive(Clone)]
+pub enum ConnectionPool {
+    Postgres(Pool<ConnectionManager<PgConnection>>),
+    Sqlite(Pool<ConnectionManager<SqliteConnection>>),
+}
+
+/// A connection checked out of a `ConnectionPool`
+pub enum Connection {
+    Postgres(PooledConnection<ConnectionManager<PgConnection>>),
+    Sqlite(PooledConnection<ConnectionManager<SqliteConnection>>),
+}
+
+impl ConnectionPool {
+    pub fn get(&self) -> Result<Connection, DatabaseError> {
+        match self {
+            ConnectionPool::Postgres(pool) => Ok(Connection::Postgres(pool.get()?)),
+            ConnectionPool::Sqlite(pool) => Ok(Connection::Sqlite(pool.get()?)),
+        }
+    }
+}
+
+impl Connection {
+    /// Runs `f` inside a database transaction, rolling back if it returns an error
+    pub fn transaction<T, F>(&self, f: F) -> Result<T, DatabaseError>
+    where
+        F: FnOnce() -> Result<T, DatabaseError>,
+    {
+        match self {
+            Connection::Postgres(conn) => conn.transaction(f),
+            Connection::Sqlite(conn) => conn.transaction(f),
+        }
+    }
+}
+
+/// Creates a connection pool for the given database URL.
+///
+/// URLs starting with `postgres://` or `postgresql://` connect to PostgreSQL; URLs starting with
+/// `sqlite://` open (or create) the SQLite database at the remaining path.
+pub fn create_connection_pool(database_url: &str) -> Result<ConnectionPool, DatabaseError> {
+    if POSTGRES_URL_PREFIXES
+        .iter()
+        .any(|prefix| database_url.starts_with(prefix))
+    {
+        let manager = ConnectionManager::<PgConnection>::new(database_url);
+        Ok(ConnectionPool::Postgres(Pool::builder().build(manager)?))
+    } else if let Some(path) = database_url.strip_prefix(SQLITE_URL_PREFIX) {
+        let manager = ConnectionManager::<SqliteConnection>::new(path);
+        // SQLite only supports a single writer; serialize access through one connection
+        Ok(ConnectionPool::Sqlite(
+            Pool::builder().max_size(1).build(manager)?,
+        ))
+    } else {
+        Err(DatabaseError::ConnectionError(
+            format!("Unsupported database URL: {}", database_url).into(),
+        ))
+    }
+}
+
+/// Brings the database schema up to date with the migrations shipped in the daemon
+pub fn run_migrations(conn: &Connection) -> Result<(), DatabaseError> {
+    match conn {
+        Connection::Postgres(conn) => postgres_migrations::run(&**conn)?,
+        Connection::Sqlite(conn) => sqlite_migrations::run(&**conn)?,
+    }
+
+    info!("Successfully applied database migrations");
+
+    Ok(())
+}
@@ -0,0 +1,279 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use grid_sdk::protocol::pike::state::OrganizationStatus;
+use grid_sdk::protocol::schema::state::DataType;
+
+use super::schema::{
+    agent, associated_agent, block, grid_property_definition, grid_schema, organization, property,
+    record, reported_value,
+};
+
+#[derive(Insertable, Debug)]
+#[table_name = "agent"]
+pub struct NewAgent {
+    pub public_key: String,
+    pub org_id: String,
+    pub active: bool,
+    pub roles: String,
+    pub metadata: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct Agent {
+    pub id: i32,
+    pub public_key: String,
+    pub org_id: String,
+    pub active: bool,
+    pub roles: String,
+    pub metadata: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "organization"]
+pub struct NewOrganization {
+    pub org_id: String,
+    pub name: String,
+    pub address: String,
+    pub metadata: String,
+    pub status: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct Organization {
+    pub id: i32,
+    pub org_id: String,
+    pub name: String,
+    pub address: String,
+    pub metadata: String,
+    pub status: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "grid_schema"]
+pub struct NewGridSchema {
+    pub name: String,
+    pub description: String,
+    pub owner: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct GridSchema {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub owner: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "grid_property_definition"]
+pub struct NewGridPropertyDefinition {
+    pub name: String,
+    pub schema_name: String,
+    pub data_type: String,
+    pub required: bool,
+    pub description: String,
+    pub number_exponent: i64,
+    pub enum_options: String,
+    pub parent_name: Option<String>,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct GridPropertyDefinition {
+    pub id: i32,
+    pub name: String,
+    pub schema_name: String,
+    pub data_type: String,
+    pub required: bool,
+    pub description: String,
+    pub number_exponent: i64,
+    pub enum_options: String,
+    pub parent_name: Option<String>,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "record"]
+pub struct NewRecord {
+    pub record_id: String,
+    pub schema: String,
+    pub finalized: bool,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct Record {
+    pub id: i32,
+    pub record_id: String,
+    pub schema: String,
+    pub finalized: bool,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "associated_agent"]
+pub struct NewAssociatedAgent {
+    pub record_id: String,
+    pub role: String,
+    pub agent_id: String,
+    pub timestamp: i64,
+    pub org_id: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct AssociatedAgent {
+    pub id: i32,
+    pub record_id: String,
+    pub role: String,
+    pub agent_id: String,
+    pub timestamp: i64,
+    pub org_id: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "property"]
+pub struct NewProperty {
+    pub name: String,
+    pub record_id: String,
+    pub data_type: String,
+    pub reporters: String,
+    pub current_page: i32,
+    pub wrapped: bool,
+    pub fixed: bool,
+    pub number_exponent: i64,
+    pub enum_options: String,
+    pub unit: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct Property {
+    pub id: i32,
+    pub name: String,
+    pub record_id: String,
+    pub data_type: String,
+    pub reporters: String,
+    pub current_page: i32,
+    pub wrapped: bool,
+    pub fixed: bool,
+    pub number_exponent: i64,
+    pub enum_options: String,
+    pub unit: String,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+/// Identifies one page of a property's reported values; setting a page replaces all of the
+/// values it held before
+#[derive(Debug)]
+pub struct PropertyPageId {
+    pub record_id: String,
+    pub property_name: String,
+    pub page_num: i32,
+}
+
+#[derive(Insertable, Debug)]
+#[table_name = "reported_value"]
+pub struct NewReportedValue {
+    pub record_id: String,
+    pub property_name: String,
+    pub page_num: i32,
+    pub reporter_index: i32,
+    pub timestamp: i64,
+    /// Base64 encoded
+    pub bytes_value: String,
+    pub boolean_value: bool,
+    pub number_value: i64,
+    pub string_value: String,
+    pub enum_value: i32,
+    pub struct_values: String,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Queryable, Debug)]
+pub struct ReportedValue {
+    pub id: i32,
+    pub record_id: String,
+    pub property_name: String,
+    pub page_num: i32,
+    pub reporter_index: i32,
+    pub timestamp: i64,
+    pub bytes_value: String,
+    pub boolean_value: bool,
+    pub number_value: i64,
+    pub string_value: String,
+    pub enum_value: i32,
+    pub struct_values: String,
+    pub latitude: i64,
+    pub longitude: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+}
+
+#[derive(Insertable, Queryable, Debug)]
+#[table_name = "block"]
+pub struct Block {
+    pub block_id: String,
+    pub block_num: i64,
+    pub state_root_hash: String,
+}
+
+/// Returns the name a schema property's data type is stored and served under
+pub fn data_type_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Bytes => "BYTES",
+        DataType::Boolean => "BOOLEAN",
+        DataType::Number => "NUMBER",
+        DataType::String => "STRING",
+        DataType::Enum => "ENUM",
+        DataType::Struct => "STRUCT",
+        DataType::Location => "LOCATION",
+    }
+}
+
+/// Returns the name an organization's status is stored and served under
+pub fn organization_status_name(status: &OrganizationStatus) -> &'static str {
+    match status {
+        OrganizationStatus::Active => "ACTIVE",
+        OrganizationStatus::Suspended => "SUSPENDED",
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+table! {
+    agent (id) {
+        id -> Integer,
+        public_key -> Text,
+        org_id -> Text,
+        active -> Bool,
+        roles -> Text,
+        metadata -> Text,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    organization (id) {
+        id -> Integer,
+        org_id -> Text,
+        name -> Text,
+        address -> Text,
+        metadata -> Text,
+        status -> Text,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    grid_schema (id) {
+        id -> Integer,
+        name -> Text,
+        description -> Text,
+        owner -> Text,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    grid_property_definition (id) {
+        id -> Integer,
+        name -> Text,
+        schema_name -> Text,
+        data_type -> Text,
+        required -> Bool,
+        description -> Text,
+        number_exponent -> BigInt,
+        enum_options -> Text,
+        parent_name -> Nullable<Text>,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    record (id) {
+        id -> Integer,
+        record_id -> Text,
+        schema -> Text,
+        finalized -> Bool,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    associated_agent (id) {
+        id -> Integer,
+        record_id -> Text,
+        role -> Text,
+        agent_id -> Text,
+        timestamp -> BigInt,
+        org_id -> Text,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    property (id) {
+        id -> Integer,
+        name -> Text,
+        record_id -> Text,
+        data_type -> Text,
+        reporters -> Text,
+        current_page -> Integer,
+        wrapped -> Bool,
+        fixed -> Bool,
+        number_exponent -> BigInt,
+        enum_options -> Text,
+        unit -> Text,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    reported_value (id) {
+        id -> Integer,
+        record_id -> Text,
+        property_name -> Text,
+        page_num -> Integer,
+        reporter_index -> Integer,
+        timestamp -> BigInt,
+        bytes_value -> Text,
+        boolean_value -> Bool,
+        number_value -> BigInt,
+        string_value -> Text,
+        enum_value -> Integer,
+        struct_values -> Text,
+        latitude -> BigInt,
+        longitude -> BigInt,
+        start_block_num -> BigInt,
+        end_block_num -> BigInt,
+    }
+}
+
+table! {
+    block (block_id) {
+        block_id -> Text,
+        block_num -> BigInt,
+        state_root_hash -> Text,
+    }
+}
+
+allow_tables_to_appear_in_same_query!(
+    agent,
+    organization,
+    grid_schema,
+    grid_property_definition,
+    record,
+    associated_agent,
+    property,
+    reported_value,
+    block,
+);
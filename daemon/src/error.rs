@@ -16,6 +16,7 @@
 use std::error::Error;
 use std::fmt;
 
+use crate::database::DatabaseError;
 use crate::event::EventProcessorError;
 
 use log;
@@ -26,6 +27,7 @@ use crate::rest_api::RestApiServerError;
 pub enum DaemonError {
     LoggingInitializationError(Box<log::SetLoggerError>),
     ConfigurationError(Box<ConfigurationError>),
+    DatabaseError(Box<DatabaseError>),
     EventProcessorError(Box<EventProcessorError>),
     RestApiError(RestApiServerError),
     StartUpError(Box<dyn Error>),
@@ -37,6 +39,7 @@ impl Error for DaemonError {
         match self {
             DaemonError::LoggingInitializationError(err) => Some(err),
             DaemonError::ConfigurationError(err) => Some(err),
+            DaemonError::DatabaseError(err) => Some(err),
             DaemonError::EventProcessorError(err) => Some(err),
             DaemonError::RestApiError(err) => Some(err),
             DaemonError::StartUpError(err) => Some(&**err),
@@ -52,6 +55,7 @@ impl fmt::Display for DaemonError {
                 write!(f, "Logging initialization error: {}", e)
             }
             DaemonError::ConfigurationError(e) => write!(f, "Configuration error: {}", e),
+            DaemonError::DatabaseError(e) => write!(f, "Database error: {}", e),
             DaemonError::EventProcessorError(e) => write!(f, "Event Processor Error: {}", e),
             DaemonError::RestApiError(e) => write!(f, "Rest API error: {}", e),
             DaemonError::StartUpError(e) => write!(f, "Start-up error: {}", e),
@@ -69,6 +73,9 @@ impl From<log::SetLoggerError> for DaemonError {
 #[derive(Debug, PartialEq)]
 pub enum ConfigurationError {
     MissingValue(String),
+    InvalidValue(String),
+    FileError(String),
+    ParseError(String),
 }
 
 impl Error for ConfigurationError {}
@@ -79,6 +86,11 @@ impl fmt::Display for ConfigurationError {
             ConfigurationError::MissingValue(config_field_name) => {
                 write!(f, "Missing configuration for {}", config_field_name)
             }
+            ConfigurationError::InvalidValue(msg) => write!(f, "Invalid configuration: {}", msg),
+            ConfigurationError::FileError(msg) => write!(f, "Configuration file error: {}", msg),
+            ConfigurationError::ParseError(msg) => {
+                write!(f, "Unable to parse configuration file: {}", msg)
+            }
         }
     }
 }
@@ -89,6 +101,12 @@ impl From<ConfigurationError> for DaemonError {
     }
 }
 
+impl From<DatabaseError> for DaemonError {
+    fn from(err: DatabaseError) -> Self {
+        DaemonError::DatabaseError(Box::new(err))
+    }
+}
+
 impl From<RestApiServerError> for DaemonError {
     fn from(err: RestApiServerError) -> DaemonError {
         DaemonError::RestApiError(err)
@@ -17,43 +17,208 @@
 
 use sawtooth_sdk::messages::events::{Event, Event_Attribute};
 
-use super::{error::EventError, EventHandler};
+use crate::database::{helpers as db, models::Block, ConnectionPool};
 
-pub struct BlockEventHandler {}
+use super::{
+    error::EventError,
+    state_delta::{GridStateChanges, STATE_DELTA_EVENT_TYPE},
+    EventHandler,
+};
+
+pub const BLOCK_COMMIT_EVENT_TYPE: &str = "sawtooth/block-commit";
+
+/// The block that a list of events was committed in
+#[derive(Debug, Clone)]
+pub struct BlockInfo {
+    pub block_id: String,
+    pub block_num: u64,
+    pub state_root_hash: String,
+}
+
+impl BlockInfo {
+    pub fn from_event(event: &Event) -> Result<Self, EventError> {
+        let attributes = event.get_attributes();
+
+        let block_id = require_attr(attributes, "block_id")?;
+        let block_num = require_attr(attributes, "block_num")?
+            .parse::<u64>()
+            .map_err(|err| EventError(format!("block_num was not a valid number: {}", err)))?;
+        let state_root_hash = require_attr(attributes, "state_root_hash")?;
+
+        Ok(Self {
+            block_id,
+            block_num,
+            state_root_hash,
+        })
+    }
+}
+
+fn require_attr(attributes: &[Event_Attribute], key: &str) -> Result<String, EventError> {
+    attributes
+        .iter()
+        .find(|attr| attr.get_key() == key)
+        .map(|attr| attr.get_value().to_owned())
+        .ok_or_else(|| EventError(format!("Unable to find {}", key)))
+}
+
+/// Stores each committed block together with the Grid state changes it made, in a single
+/// database transaction
+pub struct BlockEventHandler {
+    connection_pool: ConnectionPool,
+}
 
 impl BlockEventHandler {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(connection_pool: ConnectionPool) -> Self {
+        Self { connection_pool }
     }
+}
+
+impl EventHandler for BlockEventHandler {
+    fn handle_events(&self, block: &BlockInfo, events: &[Event]) -> Result<(), EventError> {
+        info!(
+            "Received sawtooth/block-commit ({}, {}, {})",
+            block.block_id, block.block_num, block.state_root_hash
+        );
+
+        let block_num = block.block_num as i64;
 
-    fn require_attr(attributes: &[Event_Attribute], key: &str) -> Result<String, EventError> {
-        attributes
+        let state_changes = events
             .iter()
-            .find(|attr| attr.get_key() == key)
-            .map(|attr| attr.get_value().to_owned())
-            .ok_or_else(|| EventError(format!("Unable to find {}", key)))
+            .filter(|event| event.get_event_type() == STATE_DELTA_EVENT_TYPE)
+            .map(|event| GridStateChanges::from_event(event, block_num))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let conn = self
+            .connection_pool
+            .get()
+            .map_err(|err| EventError(format!("Unable to connect to database: {}", err)))?;
+
+        conn.transaction(|| {
+            // A block at or below one already seen either replaces an abandoned fork or is
+            // being replayed after a restart; in both cases everything derived from the
+            // blocks it supersedes must go before its state changes are applied.
+            if let Some(current_block_num) = db::get_current_block_num(&conn)? {
+                if block_num <= current_block_num {
+                    match db::get_block(&conn, block_num)? {
+                        Some(ref existing) if existing.block_id != block.block_id => warn!(
+                            "Fork detected: replacing block {} with {} at height {}",
+                            existing.block_id, block.block_id, block_num
+                        ),
+                        _ => debug!("Replaying block {} at height {}", block.block_id, block_num),
+                    }
+                    db::rollback_to_block(&conn, block_num)?;
+                }
+            }
+
+            db::insert_block(
+                &conn,
+                &Block {
+                    block_id: block.block_id.clone(),
+                    block_num,
+                    state_root_hash: block.state_root_hash.clone(),
+                },
+            )?;
+
+            for grid_state in &state_changes {
+                grid_state.store(&conn)?;
+            }
+
+            Ok(())
+        })
+        .map_err(|err| EventError(format!("Unable to store block: {}", err)))
     }
 }
 
-impl EventHandler for BlockEventHandler {
-    fn event_type(&self) -> &str {
-        "sawtooth/block-commit"
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use grid_sdk::addressing::compute_pike_agent_address;
+    use grid_sdk::protocol::pike::state::{AgentBuilder, AgentListBuilder};
+    use grid_sdk::protos::IntoBytes;
+    use protobuf::Message;
+    use sawtooth_sdk::messages::transaction_receipt::{
+        StateChange, StateChangeList, StateChange_Type,
+    };
+
+    use crate::database::{create_connection_pool, run_migrations};
+
+    fn block(block_id: &str, block_num: u64) -> BlockInfo {
+        BlockInfo {
+            block_id: block_id.to_string(),
+            block_num,
+            state_root_hash: format!("{}_state_root", block_id),
+        }
     }
 
-    fn handle_event(&self, event: &Event) -> Result<(), EventError> {
-        let attributes = event.get_attributes();
+    /// Returns a state-delta event setting each agent, given as (public key, org id), at its
+    /// own address
+    fn agents_event(agents: &[(&str, &str)]) -> Event {
+        let mut state_change_list = StateChangeList::new();
+        for (public_key, org_id) in agents {
+            let agent = AgentBuilder::new()
+                .with_org_id(org_id.to_string())
+                .with_public_key(public_key.to_string())
+                .with_active(true)
+                .build()
+                .unwrap();
+            let agent_list = AgentListBuilder::new()
+                .with_agents(vec![agent])
+                .build()
+                .unwrap();
 
-        let block_id = Self::require_attr(attributes, "block_id")?;
-        let block_num = Self::require_attr(attributes, "block_num")?
-            .parse::<u64>()
-            .map_err(|err| EventError(format!("block_num was not a valid number: {}", err)))?;
-        let state_root_hash = Self::require_attr(attributes, "state_root_hash")?;
+            let mut state_change = StateChange::new();
+            state_change.set_address(compute_pike_agent_address(public_key));
+            state_change.set_field_type(StateChange_Type::SET);
+            state_change.set_value(agent_list.into_bytes().unwrap());
+            state_change_list.mut_state_changes().push(state_change);
+        }
 
-        info!(
-            "Received sawtooth/block-commit ({}, {}, {})",
-            block_id, block_num, state_root_hash
+        let mut event = Event::new();
+        event.set_event_type(STATE_DELTA_EVENT_TYPE.to_string());
+        event.set_data(state_change_list.write_to_bytes().unwrap());
+        event
+    }
+
+    #[test]
+    // check that a different block arriving at an already stored height replaces the abandoned
+    // fork: the blocks it supersedes are removed and only the new block's state is current
+    fn check_fork_replaces_abandoned_blocks() {
+        let pool = create_connection_pool("sqlite://:memory:").unwrap();
+        run_migrations(&pool.get().unwrap()).unwrap();
+
+        let handler = BlockEventHandler::new(pool.clone());
+        handler
+            .handle_events(
+                &block("block_1", 1),
+                &[agents_event(&[("agent_a", "org_1")])],
+            )
+            .unwrap();
+        handler
+            .handle_events(
+                &block("block_2", 2),
+                &[agents_event(&[("agent_a", "org_2"), ("agent_b", "org_2")])],
+            )
+            .unwrap();
+        handler
+            .handle_events(
+                &block("block_1_fork", 1),
+                &[agents_event(&[("agent_a", "org_3")])],
+            )
+            .unwrap();
+
+        let conn = pool.get().unwrap();
+        assert_eq!(db::get_current_block_num(&conn).unwrap(), Some(1));
+        assert_eq!(
+            db::get_block(&conn, 1).unwrap().unwrap().block_id,
+            "block_1_fork"
         );
+        assert!(db::get_block(&conn, 2).unwrap().is_none());
 
-        Ok(())
+        let agents = db::list_agents(&conn).unwrap();
+        assert_eq!(agents.len(), 1);
+        assert_eq!(agents[0].public_key, "agent_a");
+        assert_eq!(agents[0].org_id, "org_3");
+        assert_eq!(agents[0].start_block_num, 1);
     }
 }
@@ -0,0 +1,115 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use std::sync::{Arc, RwLock};
+
+use serde::Serialize;
+
+use super::block::BlockInfo;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventFeedStatus {
+    /// The initial subscription has not completed yet
+    Starting,
+    /// Events are being received from the validator
+    Connected,
+    /// The connection to the validator was lost and is being re-established
+    Reconnecting,
+    /// The event processor has shut down
+    Stopped,
+}
+
+/// A point-in-time view of the event feed, as reported by the REST API
+#[derive(Clone, Debug, Serialize)]
+pub struct EventFeedState {
+    pub status: EventFeedStatus,
+    pub last_block_id: Option<String>,
+    pub last_block_num: Option<u64>,
+    pub reconnect_attempts: u32,
+    pub last_error: Option<String>,
+}
+
+/// The health of the event feed, updated by the event processor and shared with the REST API
+#[derive(Clone)]
+pub struct EventFeedHealth {
+    state: Arc<RwLock<EventFeedState>>,
+}
+
+impl Default for EventFeedHealth {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(RwLock::new(EventFeedState {
+                status: EventFeedStatus::Starting,
+                last_block_id: None,
+                last_block_num: None,
+                reconnect_attempts: 0,
+                last_error: None,
+            })),
+        }
+    }
+}
+
+impl EventFeedHealth {
+    pub fn state(&self) -> EventFeedState {
+        match self.state.read() {
+            Ok(state) => state.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+
+    pub(super) fn connected(&self) {
+        self.update(|state| {
+            state.status = EventFeedStatus::Connected;
+            state.reconnect_attempts = 0;
+            state.last_error = None;
+        })
+    }
+
+    pub(super) fn reconnecting(&self, attempt: u32, err: &str) {
+        self.update(|state| {
+            state.status = EventFeedStatus::Reconnecting;
+            state.reconnect_attempts = attempt;
+            state.last_error = Some(err.to_string());
+        })
+    }
+
+    pub(super) fn stopped(&self) {
+        self.update(|state| state.status = EventFeedStatus::Stopped)
+    }
+
+    pub(super) fn failed(&self, err: &str) {
+        self.update(|state| {
+            state.status = EventFeedStatus::Stopped;
+            state.last_error = Some(err.to_string());
+        })
+    }
+
+    pub(super) fn block_processed(&self, block: &BlockInfo) {
+        self.update(|state| {
+            state.last_block_id = Some(block.block_id.clone());
+            state.last_block_num = Some(block.block_num);
+        })
+    }
+
+    fn update<F: FnOnce(&mut EventFeedState)>(&self, f: F) {
+        match self.state.write() {
+            Ok(mut state) => f(&mut state),
+            Err(poisoned) => f(&mut poisoned.into_inner()),
+        }
+    }
+}
@@ -17,10 +17,14 @@
 
 pub mod block;
 mod error;
+pub mod health;
+pub mod state_delta;
 
-use std::cell::RefCell;
+use std::cmp;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use protobuf::Message as _;
 
@@ -36,14 +40,34 @@ use sawtooth_sdk::{
 
 use crate::sawtooth_connection::SawtoothConnection;
 
+use self::block::{BlockInfo, BLOCK_COMMIT_EVENT_TYPE};
+use self::health::EventFeedHealth;
+use self::state_delta::STATE_DELTA_EVENT_TYPE;
+
 pub use super::event::error::{EventError, EventProcessorError};
 
 const SHUTDOWN_TIMEOUT: u64 = 2;
 
-pub trait EventHandler: Send {
-    fn event_type(&self) -> &str;
+/// How long to wait for the validator to answer a subscription request
+const SUBSCRIBE_TIMEOUT: u64 = 10;
+
+/// The delay before the first reconnect attempt; it doubles after every failed attempt
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// The block id the validator treats as the start of the chain
+const NULL_BLOCK_ID: &str = "0000000000000000";
 
-    fn handle_event(&self, event: &Event) -> Result<(), EventError>;
+/// The number of known block ids sent with each subscription attempt
+const BLOCK_IDS_PER_SUBSCRIPTION: usize = 10;
+
+/// The number of processed block ids remembered for resubscribing after a reconnect
+const MAX_KNOWN_BLOCK_IDS: usize = 100;
+
+pub trait EventHandler: Send {
+    /// Handles all of the events committed in `block`, including its block commit, so that a
+    /// block can be stored together with its state changes
+    fn handle_events(&self, block: &BlockInfo, events: &[Event]) -> Result<(), EventError>;
 }
 
 #[macro_export]
@@ -53,99 +77,95 @@ macro_rules! event_handlers {
     };
 }
 
+/// The sender of the current validator connection, replaced whenever the processor reconnects
+type SharedMessageSender = Arc<Mutex<Box<dyn MessageSender + Send>>>;
+
 pub struct EventProcessor {
     join_handle: thread::JoinHandle<Result<(), EventProcessorError>>,
-    message_sender: Box<dyn MessageSender + Send>,
+    message_sender: SharedMessageSender,
+    shutdown_signaled: Arc<AtomicBool>,
 }
 
 pub struct EventProcessorShutdownHandle {
-    message_sender: RefCell<Box<dyn MessageSender + Send>>,
+    message_sender: SharedMessageSender,
+    shutdown_signaled: Arc<AtomicBool>,
 }
 
 impl EventProcessorShutdownHandle {
     pub fn shutdown(&self) -> Result<(), EventProcessorError> {
-        let mut message_sender = self.message_sender.borrow_mut();
+        // Signal first, so that closing the connection is not mistaken for a dropped connection
+        self.shutdown_signaled.store(true, Ordering::SeqCst);
+
+        let mut message_sender = match self.message_sender.lock() {
+            Ok(message_sender) => message_sender,
+            Err(poisoned) => poisoned.into_inner(),
+        };
 
         debug!("Sending unsubscribe request");
-        match message_sender
-            .send(
-                Message_MessageType::CLIENT_EVENTS_UNSUBSCRIBE_REQUEST,
-                &correlation_id(),
-                &[], // An unsubscribe request has no content
-            )
-            .map_err(|err| {
-                EventProcessorError(format!("Unable to send unsubscribe request: {}", err))
-            })?
-            .get_timeout(Duration::from_secs(SHUTDOWN_TIMEOUT))
-        {
-            Ok(msg) => {
-                if msg.get_message_type() == Message_MessageType::CLIENT_EVENTS_UNSUBSCRIBE_RESPONSE
-                {
-                    debug!("Successfully unsubscribed");
-                } else {
-                    debug!("During unsubscribe, received {:?}", msg.get_message_type());
-                }
-            }
-            Err(ReceiveError::TimeoutError) => {
-                debug!("Timeout occurred while waiting for unsubscribe response; ignoring")
-            }
-            Err(err) => return Err(EventProcessorError::from(err)),
-        }
+        let result = unsubscribe(&**message_sender);
 
         debug!("Closing message sender");
         message_sender.close();
 
-        Ok(())
+        result
     }
 }
 
 impl EventProcessor {
+    /// Subscribes to Grid events and starts processing them on a separate thread.
+    ///
+    /// `last_known_block_ids` should be ordered newest first. If the validator does not know
+    /// any of the first few blocks, for example after it has switched forks, the subscription
+    /// is retried with progressively older blocks, and finally from the start of the chain.
+    ///
+    /// Only state changes to addresses starting with one of `namespaces` are received.
+    ///
+    /// If the connection to the validator drops, the processor reconnects with exponential
+    /// backoff and resubscribes from the last block it processed, reporting its progress
+    /// through `health`.
+    ///
+    /// If an event handler fails, the processor stops without recording the block as
+    /// processed, so that a restart resumes from the block before it.
     pub fn start(
-        sawtooth_connection: SawtoothConnection,
-        last_known_block_id: &str,
+        validator_endpoint: &str,
+        namespaces: &[String],
+        last_known_block_ids: &[String],
         event_handlers: Vec<Box<dyn EventHandler>>,
+        health: EventFeedHealth,
     ) -> Result<Self, EventProcessorError> {
+        let sawtooth_connection = SawtoothConnection::new(validator_endpoint);
         let message_sender = sawtooth_connection.get_sender();
 
-        let last_known_block_id = last_known_block_id.to_owned();
-        let request = create_subscription_request(last_known_block_id);
-        let mut future = message_sender.send(
-            Message_MessageType::CLIENT_EVENTS_SUBSCRIBE_REQUEST,
-            &correlation_id(),
-            &request.write_to_bytes()?,
-        )?;
+        let address_filter = format!("^({}).*", namespaces.join("|"));
 
-        let response: ClientEventsSubscribeResponse = content_of_type(
-            Message_MessageType::CLIENT_EVENTS_SUBSCRIBE_RESPONSE,
-            future.get()?,
-        )?;
+        subscribe(&*message_sender, &address_filter, last_known_block_ids)?;
+        health.connected();
 
-        if response.get_status() != ClientEventsSubscribeResponse_Status::OK {
-            return Err(EventProcessorError(format!(
-                "Failed to subscribe for events: {:?} {}",
-                response.get_status(),
-                response.get_response_message()
-            )));
-        }
+        let message_sender: SharedMessageSender = Arc::new(Mutex::new(message_sender));
+        let shutdown_signaled = Arc::new(AtomicBool::new(false));
+
+        let mut feed = EventFeed {
+            validator_endpoint: validator_endpoint.to_string(),
+            address_filter,
+            known_block_ids: last_known_block_ids.to_vec(),
+            message_sender: message_sender.clone(),
+            shutdown_signaled: shutdown_signaled.clone(),
+            health,
+        };
 
         let join_handle = thread::Builder::new()
             .name("EventProcessor".into())
             .spawn(move || {
-                while let Ok(msg_result) = sawtooth_connection.get_receiver().recv() {
-                    match msg_result {
-                        Ok(msg) => handle_message(msg, &event_handlers)?,
-                        Err(ReceiveError::DisconnectedError) => break,
-                        Err(err) => {
-                            return Err(EventProcessorError(format!(
-                                "Failed to receive events; aborting: {}",
-                                err
-                            )));
-                        }
+                let result = feed.run(sawtooth_connection, &event_handlers);
+                match result {
+                    Ok(()) => feed.health.stopped(),
+                    Err(ref err) => {
+                        error!("Event feed stopped: {}", err);
+                        feed.health.failed(&err.to_string());
                     }
                 }
-
-                info!("Disconnected from validator; terminating Event Processor");
-                Ok(())
+                info!("Terminating Event Processor");
+                result
             })
             .map_err(|err| {
                 EventProcessorError(format!("Unable to start EventProcessor thread: {}", err))
@@ -154,6 +174,7 @@ impl EventProcessor {
         Ok(Self {
             join_handle,
             message_sender,
+            shutdown_signaled,
         })
     }
 
@@ -165,45 +186,274 @@ impl EventProcessor {
     ) {
         (
             EventProcessorShutdownHandle {
-                message_sender: RefCell::new(self.message_sender),
+                message_sender: self.message_sender,
+                shutdown_signaled: self.shutdown_signaled,
             },
             self.join_handle,
         )
     }
 }
 
+/// The state of the event processor thread that survives a reconnect
+struct EventFeed {
+    validator_endpoint: String,
+    /// The regular expression that addresses of state changes must match
+    address_filter: String,
+    /// The most recently processed block ids, newest first
+    known_block_ids: Vec<String>,
+    message_sender: SharedMessageSender,
+    shutdown_signaled: Arc<AtomicBool>,
+    health: EventFeedHealth,
+}
+
+impl EventFeed {
+    fn run(
+        &mut self,
+        mut connection: SawtoothConnection,
+        event_handlers: &[Box<dyn EventHandler>],
+    ) -> Result<(), EventProcessorError> {
+        loop {
+            self.receive_events(&connection, event_handlers)?;
+
+            if self.is_shutdown_signaled() {
+                return Ok(());
+            }
+
+            warn!("Disconnected from validator; reconnecting");
+            connection = match self.reconnect() {
+                Some(connection) => connection,
+                None => return Ok(()),
+            };
+        }
+    }
+
+    /// Handles events until the connection to the validator is lost
+    fn receive_events(
+        &mut self,
+        connection: &SawtoothConnection,
+        event_handlers: &[Box<dyn EventHandler>],
+    ) -> Result<(), EventProcessorError> {
+        while let Ok(msg_result) = connection.get_receiver().recv() {
+            match msg_result {
+                Ok(msg) => {
+                    if let Some(block) = handle_message(msg, event_handlers)? {
+                        self.block_processed(&block);
+                    }
+                }
+                Err(ReceiveError::DisconnectedError) => break,
+                Err(err) => {
+                    return Err(EventProcessorError(format!(
+                        "Failed to receive events; aborting: {}",
+                        err
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn block_processed(&mut self, block: &BlockInfo) {
+        self.known_block_ids.insert(0, block.block_id.clone());
+        self.known_block_ids.truncate(MAX_KNOWN_BLOCK_IDS);
+        self.health.block_processed(block);
+    }
+
+    /// Connects and subscribes again, backing off between failed attempts. Returns `None` if
+    /// shutdown was signaled before a subscription succeeded.
+    fn reconnect(&self) -> Option<SawtoothConnection> {
+        let mut delay = INITIAL_RECONNECT_DELAY;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let connection = SawtoothConnection::new(&self.validator_endpoint);
+            let mut message_sender = connection.get_sender();
+
+            match subscribe(
+                &*message_sender,
+                &self.address_filter,
+                &self.known_block_ids,
+            ) {
+                Ok(()) => {
+                    let mut current_sender = match self.message_sender.lock() {
+                        Ok(current_sender) => current_sender,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+                    // The shutdown handle may have closed the previous sender while this one
+                    // was subscribing
+                    if self.is_shutdown_signaled() {
+                        message_sender.close();
+                        return None;
+                    }
+                    *current_sender = message_sender;
+
+                    info!("Reconnected to validator after {} attempt(s)", attempt);
+                    self.health.connected();
+                    return Some(connection);
+                }
+                Err(err) => {
+                    message_sender.close();
+                    warn!(
+                        "Reconnect attempt {} failed; retrying in {:?}: {}",
+                        attempt, delay, err
+                    );
+                    self.health.reconnecting(attempt, &err.to_string());
+                }
+            }
+
+            if !self.sleep_unless_shutdown(delay) {
+                return None;
+            }
+            delay = cmp::min(delay * 2, MAX_RECONNECT_DELAY);
+        }
+    }
+
+    /// Sleeps for `duration`, returning early with `false` if shutdown is signaled
+    fn sleep_unless_shutdown(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        while !self.is_shutdown_signaled() {
+            let now = Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            thread::sleep(cmp::min(deadline - now, Duration::from_millis(100)));
+        }
+
+        false
+    }
+
+    fn is_shutdown_signaled(&self) -> bool {
+        self.shutdown_signaled.load(Ordering::SeqCst)
+    }
+}
+
+fn unsubscribe(message_sender: &dyn MessageSender) -> Result<(), EventProcessorError> {
+    match message_sender
+        .send(
+            Message_MessageType::CLIENT_EVENTS_UNSUBSCRIBE_REQUEST,
+            &correlation_id(),
+            &[], // An unsubscribe request has no content
+        )
+        .map_err(|err| EventProcessorError(format!("Unable to send unsubscribe request: {}", err)))?
+        .get_timeout(Duration::from_secs(SHUTDOWN_TIMEOUT))
+    {
+        Ok(msg) => {
+            if msg.get_message_type() == Message_MessageType::CLIENT_EVENTS_UNSUBSCRIBE_RESPONSE {
+                debug!("Successfully unsubscribed");
+            } else {
+                debug!("During unsubscribe, received {:?}", msg.get_message_type());
+            }
+        }
+        Err(ReceiveError::TimeoutError) => {
+            debug!("Timeout occurred while waiting for unsubscribe response; ignoring")
+        }
+        Err(err) => return Err(EventProcessorError::from(err)),
+    }
+
+    Ok(())
+}
+
+fn subscribe(
+    message_sender: &dyn MessageSender,
+    address_filter: &str,
+    last_known_block_ids: &[String],
+) -> Result<(), EventProcessorError> {
+    let null_block_ids = [NULL_BLOCK_ID.to_string()];
+    let candidates = last_known_block_ids
+        .chunks(BLOCK_IDS_PER_SUBSCRIPTION)
+        .chain(std::iter::once(&null_block_ids[..]));
+
+    for block_ids in candidates {
+        let request = create_subscription_request(address_filter, block_ids);
+        let mut future = message_sender.send(
+            Message_MessageType::CLIENT_EVENTS_SUBSCRIBE_REQUEST,
+            &correlation_id(),
+            &request.write_to_bytes()?,
+        )?;
+
+        let response: ClientEventsSubscribeResponse = content_of_type(
+            Message_MessageType::CLIENT_EVENTS_SUBSCRIBE_RESPONSE,
+            future.get_timeout(Duration::from_secs(SUBSCRIBE_TIMEOUT))?,
+        )?;
+
+        match response.get_status() {
+            ClientEventsSubscribeResponse_Status::OK => {
+                debug!("Subscribed for events from one of {:?}", block_ids);
+                return Ok(());
+            }
+            ClientEventsSubscribeResponse_Status::UNKNOWN_BLOCK => {
+                warn!(
+                    "Validator does not know any of the blocks {:?}; trying earlier blocks",
+                    block_ids
+                );
+            }
+            status => {
+                return Err(EventProcessorError(format!(
+                    "Failed to subscribe for events: {:?} {}",
+                    status,
+                    response.get_response_message()
+                )));
+            }
+        }
+    }
+
+    Err(EventProcessorError(
+        "Failed to subscribe for events: no known block was accepted by the validator".into(),
+    ))
+}
+
+/// Dispatches the events of a message to their handlers, returning the block they were
+/// committed in
 fn handle_message(
     msg: Message,
     event_handlers: &[Box<dyn EventHandler>],
-) -> Result<(), EventProcessorError> {
+) -> Result<Option<BlockInfo>, EventProcessorError> {
     if msg.get_message_type() != Message_MessageType::CLIENT_EVENTS {
         warn!("Received unexpected message: {:?}", msg.get_message_type());
-        return Ok(());
+        return Ok(None);
     }
 
     let mut event_list: EventList = match protobuf::parse_from_bytes(msg.get_content()) {
         Ok(event_list) => event_list,
         Err(err) => {
             warn!("Unable to parse event list; ignoring: {}", err);
-            return Ok(());
+            return Ok(None);
         }
     };
 
-    for event in event_list.take_events().into_iter() {
-        let event_handler = event_handlers
-            .iter()
-            .find(|handler| handler.event_type() == event.get_event_type());
-
-        if let Some(event_handler) = event_handler {
-            if let Err(err) = event_handler.handle_event(&event) {
-                error!("Unable to handle event {}: {}", event.get_event_type(), err);
-            }
-        } else {
-            warn!("Unable to handle event {}", event.get_event_type());
+    let events = event_list.take_events().into_vec();
+
+    let block = match events
+        .iter()
+        .find(|event| event.get_event_type() == BLOCK_COMMIT_EVENT_TYPE)
+        .map(BlockInfo::from_event)
+    {
+        Some(Ok(block)) => block,
+        Some(Err(err)) => {
+            warn!("Unable to read block commit; ignoring event list: {}", err);
+            return Ok(None);
+        }
+        None => {
+            warn!("Received event list without a block commit; ignoring");
+            return Ok(None);
         }
+    };
+
+    // A block whose events are not all handled must not be recorded as processed; the feed
+    // stops so that the block is delivered again when it is restarted
+    for event_handler in event_handlers {
+        event_handler
+            .handle_events(&block, &events)
+            .map_err(|err| {
+                EventProcessorError(format!(
+                    "Unable to handle events of block {}: {}",
+                    block.block_id, err
+                ))
+            })?;
     }
 
-    Ok(())
+    Ok(Some(block))
 }
 
 fn content_of_type<M: protobuf::Message>(
@@ -222,17 +472,20 @@ fn content_of_type<M: protobuf::Message>(
         .map_err(|err| EventProcessorError(format!("Unable to parse message content: {}", err)))
 }
 
-fn create_subscription_request(last_known_block_id: String) -> ClientEventsSubscribeRequest {
+fn create_subscription_request(
+    address_filter: &str,
+    last_known_block_ids: &[String],
+) -> ClientEventsSubscribeRequest {
     let mut block_info_subscription = EventSubscription::new();
-    block_info_subscription.set_event_type("sawtooth/block-commit".into());
+    block_info_subscription.set_event_type(BLOCK_COMMIT_EVENT_TYPE.into());
 
     let mut grid_state_filter = EventFilter::new();
     grid_state_filter.set_filter_type(EventFilter_FilterType::REGEX_ANY);
     grid_state_filter.set_key("address".into());
-    grid_state_filter.set_match_string("621dee.*".into());
+    grid_state_filter.set_match_string(address_filter.into());
 
     let mut state_delta_subscription = EventSubscription::new();
-    state_delta_subscription.set_event_type("sawtooth/state-delta".into());
+    state_delta_subscription.set_event_type(STATE_DELTA_EVENT_TYPE.into());
     state_delta_subscription
         .mut_filters()
         .push(grid_state_filter);
@@ -240,7 +493,9 @@ fn create_subscription_request(last_known_block_id: String) -> ClientEventsSubsc
     let mut request = ClientEventsSubscribeRequest::new();
     request.mut_subscriptions().push(block_info_subscription);
     request.mut_subscriptions().push(state_delta_subscription);
-    request.mut_last_known_block_ids().push(last_known_block_id);
+    request
+        .mut_last_known_block_ids()
+        .extend(last_known_block_ids.iter().cloned());
 
     request
 }
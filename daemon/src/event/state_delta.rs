@@ -0,0 +1,732 @@
+/*
+ * Copyright 2019 Cargill Incorporated
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * -----------------------------------------------------------------------------
+ */
+
+use std::collections::HashMap;
+
+use grid_sdk::addressing::{parse_address, Resource};
+use grid_sdk::protocol::pike::state::{AgentList, KeyValueEntry, OrganizationList};
+use grid_sdk::protocol::schema::state::{PropertyDefinition, SchemaList};
+use grid_sdk::protos::track_and_trace_property::{
+    PropertyContainer, PropertyPageContainer, PropertySchema_DataType, TrackAndTracePropertyValue,
+};
+use grid_sdk::protos::track_and_trace_record::RecordContainer;
+use grid_sdk::protos::FromBytes;
+use sawtooth_sdk::messages::events::Event;
+use sawtooth_sdk::messages::transaction_receipt::{StateChangeList, StateChange_Type};
+
+use crate::database::{
+    helpers as db,
+    models::{
+        data_type_name, organization_status_name, NewAgent, NewAssociatedAgent,
+        NewGridPropertyDefinition, NewGridSchema, NewOrganization, NewProperty, NewRecord,
+        NewReportedValue, PropertyPageId,
+    },
+    Connection, DatabaseError, MAX_BLOCK_NUM,
+};
+
+use super::error::EventError;
+
+pub const STATE_DELTA_EVENT_TYPE: &str = "sawtooth/state-delta";
+
+/// The page number in a property address that holds the `Property` itself rather than a page of
+/// its reported values
+const PROPERTY_PAGE_NUM: i32 = 0;
+
+/// The database rows derived from a single state-delta event
+pub struct GridStateChanges {
+    block_num: i64,
+    agents: Vec<NewAgent>,
+    /// The public keys held by each changed agent address
+    agent_addresses: HashMap<String, Vec<String>>,
+    organizations: Vec<NewOrganization>,
+    /// The organization ids held by each changed organization address
+    organization_addresses: HashMap<String, Vec<String>>,
+    schemas: Vec<NewGridSchema>,
+    property_definitions: Vec<NewGridPropertyDefinition>,
+    records: Vec<NewRecord>,
+    associated_agents: Vec<NewAssociatedAgent>,
+    properties: Vec<NewProperty>,
+    property_pages: Vec<PropertyPageId>,
+    reported_values: Vec<NewReportedValue>,
+}
+
+impl GridStateChanges {
+    fn new(block_num: i64) -> Self {
+        Self {
+            block_num,
+            agents: Vec::new(),
+            agent_addresses: HashMap::new(),
+            organizations: Vec::new(),
+            organization_addresses: HashMap::new(),
+            schemas: Vec::new(),
+            property_definitions: Vec::new(),
+            records: Vec::new(),
+            associated_agents: Vec::new(),
+            properties: Vec::new(),
+            property_pages: Vec::new(),
+            reported_values: Vec::new(),
+        }
+    }
+
+    pub fn from_event(event: &Event, block_num: i64) -> Result<Self, EventError> {
+        let state_changes = protobuf::parse_from_bytes::<StateChangeList>(event.get_data())
+            .map_err(|err| EventError(format!("Unable to parse state change list: {}", err)))?
+            .take_state_changes();
+
+        let mut grid_state = GridStateChanges::new(block_num);
+        for state_change in state_changes.iter() {
+            match state_change.get_field_type() {
+                StateChange_Type::SET => {
+                    grid_state.add(state_change.get_address(), state_change.get_value())?
+                }
+                StateChange_Type::DELETE => grid_state.delete(state_change.get_address()),
+                change_type => debug!(
+                    "Ignoring {:?} of address {}",
+                    change_type,
+                    state_change.get_address()
+                ),
+            }
+        }
+
+        Ok(grid_state)
+    }
+
+    /// Writes the rows to the database; the caller is expected to run this inside the
+    /// transaction that records the block
+    pub fn store(&self, conn: &Connection) -> Result<(), DatabaseError> {
+        db::end_removed_agents(conn, &self.agent_addresses, self.block_num)?;
+        db::insert_agents(conn, &self.agents)?;
+        db::end_removed_organizations(conn, &self.organization_addresses, self.block_num)?;
+        db::insert_organizations(conn, &self.organizations)?;
+        db::insert_schemas(conn, &self.schemas, &self.property_definitions)?;
+        db::insert_records(conn, &self.records, &self.associated_agents)?;
+        db::insert_properties(conn, &self.properties)?;
+        db::insert_reported_values(
+            conn,
+            &self.property_pages,
+            &self.reported_values,
+            self.block_num,
+        )
+    }
+
+    fn add(&mut self, address: &str, value: &[u8]) -> Result<(), EventError> {
+        let resource = match parse_address(address) {
+            Ok(resource) => resource,
+            Err(err) => {
+                debug!(
+                    "Ignoring state change of unindexed address {}: {}",
+                    address, err
+                );
+                return Ok(());
+            }
+        };
+
+        match resource {
+            Resource::PikeAgent => self.add_agents(address, value),
+            Resource::PikeOrganization => self.add_organizations(address, value),
+            Resource::GridSchema => self.add_schemas(value),
+            Resource::TrackAndTraceRecord => self.add_records(value),
+            Resource::TrackAndTraceProperty { page } => {
+                if page as i32 == PROPERTY_PAGE_NUM {
+                    self.add_properties(value)
+                } else {
+                    self.add_property_pages(page as i32, value)
+                }
+            }
+            _ => {
+                debug!("Ignoring state change of unindexed address {}", address);
+                Ok(())
+            }
+        }
+    }
+
+    /// Records the deletion of an address; only Pike agents and organizations are removed from
+    /// state, and a deleted address no longer holds any of them
+    fn delete(&mut self, address: &str) {
+        match parse_address(address) {
+            Ok(Resource::PikeAgent) => {
+                self.agent_addresses.insert(address.to_string(), Vec::new());
+            }
+            Ok(Resource::PikeOrganization) => {
+                self.organization_addresses
+                    .insert(address.to_string(), Vec::new());
+            }
+            _ => debug!("Ignoring deletion of unindexed address {}", address),
+        }
+    }
+
+    fn add_agents(&mut self, address: &str, value: &[u8]) -> Result<(), EventError> {
+        let agent_list = AgentList::from_bytes(value)
+            .map_err(|err| EventError(format!("Unable to parse agent list: {}", err)))?;
+
+        self.agent_addresses.insert(
+            address.to_string(),
+            agent_list
+                .agents()
+                .iter()
+                .map(|agent| agent.public_key().to_string())
+                .collect(),
+        );
+
+        for agent in agent_list.agents() {
+            self.agents.push(NewAgent {
+                public_key: agent.public_key().to_string(),
+                org_id: agent.org_id().to_string(),
+                active: *agent.active(),
+                roles: to_json(agent.roles())?,
+                metadata: metadata_to_json(agent.metadata())?,
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn add_organizations(&mut self, address: &str, value: &[u8]) -> Result<(), EventError> {
+        let organization_list = OrganizationList::from_bytes(value)
+            .map_err(|err| EventError(format!("Unable to parse organization list: {}", err)))?;
+
+        self.organization_addresses.insert(
+            address.to_string(),
+            organization_list
+                .organizations()
+                .iter()
+                .map(|organization| organization.org_id().to_string())
+                .collect(),
+        );
+
+        for organization in organization_list.organizations() {
+            self.organizations.push(NewOrganization {
+                org_id: organization.org_id().to_string(),
+                name: organization.name().to_string(),
+                address: organization.address().to_string(),
+                metadata: metadata_to_json(organization.metadata())?,
+                status: organization_status_name(organization.status()).to_string(),
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn add_schemas(&mut self, value: &[u8]) -> Result<(), EventError> {
+        let schema_list = SchemaList::from_bytes(value)
+            .map_err(|err| EventError(format!("Unable to parse schema list: {}", err)))?;
+
+        for schema in schema_list.schemas() {
+            self.schemas.push(NewGridSchema {
+                name: schema.name().to_string(),
+                description: schema.description().to_string(),
+                owner: schema.owner().to_string(),
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+            self.add_property_definitions(schema.name(), None, schema.properties())?;
+        }
+
+        Ok(())
+    }
+
+    /// Flattens a tree of property definitions; nested definitions of a STRUCT property record
+    /// the dotted path of their parent.
+    fn add_property_definitions(
+        &mut self,
+        schema_name: &str,
+        parent_name: Option<&str>,
+        definitions: &[PropertyDefinition],
+    ) -> Result<(), EventError> {
+        for definition in definitions {
+            self.property_definitions.push(NewGridPropertyDefinition {
+                name: definition.name().to_string(),
+                schema_name: schema_name.to_string(),
+                data_type: data_type_name(definition.data_type()).to_string(),
+                required: *definition.required(),
+                description: definition.description().to_string(),
+                number_exponent: i64::from(*definition.number_exponent()),
+                enum_options: to_json(definition.enum_options())?,
+                parent_name: parent_name.map(ToOwned::to_owned),
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+
+            if !definition.struct_properties().is_empty() {
+                let path = match parent_name {
+                    Some(parent_name) => format!("{}.{}", parent_name, definition.name()),
+                    None => definition.name().to_string(),
+                };
+                self.add_property_definitions(
+                    schema_name,
+                    Some(&path),
+                    definition.struct_properties(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_records(&mut self, value: &[u8]) -> Result<(), EventError> {
+        let record_container = protobuf::parse_from_bytes::<RecordContainer>(value)
+            .map_err(|err| EventError(format!("Unable to parse record container: {}", err)))?;
+
+        for record in record_container.get_entries() {
+            self.records.push(NewRecord {
+                record_id: record.get_record_id().to_string(),
+                schema: record.get_record_type().to_string(),
+                finalized: record.get_field_final(),
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+
+            let owners = record.get_owners().iter().map(|owner| ("OWNER", owner));
+            let custodians = record
+                .get_custodians()
+                .iter()
+                .map(|custodian| ("CUSTODIAN", custodian));
+            for (role, associated_agent) in owners.chain(custodians) {
+                self.associated_agents.push(NewAssociatedAgent {
+                    record_id: record.get_record_id().to_string(),
+                    role: role.to_string(),
+                    agent_id: associated_agent.get_agent_id().to_string(),
+                    timestamp: associated_agent.get_timestamp() as i64,
+                    org_id: associated_agent.get_org_id().to_string(),
+                    start_block_num: self.block_num,
+                    end_block_num: MAX_BLOCK_NUM,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_properties(&mut self, value: &[u8]) -> Result<(), EventError> {
+        let property_container = protobuf::parse_from_bytes::<PropertyContainer>(value)
+            .map_err(|err| EventError(format!("Unable to parse property container: {}", err)))?;
+
+        for property in property_container.get_entries() {
+            let reporters = property
+                .get_reporters()
+                .iter()
+                .map(|reporter| {
+                    serde_json::json!({
+                        "public_key": reporter.get_public_key(),
+                        "authorized": reporter.get_authorized(),
+                        "index": reporter.get_index(),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            self.properties.push(NewProperty {
+                name: property.get_name().to_string(),
+                record_id: property.get_record_id().to_string(),
+                data_type: property_data_type_name(property.get_data_type()).to_string(),
+                reporters: to_json(&reporters)?,
+                current_page: property.get_current_page() as i32,
+                wrapped: property.get_wrapped(),
+                fixed: property.get_fixed(),
+                number_exponent: i64::from(property.get_number_exponent()),
+                enum_options: to_json(property.get_enum_options())?,
+                unit: property.get_unit().to_string(),
+                start_block_num: self.block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn add_property_pages(&mut self, page_num: i32, value: &[u8]) -> Result<(), EventError> {
+        let page_container =
+            protobuf::parse_from_bytes::<PropertyPageContainer>(value).map_err(|err| {
+                EventError(format!("Unable to parse property page container: {}", err))
+            })?;
+
+        for page in page_container.get_entries() {
+            self.property_pages.push(PropertyPageId {
+                record_id: page.get_record_id().to_string(),
+                property_name: page.get_name().to_string(),
+                page_num,
+            });
+
+            for reported_value in page.get_reported_values() {
+                let struct_values = reported_value
+                    .get_struct_values()
+                    .iter()
+                    .map(struct_value_json)
+                    .collect::<Vec<_>>();
+
+                self.reported_values.push(NewReportedValue {
+                    record_id: page.get_record_id().to_string(),
+                    property_name: page.get_name().to_string(),
+                    page_num,
+                    reporter_index: reported_value.get_reporter_index() as i32,
+                    timestamp: reported_value.get_timestamp() as i64,
+                    bytes_value: base64::encode(reported_value.get_bytes_value()),
+                    boolean_value: reported_value.get_boolean_value(),
+                    number_value: reported_value.get_number_value(),
+                    string_value: reported_value.get_string_value().to_string(),
+                    enum_value: reported_value.get_enum_value() as i32,
+                    struct_values: to_json(&struct_values)?,
+                    latitude: reported_value.get_location_value().get_latitude(),
+                    longitude: reported_value.get_location_value().get_longitude(),
+                    start_block_num: self.block_num,
+                    end_block_num: MAX_BLOCK_NUM,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a member of a STRUCT value to JSON, keeping only the field that matches its type
+fn struct_value_json(value: &TrackAndTracePropertyValue) -> serde_json::Value {
+    let data_type = value.get_data_type();
+    let json_value = match data_type {
+        PropertySchema_DataType::TYPE_UNSET => serde_json::Value::Null,
+        PropertySchema_DataType::BYTES => base64::encode(value.get_bytes_value()).into(),
+        PropertySchema_DataType::BOOLEAN => value.get_boolean_value().into(),
+        PropertySchema_DataType::NUMBER => value.get_number_value().into(),
+        PropertySchema_DataType::STRING => value.get_string_value().into(),
+        PropertySchema_DataType::ENUM => value.get_enum_value().into(),
+        PropertySchema_DataType::STRUCT => value
+            .get_struct_values()
+            .iter()
+            .map(struct_value_json)
+            .collect::<Vec<_>>()
+            .into(),
+        PropertySchema_DataType::LOCATION => serde_json::json!({
+            "latitude": value.get_location_value().get_latitude(),
+            "longitude": value.get_location_value().get_longitude(),
+        }),
+    };
+
+    serde_json::json!({
+        "name": value.get_name(),
+        "data_type": property_data_type_name(data_type),
+        "value": json_value,
+    })
+}
+
+fn property_data_type_name(data_type: PropertySchema_DataType) -> &'static str {
+    match data_type {
+        PropertySchema_DataType::TYPE_UNSET => "TYPE_UNSET",
+        PropertySchema_DataType::BYTES => "BYTES",
+        PropertySchema_DataType::BOOLEAN => "BOOLEAN",
+        PropertySchema_DataType::NUMBER => "NUMBER",
+        PropertySchema_DataType::STRING => "STRING",
+        PropertySchema_DataType::ENUM => "ENUM",
+        PropertySchema_DataType::STRUCT => "STRUCT",
+        PropertySchema_DataType::LOCATION => "LOCATION",
+    }
+}
+
+fn metadata_to_json(metadata: &[KeyValueEntry]) -> Result<String, EventError> {
+    let metadata = metadata
+        .iter()
+        .map(|entry| {
+            serde_json::json!({
+                "key": entry.key(),
+                "value": entry.value(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    to_json(&metadata)
+}
+
+fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, EventError> {
+    serde_json::to_string(value)
+        .map_err(|err| EventError(format!("Unable to serialize value: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use grid_sdk::addressing::{
+        compute_pike_agent_address, compute_pike_organization_address, compute_schema_address,
+        compute_track_and_trace_property_address, compute_track_and_trace_record_address,
+    };
+    use grid_sdk::protocol::pike::state::{
+        AgentBuilder, AgentListBuilder, OrganizationBuilder, OrganizationListBuilder,
+        OrganizationStatus,
+    };
+    use grid_sdk::protocol::schema::state::{
+        DataType, PropertyDefinitionBuilder, SchemaBuilder, SchemaListBuilder,
+    };
+    use grid_sdk::protos::track_and_trace_property::{
+        Property, PropertyPage, PropertyPage_ReportedValue,
+    };
+    use grid_sdk::protos::track_and_trace_record::{Record, Record_AssociatedAgent};
+    use grid_sdk::protos::IntoBytes;
+    use protobuf::{Message, RepeatedField};
+    use sawtooth_sdk::messages::transaction_receipt::StateChange;
+
+    const BLOCK_NUM: i64 = 5;
+
+    /// Returns a state-delta event setting each address to its value, or deleting it if there
+    /// is no value
+    fn state_delta_event(changes: Vec<(String, Option<Vec<u8>>)>) -> Event {
+        let mut state_change_list = StateChangeList::new();
+        for (address, value) in changes {
+            let mut state_change = StateChange::new();
+            state_change.set_address(address);
+            match value {
+                Some(value) => {
+                    state_change.set_field_type(StateChange_Type::SET);
+                    state_change.set_value(value);
+                }
+                None => state_change.set_field_type(StateChange_Type::DELETE),
+            }
+            state_change_list.mut_state_changes().push(state_change);
+        }
+
+        let mut event = Event::new();
+        event.set_event_type(STATE_DELTA_EVENT_TYPE.to_string());
+        event.set_data(state_change_list.write_to_bytes().unwrap());
+        event
+    }
+
+    fn decode(changes: Vec<(String, Option<Vec<u8>>)>) -> GridStateChanges {
+        GridStateChanges::from_event(&state_delta_event(changes), BLOCK_NUM).unwrap()
+    }
+
+    fn agent_list(public_key: &str) -> Vec<u8> {
+        let agent = AgentBuilder::new()
+            .with_org_id("org_01".to_string())
+            .with_public_key(public_key.to_string())
+            .with_active(true)
+            .with_roles(vec!["admin".to_string()])
+            .build()
+            .unwrap();
+        AgentListBuilder::new()
+            .with_agents(vec![agent])
+            .build()
+            .unwrap()
+            .into_bytes()
+            .unwrap()
+    }
+
+    fn organization_list(org_id: &str) -> Vec<u8> {
+        let organization = OrganizationBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_name("Organization".to_string())
+            .with_address("address".to_string())
+            .with_status(OrganizationStatus::Suspended)
+            .build()
+            .unwrap();
+        OrganizationListBuilder::new()
+            .with_organizations(vec![organization])
+            .build()
+            .unwrap()
+            .into_bytes()
+            .unwrap()
+    }
+
+    #[test]
+    // check that a Pike agent list is decoded into agents tagged with the block number
+    fn check_agent_changes() {
+        let address = compute_pike_agent_address("agent_key");
+        let changes = decode(vec![(address.clone(), Some(agent_list("agent_key")))]);
+
+        assert_eq!(changes.agents.len(), 1);
+        assert_eq!(changes.agents[0].public_key, "agent_key");
+        assert_eq!(changes.agents[0].org_id, "org_01");
+        assert_eq!(changes.agents[0].roles, r#"["admin"]"#);
+        assert_eq!(changes.agents[0].start_block_num, BLOCK_NUM);
+        assert_eq!(changes.agents[0].end_block_num, MAX_BLOCK_NUM);
+        assert_eq!(
+            changes.agent_addresses.get(&address),
+            Some(&vec!["agent_key".to_string()])
+        );
+    }
+
+    #[test]
+    // check that a Pike organization list is decoded into organizations with their status
+    fn check_organization_changes() {
+        let address = compute_pike_organization_address("org_01");
+        let changes = decode(vec![(address.clone(), Some(organization_list("org_01")))]);
+
+        assert_eq!(changes.organizations.len(), 1);
+        assert_eq!(changes.organizations[0].org_id, "org_01");
+        assert_eq!(changes.organizations[0].status, "SUSPENDED");
+        assert_eq!(
+            changes.organization_addresses.get(&address),
+            Some(&vec!["org_01".to_string()])
+        );
+    }
+
+    #[test]
+    // check that a schema list is decoded into schemas and flattened property definitions
+    fn check_schema_changes() {
+        let unit = PropertyDefinitionBuilder::new()
+            .with_name("unit".to_string())
+            .with_data_type(DataType::String)
+            .build()
+            .unwrap();
+        let dimensions = PropertyDefinitionBuilder::new()
+            .with_name("dimensions".to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_properties(vec![unit])
+            .build()
+            .unwrap();
+        let schema = SchemaBuilder::new()
+            .with_name("product".to_string())
+            .with_owner("org_01".to_string())
+            .with_properties(vec![dimensions])
+            .build()
+            .unwrap();
+        let schema_list = SchemaListBuilder::new()
+            .with_schemas(vec![schema])
+            .build()
+            .unwrap()
+            .into_bytes()
+            .unwrap();
+
+        let changes = decode(vec![(compute_schema_address("product"), Some(schema_list))]);
+
+        assert_eq!(changes.schemas.len(), 1);
+        assert_eq!(changes.schemas[0].name, "product");
+        assert_eq!(changes.schemas[0].owner, "org_01");
+        assert_eq!(changes.property_definitions.len(), 2);
+        assert_eq!(changes.property_definitions[0].name, "dimensions");
+        assert_eq!(changes.property_definitions[0].data_type, "STRUCT");
+        assert_eq!(changes.property_definitions[0].parent_name, None);
+        assert_eq!(changes.property_definitions[1].name, "unit");
+        assert_eq!(
+            changes.property_definitions[1].parent_name,
+            Some("dimensions".to_string())
+        );
+    }
+
+    #[test]
+    // check that a record container is decoded into records and their owners and custodians
+    fn check_record_changes() {
+        let mut owner = Record_AssociatedAgent::new();
+        owner.set_agent_id("owner_key".to_string());
+        owner.set_org_id("org_01".to_string());
+        owner.set_timestamp(10);
+        let mut custodian = Record_AssociatedAgent::new();
+        custodian.set_agent_id("custodian_key".to_string());
+        custodian.set_org_id("org_02".to_string());
+        custodian.set_timestamp(20);
+
+        let mut record = Record::new();
+        record.set_record_id("record_01".to_string());
+        record.set_record_type("product".to_string());
+        record.set_owners(RepeatedField::from_vec(vec![owner]));
+        record.set_custodians(RepeatedField::from_vec(vec![custodian]));
+        let mut record_container = RecordContainer::new();
+        record_container.mut_entries().push(record);
+
+        let changes = decode(vec![(
+            compute_track_and_trace_record_address("record_01"),
+            Some(record_container.write_to_bytes().unwrap()),
+        )]);
+
+        assert_eq!(changes.records.len(), 1);
+        assert_eq!(changes.records[0].record_id, "record_01");
+        assert_eq!(changes.records[0].schema, "product");
+        assert_eq!(changes.associated_agents.len(), 2);
+        assert_eq!(changes.associated_agents[0].role, "OWNER");
+        assert_eq!(changes.associated_agents[0].agent_id, "owner_key");
+        assert_eq!(changes.associated_agents[1].role, "CUSTODIAN");
+        assert_eq!(changes.associated_agents[1].org_id, "org_02");
+        assert_eq!(changes.associated_agents[1].timestamp, 20);
+    }
+
+    #[test]
+    // check that the first page of a property address holds the property and later pages hold
+    // its reported values
+    fn check_property_changes() {
+        let mut property = Property::new();
+        property.set_name("weight".to_string());
+        property.set_record_id("record_01".to_string());
+        property.set_data_type(PropertySchema_DataType::NUMBER);
+        let mut property_container = PropertyContainer::new();
+        property_container.mut_entries().push(property);
+
+        let mut reported_value = PropertyPage_ReportedValue::new();
+        reported_value.set_reporter_index(1);
+        reported_value.set_timestamp(30);
+        reported_value.set_number_value(42);
+        let mut page = PropertyPage::new();
+        page.set_name("weight".to_string());
+        page.set_record_id("record_01".to_string());
+        page.mut_reported_values().push(reported_value);
+        let mut page_container = PropertyPageContainer::new();
+        page_container.mut_entries().push(page);
+
+        let changes = decode(vec![
+            (
+                compute_track_and_trace_property_address("record_01", "weight", 0),
+                Some(property_container.write_to_bytes().unwrap()),
+            ),
+            (
+                compute_track_and_trace_property_address("record_01", "weight", 1),
+                Some(page_container.write_to_bytes().unwrap()),
+            ),
+        ]);
+
+        assert_eq!(changes.properties.len(), 1);
+        assert_eq!(changes.properties[0].name, "weight");
+        assert_eq!(changes.properties[0].data_type, "NUMBER");
+        assert_eq!(changes.property_pages.len(), 1);
+        assert_eq!(changes.property_pages[0].page_num, 1);
+        assert_eq!(changes.reported_values.len(), 1);
+        assert_eq!(changes.reported_values[0].property_name, "weight");
+        assert_eq!(changes.reported_values[0].reporter_index, 1);
+        assert_eq!(changes.reported_values[0].number_value, 42);
+    }
+
+    #[test]
+    // check that deleting a Pike agent or organization address records that it holds nothing,
+    // and that deletions of other addresses are ignored
+    fn check_delete_changes() {
+        let agent_address = compute_pike_agent_address("agent_key");
+        let organization_address = compute_pike_organization_address("org_01");
+
+        let changes = decode(vec![
+            (agent_address.clone(), None),
+            (organization_address.clone(), None),
+            (compute_track_and_trace_record_address("record_01"), None),
+        ]);
+
+        assert!(changes.agents.is_empty());
+        assert_eq!(changes.agent_addresses.get(&agent_address), Some(&vec![]));
+        assert!(changes.organizations.is_empty());
+        assert_eq!(
+            changes.organization_addresses.get(&organization_address),
+            Some(&vec![])
+        );
+        assert!(changes.records.is_empty());
+    }
+
+    #[test]
+    // check that changes to addresses outside the indexed namespaces are ignored
+    fn check_unindexed_changes() {
+        let changes = decode(vec![("000000".repeat(11) + "0000", Some(vec![1, 2, 3]))]);
+
+        assert!(changes.agents.is_empty());
+        assert!(changes.agent_addresses.is_empty());
+        assert!(changes.schemas.is_empty());
+        assert!(changes.records.is_empty());
+    }
+}
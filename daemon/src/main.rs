@@ -18,14 +18,20 @@
 #[macro_use]
 extern crate clap;
 #[macro_use]
+extern crate diesel;
+#[macro_use]
+extern crate diesel_migrations;
+#[macro_use]
 extern crate log;
 
 mod config;
+mod database;
 mod error;
 mod event;
 mod rest_api;
 mod sawtooth_connection;
 
+use std::env;
 use std::process;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -33,39 +39,68 @@ use simple_logger;
 
 use crate::config::GridConfigBuilder;
 use crate::error::DaemonError;
-use crate::event::{block::BlockEventHandler, EventProcessor};
+use crate::event::{block::BlockEventHandler, health::EventFeedHealth, EventProcessor};
 use crate::sawtooth_connection::SawtoothConnection;
 
 const APP_NAME: &str = env!("CARGO_PKG_NAME");
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The number of recent blocks to offer the validator when resuming the event subscription
+const KNOWN_BLOCK_COUNT: i64 = 100;
+
 fn run() -> Result<(), DaemonError> {
     let matches = clap_app!(myapp =>
         (name: APP_NAME)
         (version: VERSION)
         (author: "Contributors to Hyperledger Grid")
         (about: "Daemon Package for Hyperledger Grid")
+        (@arg config: -c --config +takes_value "path to a TOML configuration file")
         (@arg connect: -C --connect +takes_value "connection endpoint for validator")
         (@arg verbose: -v +multiple "Log verbosely")
         (@arg bind: -b --bind +takes_value "connection endpoint for rest API")
+        (@arg database_url: --("database-url") +takes_value
+          "URL of the PostgreSQL (postgres://) or SQLite (sqlite://) database")
+        (@arg tls_cert: --("tls-cert") +takes_value
+          "certificate chain file used to serve the REST API over HTTPS")
+        (@arg tls_key: --("tls-key") +takes_value
+          "private key file used to serve the REST API over HTTPS")
     )
     .get_matches();
 
     let config = GridConfigBuilder::default()
+        .with_config_file(matches.value_of("config"))?
+        .with_env_vars(env::vars())?
         .with_cli_args(&matches)
         .build()?;
 
     simple_logger::init_with_level(config.log_level())?;
 
+    let connection_pool = database::create_connection_pool(config.database_url())?;
+    database::run_migrations(&connection_pool.get()?)?;
+
     let sawtooth_connection = SawtoothConnection::new(config.validator_endpoint());
 
-    let (rest_api_shutdown_handle, rest_api_join_handle) =
-        rest_api::run(config.rest_api_endpoint(), sawtooth_connection.get_sender())?;
+    let event_feed_health = EventFeedHealth::default();
+
+    let (rest_api_shutdown_handle, rest_api_join_handle) = rest_api::run(
+        config.rest_api_endpoint(),
+        sawtooth_connection.get_sender(),
+        connection_pool.clone(),
+        event_feed_health.clone(),
+        config.request_timeout(),
+        config.tls_paths(),
+    )?;
+
+    // Blocks are stored together with their state changes, so every recorded block is complete
+    let last_known_block_ids =
+        database::helpers::get_last_known_block_ids(&connection_pool.get()?, KNOWN_BLOCK_COUNT)?;
 
     let evt_processor = EventProcessor::start(
-        sawtooth_connection,
-        "0000000000000000",
-        event_handlers![BlockEventHandler::new()],
+        config.validator_endpoint(),
+        config.namespaces(),
+        &last_known_block_ids,
+        event_handlers![BlockEventHandler::new(connection_pool)],
+        event_feed_health,
     )
     .map_err(|err| DaemonError::EventProcessorError(Box::new(err)))?;
 
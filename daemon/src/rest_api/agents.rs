@@ -0,0 +1,160 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix::{Handler, Message, SyncContext};
+use actix_web::{HttpResponse, Path, State};
+use futures::future::Future;
+use grid_sdk::protocol::pike::state::{Agent, AgentBuilder, KeyValueEntry, KeyValueEntryBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::database::{helpers as db, models};
+use crate::rest_api::{db_executor::DbExecutor, error::RestApiResponseError, AppState};
+
+/// The JSON representation of a Pike `Agent`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AgentSlice {
+    pub public_key: String,
+    pub org_id: String,
+    pub active: bool,
+    pub roles: Vec<String>,
+    pub metadata: Vec<KeyValueEntrySlice>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KeyValueEntrySlice {
+    pub key: String,
+    pub value: String,
+}
+
+impl AgentSlice {
+    pub fn from_agent(agent: &Agent) -> Self {
+        Self {
+            public_key: agent.public_key().to_string(),
+            org_id: agent.org_id().to_string(),
+            active: *agent.active(),
+            roles: agent.roles().to_vec(),
+            metadata: agent
+                .metadata()
+                .iter()
+                .map(|entry| KeyValueEntrySlice {
+                    key: entry.key().to_string(),
+                    value: entry.value().to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Rebuilds a Pike `Agent` from its row in the database
+fn agent_from_row(row: models::Agent) -> Result<Agent, RestApiResponseError> {
+    let roles: Vec<String> = serde_json::from_str(&row.roles).map_err(|err| {
+        RestApiResponseError::DatabaseError(format!("Unable to parse agent roles: {}", err))
+    })?;
+    let metadata: Vec<KeyValueEntrySlice> = serde_json::from_str(&row.metadata).map_err(|err| {
+        RestApiResponseError::DatabaseError(format!("Unable to parse agent metadata: {}", err))
+    })?;
+    let metadata = metadata
+        .into_iter()
+        .map(|entry| {
+            KeyValueEntryBuilder::new()
+                .with_key(entry.key)
+                .with_value(entry.value)
+                .build()
+        })
+        .collect::<Result<Vec<KeyValueEntry>, _>>()
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+    AgentBuilder::new()
+        .with_public_key(row.public_key)
+        .with_org_id(row.org_id)
+        .with_active(row.active)
+        .with_roles(roles)
+        .with_metadata(metadata)
+        .build()
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))
+}
+
+struct ListAgents;
+
+impl Message for ListAgents {
+    type Result = Result<Vec<AgentSlice>, RestApiResponseError>;
+}
+
+impl Handler<ListAgents> for DbExecutor {
+    type Result = Result<Vec<AgentSlice>, RestApiResponseError>;
+
+    fn handle(&mut self, _msg: ListAgents, _: &mut SyncContext<Self>) -> Self::Result {
+        db::list_agents(&self.get_connection()?)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+            .into_iter()
+            .map(|row| agent_from_row(row).map(|agent| AgentSlice::from_agent(&agent)))
+            .collect()
+    }
+}
+
+pub fn list_agents(
+    state: State<AppState>,
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(ListAgents)
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(agents) => Ok(HttpResponse::Ok().json(agents)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+struct FetchAgent {
+    public_key: String,
+}
+
+impl Message for FetchAgent {
+    type Result = Result<AgentSlice, RestApiResponseError>;
+}
+
+impl Handler<FetchAgent> for DbExecutor {
+    type Result = Result<AgentSlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchAgent, _: &mut SyncContext<Self>) -> Self::Result {
+        match db::fetch_agent(&self.get_connection()?, &msg.public_key)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+        {
+            Some(row) => Ok(AgentSlice::from_agent(&agent_from_row(row)?)),
+            None => Err(RestApiResponseError::NotFoundError(format!(
+                "Could not find agent with public key: {}",
+                msg.public_key
+            ))),
+        }
+    }
+}
+
+pub fn fetch_agent(
+    (state, public_key): (State<AppState>, Path<String>),
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(FetchAgent {
+                public_key: public_key.into_inner(),
+            })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(agent) => Ok(HttpResponse::Ok().json(agent)),
+                Err(err) => Err(err),
+            }),
+    )
+}
@@ -0,0 +1,40 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix::{Actor, SyncContext};
+
+use crate::database::{Connection, ConnectionPool};
+use crate::rest_api::error::RestApiResponseError;
+
+/// Answers queries against the reporting database. Database calls block, so the executor runs
+/// on its own threads in a `SyncArbiter` rather than on the server's event loop.
+pub struct DbExecutor {
+    connection_pool: ConnectionPool,
+}
+
+impl Actor for DbExecutor {
+    type Context = SyncContext<Self>;
+}
+
+impl DbExecutor {
+    pub fn new(connection_pool: ConnectionPool) -> DbExecutor {
+        DbExecutor { connection_pool }
+    }
+
+    pub fn get_connection(&self) -> Result<Connection, RestApiResponseError> {
+        self.connection_pool
+            .get()
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))
+    }
+}
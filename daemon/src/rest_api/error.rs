@@ -52,6 +52,8 @@ impl fmt::Display for RestApiServerError {
 #[derive(Debug)]
 pub enum RestApiResponseError {
     BadRequest(String),
+    NotFoundError(String),
+    DatabaseError(String),
     SawtoothConnectionError(String),
     SawtoothValidatorResponseError(String),
     RequestHandlerError(String),
@@ -61,6 +63,8 @@ impl Error for RestApiResponseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             RestApiResponseError::BadRequest(_) => None,
+            RestApiResponseError::NotFoundError(_) => None,
+            RestApiResponseError::DatabaseError(_) => None,
             RestApiResponseError::SawtoothConnectionError(_) => None,
             RestApiResponseError::SawtoothValidatorResponseError(_) => None,
             RestApiResponseError::RequestHandlerError(_) => None,
@@ -72,6 +76,8 @@ impl fmt::Display for RestApiResponseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             RestApiResponseError::BadRequest(ref s) => write!(f, "Bad Request: {}", s),
+            RestApiResponseError::NotFoundError(ref s) => write!(f, "Not Found: {}", s),
+            RestApiResponseError::DatabaseError(ref s) => write!(f, "Database Error: {}", s),
             RestApiResponseError::SawtoothConnectionError(ref s) => {
                 write!(f, "Zmq Connection Error: {}", s)
             }
@@ -92,6 +98,13 @@ impl ResponseError for RestApiResponseError {
             RestApiResponseError::BadRequest(ref message) => {
                 HttpResponse::BadRequest().json(message)
             }
+            RestApiResponseError::NotFoundError(ref message) => {
+                HttpResponse::NotFound().json(message)
+            }
+            RestApiResponseError::DatabaseError(ref message) => {
+                error!("{}", message);
+                HttpResponse::ServiceUnavailable().json("Unable to query the reporting database")
+            }
             RestApiResponseError::SawtoothConnectionError(ref message) => {
                 HttpResponse::ServiceUnavailable().json(message)
             }
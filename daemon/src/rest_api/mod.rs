@@ -12,20 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agents;
+mod db_executor;
 mod error;
+mod organizations;
+mod records;
 mod route_handler;
+mod schemas;
 
 use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
 
+use crate::database::ConnectionPool;
+use crate::event::health::EventFeedHealth;
+use crate::rest_api::agents::{fetch_agent, list_agents};
+use crate::rest_api::db_executor::DbExecutor;
 pub use crate::rest_api::error::RestApiServerError;
-use crate::rest_api::route_handler::{get_batch_statuses, submit_batches, SawtoothMessageSender};
-use actix::{Actor, Addr, Context};
+use crate::rest_api::organizations::{fetch_organization, list_organizations};
+use crate::rest_api::records::{fetch_record, fetch_record_property, list_records};
+use crate::rest_api::route_handler::{
+    get_batch_statuses, get_health, submit_batches, SawtoothMessageSender,
+};
+use crate::rest_api::schemas::{fetch_schema, list_schemas};
+use actix::{Actor, Addr, Context, SyncArbiter};
 use actix_web::{http::Method, server, App};
+use openssl::ssl::{SslAcceptor, SslAcceptorBuilder, SslFiletype, SslMethod};
 use sawtooth_sdk::messaging::stream::MessageSender;
 
+/// The number of threads answering database queries
+const DB_EXECUTOR_THREADS: usize = 4;
+
 pub struct AppState {
     sawtooth_connection: Addr<SawtoothMessageSender>,
+    database_connection: Addr<DbExecutor>,
+    event_feed_health: EventFeedHealth,
+    request_timeout: u64,
 }
 
 pub struct RestApiShutdownHandle {
@@ -38,9 +60,17 @@ impl RestApiShutdownHandle {
     }
 }
 
-fn create_app(sawtooth_connection: Addr<SawtoothMessageSender>) -> App<AppState> {
+fn create_app(
+    sawtooth_connection: Addr<SawtoothMessageSender>,
+    database_connection: Addr<DbExecutor>,
+    event_feed_health: EventFeedHealth,
+    request_timeout: u64,
+) -> App<AppState> {
     App::with_state(AppState {
         sawtooth_connection,
+        database_connection,
+        event_feed_health,
+        request_timeout,
     })
     .resource("/batches", |r| {
         r.method(Method::POST).with_async(submit_batches)
@@ -49,11 +79,44 @@ fn create_app(sawtooth_connection: Addr<SawtoothMessageSender>) -> App<AppState>
         r.name("batch_statuses");
         r.method(Method::GET).with_async(get_batch_statuses)
     })
+    .resource("/health", |r| r.method(Method::GET).with(get_health))
+    .resource("/agent", |r| r.method(Method::GET).with_async(list_agents))
+    .resource("/agent/{public_key}", |r| {
+        r.method(Method::GET).with_async(fetch_agent)
+    })
+    .resource("/organization", |r| {
+        r.method(Method::GET).with_async(list_organizations)
+    })
+    .resource("/organization/{id}", |r| {
+        r.method(Method::GET).with_async(fetch_organization)
+    })
+    .resource("/schema", |r| {
+        r.method(Method::GET).with_async(list_schemas)
+    })
+    .resource("/schema/{name}", |r| {
+        r.method(Method::GET).with_async(fetch_schema)
+    })
+    .resource("/record", |r| {
+        r.method(Method::GET).with_async(list_records)
+    })
+    .resource("/record/{record_id}", |r| {
+        r.method(Method::GET).with_async(fetch_record)
+    })
+    .resource("/record/{record_id}/property/{property_name}", |r| {
+        r.method(Method::GET).with_async(fetch_record_property)
+    })
 }
 
+/// Starts the REST API on its own thread. Requests to the validator time out after
+/// `request_timeout` seconds. If `tls_paths` holds a certificate chain and private key file,
+/// the API is served over HTTPS.
 pub fn run(
     bind_url: &str,
     zmq_sender: Box<dyn MessageSender + Send>,
+    connection_pool: ConnectionPool,
+    event_feed_health: EventFeedHealth,
+    request_timeout: u64,
+    tls_paths: Option<(&str, &str)>,
 ) -> Result<
     (
         RestApiShutdownHandle,
@@ -63,21 +126,39 @@ pub fn run(
 > {
     let (tx, rx) = mpsc::channel();
     let bind_url = bind_url.to_owned();
+    let tls_paths =
+        tls_paths.map(|(cert_path, key_path)| (cert_path.to_owned(), key_path.to_owned()));
     let join_handle = thread::Builder::new()
         .name("GridRestApi".into())
         .spawn(move || {
             let sys = actix::System::new("Grid-Rest-API");
             let zmq_connection_addr =
                 SawtoothMessageSender::create(move |_ctx: &mut Context<SawtoothMessageSender>| {
-                    SawtoothMessageSender::new(zmq_sender)
+                    SawtoothMessageSender::new(zmq_sender, Duration::from_secs(request_timeout))
                 });
-
-            info!("Starting Rest API at {}", &bind_url);
-            let addr = server::new(move || create_app(zmq_connection_addr.clone()))
-                .bind(bind_url)?
-                .disable_signals()
-                .system_exit()
-                .start();
+            let db_executor_addr = SyncArbiter::start(DB_EXECUTOR_THREADS, move || {
+                DbExecutor::new(connection_pool.clone())
+            });
+
+            let server = server::new(move || {
+                create_app(
+                    zmq_connection_addr.clone(),
+                    db_executor_addr.clone(),
+                    event_feed_health.clone(),
+                    request_timeout,
+                )
+            });
+            let server = match tls_paths {
+                Some((cert_path, key_path)) => {
+                    info!("Starting Rest API at https://{}", &bind_url);
+                    server.bind_ssl(bind_url, create_ssl_acceptor(&cert_path, &key_path)?)?
+                }
+                None => {
+                    info!("Starting Rest API at {}", &bind_url);
+                    server.bind(bind_url)?
+                }
+            };
+            let addr = server.disable_signals().system_exit().start();
 
             tx.send(addr).map_err(|err| {
                 RestApiServerError::StartUpError(format!("Unable to send Server Addr: {}", err))
@@ -104,3 +185,19 @@ pub fn run(
 
     Ok((RestApiShutdownHandle { do_shutdown }, join_handle))
 }
+
+fn create_ssl_acceptor(
+    cert_path: &str,
+    key_path: &str,
+) -> Result<SslAcceptorBuilder, RestApiServerError> {
+    SslAcceptor::mozilla_intermediate(SslMethod::tls())
+        .and_then(|mut builder| {
+            builder.set_private_key_file(key_path, SslFiletype::PEM)?;
+            builder.set_certificate_chain_file(cert_path)?;
+            builder.check_private_key()?;
+            Ok(builder)
+        })
+        .map_err(|err| {
+            RestApiServerError::StartUpError(format!("Unable to configure TLS: {}", err))
+        })
+}
@@ -0,0 +1,175 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use actix::{Handler, Message, SyncContext};
+use actix_web::{HttpResponse, Path, State};
+use futures::future::Future;
+use grid_sdk::protocol::pike::state::{
+    KeyValueEntry, KeyValueEntryBuilder, Organization, OrganizationBuilder, OrganizationStatus,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::database::{helpers as db, models};
+use crate::rest_api::agents::KeyValueEntrySlice;
+use crate::rest_api::{db_executor::DbExecutor, error::RestApiResponseError, AppState};
+
+/// The JSON representation of a Pike `Organization`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OrganizationSlice {
+    pub org_id: String,
+    pub name: String,
+    pub address: String,
+    pub metadata: Vec<KeyValueEntrySlice>,
+    pub status: String,
+}
+
+impl OrganizationSlice {
+    pub fn from_organization(organization: &Organization) -> Self {
+        Self {
+            org_id: organization.org_id().to_string(),
+            name: organization.name().to_string(),
+            address: organization.address().to_string(),
+            metadata: organization
+                .metadata()
+                .iter()
+                .map(|entry| KeyValueEntrySlice {
+                    key: entry.key().to_string(),
+                    value: entry.value().to_string(),
+                })
+                .collect(),
+            status: models::organization_status_name(organization.status()).to_string(),
+        }
+    }
+}
+
+fn parse_status(name: &str) -> Result<OrganizationStatus, RestApiResponseError> {
+    match name {
+        "ACTIVE" => Ok(OrganizationStatus::Active),
+        "SUSPENDED" => Ok(OrganizationStatus::Suspended),
+        _ => Err(RestApiResponseError::DatabaseError(format!(
+            "Unknown organization status: {}",
+            name
+        ))),
+    }
+}
+
+/// Rebuilds a Pike `Organization` from its row in the database
+pub fn organization_from_row(
+    row: models::Organization,
+) -> Result<Organization, RestApiResponseError> {
+    let metadata: Vec<KeyValueEntrySlice> = serde_json::from_str(&row.metadata).map_err(|err| {
+        RestApiResponseError::DatabaseError(format!(
+            "Unable to parse organization metadata: {}",
+            err
+        ))
+    })?;
+    let metadata = metadata
+        .into_iter()
+        .map(|entry| {
+            KeyValueEntryBuilder::new()
+                .with_key(entry.key)
+                .with_value(entry.value)
+                .build()
+        })
+        .collect::<Result<Vec<KeyValueEntry>, _>>()
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+    OrganizationBuilder::new()
+        .with_org_id(row.org_id)
+        .with_name(row.name)
+        .with_address(row.address)
+        .with_metadata(metadata)
+        .with_status(parse_status(&row.status)?)
+        .build()
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))
+}
+
+struct ListOrganizations;
+
+impl Message for ListOrganizations {
+    type Result = Result<Vec<OrganizationSlice>, RestApiResponseError>;
+}
+
+impl Handler<ListOrganizations> for DbExecutor {
+    type Result = Result<Vec<OrganizationSlice>, RestApiResponseError>;
+
+    fn handle(&mut self, _msg: ListOrganizations, _: &mut SyncContext<Self>) -> Self::Result {
+        db::list_organizations(&self.get_connection()?)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+            .into_iter()
+            .map(|row| {
+                organization_from_row(row)
+                    .map(|organization| OrganizationSlice::from_organization(&organization))
+            })
+            .collect()
+    }
+}
+
+pub fn list_organizations(
+    state: State<AppState>,
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(ListOrganizations)
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(organizations) => Ok(HttpResponse::Ok().json(organizations)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+struct FetchOrganization {
+    org_id: String,
+}
+
+impl Message for FetchOrganization {
+    type Result = Result<OrganizationSlice, RestApiResponseError>;
+}
+
+impl Handler<FetchOrganization> for DbExecutor {
+    type Result = Result<OrganizationSlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchOrganization, _: &mut SyncContext<Self>) -> Self::Result {
+        match db::fetch_organization(&self.get_connection()?, &msg.org_id)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+        {
+            Some(row) => Ok(OrganizationSlice::from_organization(
+                &organization_from_row(row)?,
+            )),
+            None => Err(RestApiResponseError::NotFoundError(format!(
+                "Could not find organization with id: {}",
+                msg.org_id
+            ))),
+        }
+    }
+}
+
+pub fn fetch_organization(
+    (state, org_id): (State<AppState>, Path<String>),
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(FetchOrganization {
+                org_id: org_id.into_inner(),
+            })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(organization) => Ok(HttpResponse::Ok().json(organization)),
+                Err(err) => Err(err),
+            }),
+    )
+}
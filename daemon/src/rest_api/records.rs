@@ -0,0 +1,386 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use actix::{Handler, Message, SyncContext};
+use actix_web::{HttpRequest, HttpResponse, Path, State};
+use futures::future;
+use futures::future::Future;
+use serde::{Deserialize, Serialize};
+
+use crate::database::{helpers as db, models, Connection};
+use crate::rest_api::{db_executor::DbExecutor, error::RestApiResponseError, AppState};
+
+/// The JSON representation of a Track and Trace `Record`. The current owner and custodian are
+/// the last entries of `owner_updates` and `custodian_updates`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecordSlice {
+    pub record_id: String,
+    pub schema: String,
+    pub finalized: bool,
+    pub owner: Option<String>,
+    pub custodian: Option<String>,
+    pub owner_updates: Vec<AssociatedAgentSlice>,
+    pub custodian_updates: Vec<AssociatedAgentSlice>,
+    pub properties: Vec<PropertySlice>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssociatedAgentSlice {
+    pub agent_id: String,
+    pub timestamp: u64,
+    pub org_id: String,
+}
+
+/// The JSON representation of a Track and Trace `Property`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertySlice {
+    pub name: String,
+    pub record_id: String,
+    pub data_type: String,
+    pub reporters: Vec<ReporterSlice>,
+    pub current_page: u32,
+    pub wrapped: bool,
+    pub fixed: bool,
+    pub number_exponent: i32,
+    pub enum_options: Vec<String>,
+    pub unit: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReporterSlice {
+    pub public_key: String,
+    pub authorized: bool,
+    pub index: u32,
+}
+
+/// A `Property` along with the values reported for it, oldest first
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyHistorySlice {
+    #[serde(flatten)]
+    pub property: PropertySlice,
+    pub updates: Vec<ReportedValueSlice>,
+}
+
+/// A single reported value; `reporter` is the public key of the agent that reported it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportedValueSlice {
+    pub reporter: Option<String>,
+    pub timestamp: u64,
+    pub value: serde_json::Value,
+}
+
+impl PropertySlice {
+    pub fn from_row(row: &models::Property) -> Result<Self, RestApiResponseError> {
+        let reporters: Vec<ReporterSlice> =
+            serde_json::from_str(&row.reporters).map_err(|err| {
+                RestApiResponseError::DatabaseError(format!("Unable to parse reporters: {}", err))
+            })?;
+        let enum_options: Vec<String> = serde_json::from_str(&row.enum_options).map_err(|err| {
+            RestApiResponseError::DatabaseError(format!("Unable to parse enum options: {}", err))
+        })?;
+
+        Ok(Self {
+            name: row.name.clone(),
+            record_id: row.record_id.clone(),
+            data_type: row.data_type.clone(),
+            reporters,
+            current_page: row.current_page as u32,
+            wrapped: row.wrapped,
+            fixed: row.fixed,
+            number_exponent: row.number_exponent as i32,
+            enum_options,
+            unit: row.unit.clone(),
+        })
+    }
+}
+
+impl ReportedValueSlice {
+    /// Builds the reported value from its row, reading the value field that matches the data
+    /// type of `property`
+    pub fn from_row(
+        property: &PropertySlice,
+        row: &models::ReportedValue,
+    ) -> Result<Self, RestApiResponseError> {
+        let reporter = property
+            .reporters
+            .iter()
+            .find(|reporter| reporter.index as i32 == row.reporter_index)
+            .map(|reporter| reporter.public_key.clone());
+
+        let value = match property.data_type.as_str() {
+            "BYTES" => row.bytes_value.clone().into(),
+            "BOOLEAN" => row.boolean_value.into(),
+            "NUMBER" => row.number_value.into(),
+            "STRING" => row.string_value.clone().into(),
+            "ENUM" => match property.enum_options.get(row.enum_value as usize) {
+                Some(option) => option.clone().into(),
+                None => row.enum_value.into(),
+            },
+            "STRUCT" => serde_json::from_str(&row.struct_values).map_err(|err| {
+                RestApiResponseError::DatabaseError(format!(
+                    "Unable to parse struct values: {}",
+                    err
+                ))
+            })?,
+            "LOCATION" => serde_json::json!({
+                "latitude": row.latitude,
+                "longitude": row.longitude,
+            }),
+            _ => serde_json::Value::Null,
+        };
+
+        Ok(Self {
+            reporter,
+            timestamp: row.timestamp as u64,
+            value,
+        })
+    }
+}
+
+/// Loads the given record rows with their owner and custodian history and their properties
+fn load_records(
+    conn: &Connection,
+    rows: Vec<models::Record>,
+) -> Result<Vec<RecordSlice>, RestApiResponseError> {
+    let record_ids = rows
+        .iter()
+        .map(|row| row.record_id.clone())
+        .collect::<Vec<_>>();
+    let associated_agents = db::list_associated_agents(conn, &record_ids)
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+    let properties = db::list_properties(conn, &record_ids)
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+    let mut agents_by_record: HashMap<&str, Vec<&models::AssociatedAgent>> = HashMap::new();
+    for associated_agent in &associated_agents {
+        agents_by_record
+            .entry(&associated_agent.record_id)
+            .or_default()
+            .push(associated_agent);
+    }
+    let mut properties_by_record: HashMap<&str, Vec<PropertySlice>> = HashMap::new();
+    for property in &properties {
+        properties_by_record
+            .entry(&property.record_id)
+            .or_default()
+            .push(PropertySlice::from_row(property)?);
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let agents = agents_by_record
+                .get(row.record_id.as_str())
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let updates = |role: &str| {
+                agents
+                    .iter()
+                    .filter(|agent| agent.role == role)
+                    .map(|agent| AssociatedAgentSlice {
+                        agent_id: agent.agent_id.clone(),
+                        timestamp: agent.timestamp as u64,
+                        org_id: agent.org_id.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let owner_updates = updates("OWNER");
+            let custodian_updates = updates("CUSTODIAN");
+
+            RecordSlice {
+                owner: owner_updates.last().map(|owner| owner.agent_id.clone()),
+                custodian: custodian_updates
+                    .last()
+                    .map(|custodian| custodian.agent_id.clone()),
+                properties: properties_by_record
+                    .remove(row.record_id.as_str())
+                    .unwrap_or_default(),
+                record_id: row.record_id,
+                schema: row.schema,
+                finalized: row.finalized,
+                owner_updates,
+                custodian_updates,
+            }
+        })
+        .collect())
+}
+
+struct ListRecords;
+
+impl Message for ListRecords {
+    type Result = Result<Vec<RecordSlice>, RestApiResponseError>;
+}
+
+impl Handler<ListRecords> for DbExecutor {
+    type Result = Result<Vec<RecordSlice>, RestApiResponseError>;
+
+    fn handle(&mut self, _msg: ListRecords, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.get_connection()?;
+        let rows = db::list_records(&conn)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+        load_records(&conn, rows)
+    }
+}
+
+pub fn list_records(
+    state: State<AppState>,
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(ListRecords)
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(records) => Ok(HttpResponse::Ok().json(records)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+struct FetchRecord {
+    record_id: String,
+}
+
+impl Message for FetchRecord {
+    type Result = Result<RecordSlice, RestApiResponseError>;
+}
+
+impl Handler<FetchRecord> for DbExecutor {
+    type Result = Result<RecordSlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchRecord, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.get_connection()?;
+        let row = db::fetch_record(&conn, &msg.record_id)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+            .ok_or_else(|| {
+                RestApiResponseError::NotFoundError(format!(
+                    "Could not find record with id: {}",
+                    msg.record_id
+                ))
+            })?;
+
+        load_records(&conn, vec![row])?
+            .pop()
+            .ok_or_else(|| RestApiResponseError::DatabaseError("Unable to load record".into()))
+    }
+}
+
+pub fn fetch_record(
+    (state, record_id): (State<AppState>, Path<String>),
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(FetchRecord {
+                record_id: record_id.into_inner(),
+            })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(record) => Ok(HttpResponse::Ok().json(record)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+struct FetchRecordProperty {
+    record_id: String,
+    property_name: String,
+    start: Option<i64>,
+    end: Option<i64>,
+}
+
+impl Message for FetchRecordProperty {
+    type Result = Result<PropertyHistorySlice, RestApiResponseError>;
+}
+
+impl Handler<FetchRecordProperty> for DbExecutor {
+    type Result = Result<PropertyHistorySlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchRecordProperty, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.get_connection()?;
+        let row = db::fetch_property(&conn, &msg.record_id, &msg.property_name)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+            .ok_or_else(|| {
+                RestApiResponseError::NotFoundError(format!(
+                    "Could not find property {} of record {}",
+                    msg.property_name, msg.record_id
+                ))
+            })?;
+        let property = PropertySlice::from_row(&row)?;
+
+        let updates = db::list_reported_values(
+            &conn,
+            &msg.record_id,
+            &msg.property_name,
+            msg.start,
+            msg.end,
+        )
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+        .iter()
+        .map(|row| ReportedValueSlice::from_row(&property, row))
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PropertyHistorySlice { property, updates })
+    }
+}
+
+/// Returns a record's property with the values reported for it. The optional `start` and `end`
+/// query parameters limit the values to those reported within that range of Unix timestamps.
+pub fn fetch_record_property(
+    (req, state, path): (
+        HttpRequest<AppState>,
+        State<AppState>,
+        Path<(String, String)>,
+    ),
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    let query = req.query();
+    let (start, end) = match (
+        parse_timestamp(query.get("start")),
+        parse_timestamp(query.get("end")),
+    ) {
+        (Ok(start), Ok(end)) => (start, end),
+        (Err(err), _) | (_, Err(err)) => return Box::new(future::err(err)),
+    };
+
+    let (record_id, property_name) = path.into_inner();
+
+    Box::new(
+        state
+            .database_connection
+            .send(FetchRecordProperty {
+                record_id,
+                property_name,
+                start,
+                end,
+            })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(property) => Ok(HttpResponse::Ok().json(property)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+fn parse_timestamp(value: Option<&String>) -> Result<Option<i64>, RestApiResponseError> {
+    value
+        .map(|value| {
+            value.parse::<i64>().map_err(|_| {
+                RestApiResponseError::BadRequest(format!("Invalid timestamp: {}", value))
+            })
+        })
+        .transpose()
+}
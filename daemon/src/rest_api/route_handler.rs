@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::event::health::EventFeedStatus;
 use crate::rest_api::{error::RestApiResponseError, AppState};
 
 use actix::{Actor, Context, Handler, Message};
@@ -21,7 +22,9 @@ use futures::future::Future;
 use protobuf;
 use sawtooth_sdk::messages::batch::{Batch, BatchList};
 use sawtooth_sdk::messages::client_batch_submit::{
-    ClientBatchSubmitRequest, ClientBatchSubmitResponse, ClientBatchSubmitResponse_Status,
+    ClientBatchStatus, ClientBatchStatusRequest, ClientBatchStatusResponse,
+    ClientBatchStatusResponse_Status, ClientBatchSubmitRequest, ClientBatchSubmitResponse,
+    ClientBatchSubmitResponse_Status,
 };
 use sawtooth_sdk::messages::validator::Message_MessageType;
 use sawtooth_sdk::messaging::stream::MessageSender;
@@ -30,10 +33,9 @@ use std::time::Duration;
 use url::Url;
 use uuid::Uuid;
 
-const DEFAULT_TIME_OUT: u64 = 30;
-
 pub struct SawtoothMessageSender {
     sender: Box<dyn MessageSender>,
+    timeout: Duration,
 }
 
 impl Actor for SawtoothMessageSender {
@@ -41,8 +43,8 @@ impl Actor for SawtoothMessageSender {
 }
 
 impl SawtoothMessageSender {
-    pub fn new(sender: Box<dyn MessageSender>) -> SawtoothMessageSender {
-        SawtoothMessageSender { sender }
+    pub fn new(sender: Box<dyn MessageSender>, timeout: Duration) -> SawtoothMessageSender {
+        SawtoothMessageSender { sender, timeout }
     }
 }
 
@@ -90,7 +92,7 @@ impl Handler<SubmitBatches> for SawtoothMessageSender {
             })?;
         let response_status: ClientBatchSubmitResponse = protobuf::parse_from_bytes(
             response_future
-                .get_timeout(Duration::new(DEFAULT_TIME_OUT, 0))
+                .get_timeout(self.timeout)
                 .map_err(|err| RestApiResponseError::RequestHandlerError(err.to_string()))?
                 .get_content(),
         )
@@ -158,10 +160,162 @@ pub fn submit_batches(
     )
 }
 
+struct BatchStatuses {
+    batch_ids: Vec<String>,
+    wait: Option<u32>,
+}
+
+impl Message for BatchStatuses {
+    type Result = Result<Vec<BatchStatus>, RestApiResponseError>;
+}
+
+#[derive(Serialize)]
+pub struct BatchStatus {
+    pub id: String,
+    pub status: String,
+    pub invalid_transactions: Vec<InvalidTransaction>,
+}
+
+#[derive(Serialize)]
+pub struct InvalidTransaction {
+    pub id: String,
+    pub message: String,
+    pub extended_data: String,
+}
+
+#[derive(Serialize)]
+pub struct BatchStatusResponse {
+    pub data: Vec<BatchStatus>,
+    pub link: String,
+}
+
+impl BatchStatus {
+    fn from_proto(proto: &ClientBatchStatus) -> BatchStatus {
+        BatchStatus {
+            id: proto.get_batch_id().to_string(),
+            status: format!("{:?}", proto.get_status()),
+            invalid_transactions: proto
+                .get_invalid_transactions()
+                .iter()
+                .map(|txn| InvalidTransaction {
+                    id: txn.get_transaction_id().to_string(),
+                    message: txn.get_message().to_string(),
+                    extended_data: base64::encode(txn.get_extended_data()),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Handler<BatchStatuses> for SawtoothMessageSender {
+    type Result = Result<Vec<BatchStatus>, RestApiResponseError>;
+
+    fn handle(&mut self, msg: BatchStatuses, _: &mut Context<Self>) -> Self::Result {
+        let mut batch_status_request = ClientBatchStatusRequest::new();
+        batch_status_request.set_batch_ids(protobuf::RepeatedField::from_vec(msg.batch_ids));
+        if let Some(wait) = msg.wait {
+            batch_status_request.set_wait(true);
+            batch_status_request.set_timeout(wait);
+        }
+
+        let content = protobuf::Message::write_to_bytes(&batch_status_request).map_err(|err| {
+            RestApiResponseError::RequestHandlerError(format!(
+                "Failed to serialize batch status request. {}",
+                err.to_string()
+            ))
+        })?;
+        let correlation_id = Uuid::new_v4().to_string();
+        let mut response_future = self
+            .sender
+            .send(
+                Message_MessageType::CLIENT_BATCH_STATUS_REQUEST,
+                &correlation_id,
+                &content,
+            )
+            .map_err(|err| {
+                RestApiResponseError::SawtoothConnectionError(format!(
+                    "Failed to send message to validator. {}",
+                    err.to_string()
+                ))
+            })?;
+        let response: ClientBatchStatusResponse = protobuf::parse_from_bytes(
+            response_future
+                .get_timeout(self.timeout)
+                .map_err(|err| RestApiResponseError::RequestHandlerError(err.to_string()))?
+                .get_content(),
+        )
+        .map_err(|err| {
+            RestApiResponseError::RequestHandlerError(format!(
+                "Failed to parse validator response from bytes. {}",
+                err.to_string()
+            ))
+        })?;
+
+        match response.get_status() {
+            ClientBatchStatusResponse_Status::OK => Ok(response
+                .get_batch_statuses()
+                .iter()
+                .map(BatchStatus::from_proto)
+                .collect()),
+            ClientBatchStatusResponse_Status::INVALID_ID => Err(RestApiResponseError::BadRequest(
+                "Blockchain items are identified by 128 character hex-strings. A submitted \
+                 batch id was invalid"
+                    .to_string(),
+            )),
+            status => Err(RestApiResponseError::SawtoothValidatorResponseError(
+                format!("Validator responded with error {:?}", status),
+            )),
+        }
+    }
+}
+
 pub fn get_batch_statuses(
-    (_req, _state): (HttpRequest<AppState>, State<AppState>),
+    (req, state): (HttpRequest<AppState>, State<AppState>),
 ) -> Box<Future<Item = HttpResponse, Error = RestApiResponseError>> {
-    unimplemented!()
+    let query = req.query();
+
+    let batch_ids = match query.get("id") {
+        Some(ids) => ids
+            .split(',')
+            .filter(|id| !id.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+    if batch_ids.is_empty() {
+        return Box::new(future::err(RestApiResponseError::BadRequest(
+            "Request for statuses missing id query.".to_string(),
+        )));
+    }
+
+    // A wait value that is present but not a number (other than "false") waits for just under
+    // the time the request to the validator is allowed to take
+    let max_wait = state.request_timeout.saturating_sub(1) as u32;
+    let wait = match query.get("wait").map(String::as_str) {
+        None | Some("false") => None,
+        Some(wait) => match wait.parse::<u32>() {
+            Ok(wait) => Some(wait.min(max_wait)),
+            Err(_) => Some(max_wait),
+        },
+    };
+
+    let mut link = match req.url_for_static("batch_statuses") {
+        Ok(url) => url,
+        Err(err) => return Box::new(future::err(err.into())),
+    };
+    link.set_query(Some(req.query_string()));
+    let link = link.to_string();
+
+    Box::new(
+        state
+            .sawtooth_connection
+            .send(BatchStatuses { batch_ids, wait })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(data) => Ok(HttpResponse::Ok().json(BatchStatusResponse { data, link })),
+                Err(err) => Err(err),
+            }),
+    )
 }
 
 fn process_validator_response(
@@ -179,3 +333,14 @@ fn process_validator_response(
         )),
     }
 }
+
+/// Reports the state of the event feed from the validator. Responds with 503 Service
+/// Unavailable while the feed is not connected, since the database may be falling behind.
+pub fn get_health(state: State<AppState>) -> HttpResponse {
+    let feed_state = state.event_feed_health.state();
+    if feed_state.status == EventFeedStatus::Connected {
+        HttpResponse::Ok().json(feed_state)
+    } else {
+        HttpResponse::ServiceUnavailable().json(feed_state)
+    }
+}
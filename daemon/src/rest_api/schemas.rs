@@ -0,0 +1,269 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use actix::{Handler, Message, SyncContext};
+use actix_web::{HttpResponse, Path, State};
+use futures::future::Future;
+use grid_sdk::protocol::schema::state::{
+    DataType, PropertyDefinition, PropertyDefinitionBuilder, Schema, SchemaBuilder,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::database::{helpers as db, models, Connection};
+use crate::rest_api::{
+    db_executor::DbExecutor,
+    error::RestApiResponseError,
+    organizations::{organization_from_row, OrganizationSlice},
+    AppState,
+};
+
+/// The JSON representation of a Grid `Schema`, along with the organization that owns it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SchemaSlice {
+    pub name: String,
+    pub description: String,
+    pub owner: String,
+    pub organization: Option<OrganizationSlice>,
+    pub properties: Vec<PropertyDefinitionSlice>,
+}
+
+/// The JSON representation of a `PropertyDefinition`; the definitions of a STRUCT property are
+/// nested in `struct_properties`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyDefinitionSlice {
+    pub name: String,
+    pub data_type: String,
+    pub required: bool,
+    pub description: String,
+    pub number_exponent: i32,
+    pub enum_options: Vec<String>,
+    pub struct_properties: Vec<PropertyDefinitionSlice>,
+}
+
+impl SchemaSlice {
+    pub fn from_schema(schema: &Schema, organization: Option<OrganizationSlice>) -> Self {
+        Self {
+            name: schema.name().to_string(),
+            description: schema.description().to_string(),
+            owner: schema.owner().to_string(),
+            organization,
+            properties: schema
+                .properties()
+                .iter()
+                .map(PropertyDefinitionSlice::from_definition)
+                .collect(),
+        }
+    }
+}
+
+impl PropertyDefinitionSlice {
+    pub fn from_definition(definition: &PropertyDefinition) -> Self {
+        Self {
+            name: definition.name().to_string(),
+            data_type: models::data_type_name(definition.data_type()).to_string(),
+            required: *definition.required(),
+            description: definition.description().to_string(),
+            number_exponent: *definition.number_exponent(),
+            enum_options: definition.enum_options().to_vec(),
+            struct_properties: definition
+                .struct_properties()
+                .iter()
+                .map(PropertyDefinitionSlice::from_definition)
+                .collect(),
+        }
+    }
+}
+
+fn parse_data_type(name: &str) -> Result<DataType, RestApiResponseError> {
+    match name {
+        "BYTES" => Ok(DataType::Bytes),
+        "BOOLEAN" => Ok(DataType::Boolean),
+        "NUMBER" => Ok(DataType::Number),
+        "STRING" => Ok(DataType::String),
+        "ENUM" => Ok(DataType::Enum),
+        "STRUCT" => Ok(DataType::Struct),
+        "LOCATION" => Ok(DataType::Location),
+        _ => Err(RestApiResponseError::DatabaseError(format!(
+            "Unknown data type: {}",
+            name
+        ))),
+    }
+}
+
+/// Rebuilds a Grid `Schema` from its row and the rows of its property definitions
+fn schema_from_rows(
+    row: models::GridSchema,
+    definitions: &[&models::GridPropertyDefinition],
+) -> Result<Schema, RestApiResponseError> {
+    SchemaBuilder::new()
+        .with_name(row.name)
+        .with_description(row.description)
+        .with_owner(row.owner)
+        .with_properties(definitions_from_rows(definitions, None)?)
+        .build()
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))
+}
+
+/// Rebuilds the property definitions whose parent has the dotted path `parent_name`, recursing
+/// into the definitions of STRUCT properties
+fn definitions_from_rows(
+    rows: &[&models::GridPropertyDefinition],
+    parent_name: Option<&str>,
+) -> Result<Vec<PropertyDefinition>, RestApiResponseError> {
+    rows.iter()
+        .filter(|row| row.parent_name.as_deref() == parent_name)
+        .map(|row| {
+            let path = match parent_name {
+                Some(parent_name) => format!("{}.{}", parent_name, row.name),
+                None => row.name.clone(),
+            };
+            let enum_options: Vec<String> =
+                serde_json::from_str(&row.enum_options).map_err(|err| {
+                    RestApiResponseError::DatabaseError(format!(
+                        "Unable to parse enum options: {}",
+                        err
+                    ))
+                })?;
+
+            PropertyDefinitionBuilder::new()
+                .with_name(row.name.clone())
+                .with_data_type(parse_data_type(&row.data_type)?)
+                .with_required(row.required)
+                .with_description(row.description.clone())
+                .with_number_exponent(row.number_exponent as i32)
+                .with_enum_options(enum_options)
+                .with_struct_properties(definitions_from_rows(rows, Some(&path))?)
+                .build()
+                .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))
+        })
+        .collect()
+}
+
+/// Loads the given schema rows with their property definitions and owning organizations
+fn load_schemas(
+    conn: &Connection,
+    rows: Vec<models::GridSchema>,
+) -> Result<Vec<SchemaSlice>, RestApiResponseError> {
+    let names = rows.iter().map(|row| row.name.clone()).collect::<Vec<_>>();
+    let definitions = db::list_property_definitions(conn, &names)
+        .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+    let mut definitions_by_schema: HashMap<&str, Vec<&models::GridPropertyDefinition>> =
+        HashMap::new();
+    for definition in &definitions {
+        definitions_by_schema
+            .entry(&definition.schema_name)
+            .or_default()
+            .push(definition);
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            let organization = db::fetch_organization(conn, &row.owner)
+                .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+                .map(|organization| {
+                    organization_from_row(organization)
+                        .map(|organization| OrganizationSlice::from_organization(&organization))
+                })
+                .transpose()?;
+            let definitions = definitions_by_schema
+                .get(row.name.as_str())
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let schema = schema_from_rows(row, definitions)?;
+
+            Ok(SchemaSlice::from_schema(&schema, organization))
+        })
+        .collect()
+}
+
+struct ListSchemas;
+
+impl Message for ListSchemas {
+    type Result = Result<Vec<SchemaSlice>, RestApiResponseError>;
+}
+
+impl Handler<ListSchemas> for DbExecutor {
+    type Result = Result<Vec<SchemaSlice>, RestApiResponseError>;
+
+    fn handle(&mut self, _msg: ListSchemas, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.get_connection()?;
+        let rows = db::list_schemas(&conn)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?;
+
+        load_schemas(&conn, rows)
+    }
+}
+
+pub fn list_schemas(
+    state: State<AppState>,
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(ListSchemas)
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(schemas) => Ok(HttpResponse::Ok().json(schemas)),
+                Err(err) => Err(err),
+            }),
+    )
+}
+
+struct FetchSchema {
+    name: String,
+}
+
+impl Message for FetchSchema {
+    type Result = Result<SchemaSlice, RestApiResponseError>;
+}
+
+impl Handler<FetchSchema> for DbExecutor {
+    type Result = Result<SchemaSlice, RestApiResponseError>;
+
+    fn handle(&mut self, msg: FetchSchema, _: &mut SyncContext<Self>) -> Self::Result {
+        let conn = self.get_connection()?;
+        let row = db::fetch_schema(&conn, &msg.name)
+            .map_err(|err| RestApiResponseError::DatabaseError(err.to_string()))?
+            .ok_or_else(|| {
+                RestApiResponseError::NotFoundError(format!(
+                    "Could not find schema with name: {}",
+                    msg.name
+                ))
+            })?;
+
+        load_schemas(&conn, vec![row])?
+            .pop()
+            .ok_or_else(|| RestApiResponseError::DatabaseError("Unable to load schema".into()))
+    }
+}
+
+pub fn fetch_schema(
+    (state, name): (State<AppState>, Path<String>),
+) -> Box<dyn Future<Item = HttpResponse, Error = RestApiResponseError>> {
+    Box::new(
+        state
+            .database_connection
+            .send(FetchSchema {
+                name: name.into_inner(),
+            })
+            .from_err()
+            .and_then(move |res| match res {
+                Ok(schema) => Ok(HttpResponse::Ok().json(schema)),
+                Err(err) => Err(err),
+            }),
+    )
+}
@@ -12,11 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-extern crate addresser;
 #[macro_use]
 extern crate clap;
 extern crate crypto;
 extern crate futures;
+extern crate grid_sdk;
 extern crate hyper;
 extern crate protobuf;
 extern crate sawtooth_sdk;
@@ -30,7 +30,7 @@ use sawtooth_sdk::messages::transaction::Transaction;
 use sawtooth_sdk::messages::transaction::TransactionHeader;
 use sawtooth_sdk::signing::Signer;
 
-use addresser::{resource_to_byte, Resource};
+use grid_sdk::addressing::{compute_pike_agent_address, compute_pike_organization_address};
 
 use error::CliError;
 use protos::payload;
@@ -63,38 +63,6 @@ fn bytes_to_hex_str(b: &[u8]) -> String {
         .join("")
 }
 
-/// Returns a state address for a given agent name
-///
-/// # Arguments
-///
-/// * `name` - the agent's name
-fn compute_agent_address(name: &str) -> String {
-    let hash: &mut [u8] = &mut [0; 64];
-
-    let mut sha = Sha512::new();
-    sha.input(name.as_bytes());
-    sha.result(hash);
-
-    String::from(PIKE_NAMESPACE) + &resource_to_byte(Resource::AGENT)
-        + &bytes_to_hex_str(hash)[..62]
-}
-
-/// Returns a state address for a given organization id
-///
-/// # Arguments
-///
-/// * `id` - the organization's id
-fn compute_org_address(id: &str) -> String {
-    let hash: &mut [u8] = &mut [0; 64];
-
-    let mut sha = Sha512::new();
-    sha.input(id.as_bytes());
-    sha.result(hash);
-
-    String::from(PIKE_NAMESPACE) + &resource_to_byte(Resource::ORG)
-        + &bytes_to_hex_str(hash)[..62]
-}
-
 /// Returns a Transaction for the given Payload and Signer
 ///
 /// # Arguments
@@ -129,32 +97,32 @@ pub fn create_transaction(
             let org_id = payload.get_create_agent().get_org_id();
             let agent_public_key = payload.get_create_agent().get_public_key();
             protobuf::RepeatedField::from_vec(vec![
-                compute_agent_address(org_id),
-                compute_agent_address(agent_public_key),
-                compute_agent_address(public_key),
+                compute_pike_agent_address(org_id),
+                compute_pike_agent_address(agent_public_key),
+                compute_pike_agent_address(public_key),
             ])
         }
         Action::UPDATE_AGENT => {
             let org_id = payload.get_update_agent().get_org_id();
             let agent_public_key = payload.get_update_agent().get_public_key();
             protobuf::RepeatedField::from_vec(vec![
-                compute_agent_address(org_id),
-                compute_agent_address(agent_public_key),
-                compute_agent_address(public_key),
+                compute_pike_agent_address(org_id),
+                compute_pike_agent_address(agent_public_key),
+                compute_pike_agent_address(public_key),
             ])
         }
         Action::CREATE_ORGANIZATION => {
             let id = payload.get_create_organization().get_id();
             protobuf::RepeatedField::from_vec(vec![
-                compute_org_address(id),
-                compute_agent_address(public_key),
+                compute_pike_organization_address(id),
+                compute_pike_agent_address(public_key),
             ])
         }
         Action::UPDATE_ORGANIZATION => {
             let id = payload.get_update_organization().get_id();
             protobuf::RepeatedField::from_vec(vec![
-                compute_org_address(id),
-                compute_agent_address(public_key),
+                compute_pike_organization_address(id),
+                compute_pike_agent_address(public_key),
             ])
         }
         _ => protobuf::RepeatedField::from_vec(vec![String::from(PIKE_NAMESPACE)]),
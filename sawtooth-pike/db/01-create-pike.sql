@@ -23,5 +23,12 @@ CREATE TABLE agents (
 CREATE TABLE organizations (
     id VARCHAR(256) PRIMARY KEY NOT NULL,
     name VARCHAR(256) NOT NULL,
-    address VARCHAR(256) NOT NULL
+    address VARCHAR(256) NOT NULL,
+    metadata JSON [] NOT NULL,
+    status VARCHAR(16) NOT NULL
+);
+
+CREATE TABLE blocks (
+    id SERIAL PRIMARY KEY,
+    block_id VARCHAR(128) UNIQUE NOT NULL
 );
@@ -40,6 +40,12 @@ pub fn update_agent(conn: &PgConnection, public_key: &str, agent: NewAgent) -> Q
         .get_result::<Agent>(conn)
 }
 
+pub fn delete_agent(conn: &PgConnection, public_key: &str) -> QueryResult<usize> {
+    diesel::delete(agents::table)
+        .filter(dsl::public_key.eq(public_key))
+        .execute(conn)
+}
+
 pub fn get_agent(conn: &PgConnection, public_key: &str) -> QueryResult<Agent> {
     agents::table
         .select(agents::all_columns)
@@ -0,0 +1,48 @@
+// Copyright 2018 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schema::{agents, blocks, organizations};
+use schema::blocks::dsl;
+use models::NewBlock;
+
+use diesel;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use diesel::QueryResult;
+
+/// Records that every state change up to and including the given block has been exported
+pub fn insert_block(conn: &PgConnection, block_id: &str) -> QueryResult<usize> {
+    diesel::insert_into(blocks::table)
+        .values(&NewBlock { block_id })
+        .on_conflict_do_nothing()
+        .execute(conn)
+}
+
+/// Returns the ids of the most recently exported blocks, newest first
+pub fn get_last_block_ids(conn: &PgConnection, limit: i64) -> QueryResult<Vec<String>> {
+    blocks::table
+        .select(dsl::block_id)
+        .order(dsl::id.desc())
+        .limit(limit)
+        .load(conn)
+}
+
+/// Removes all exported agents, organizations and blocks. Callers rebuilding the tables should
+/// run this in the same transaction as the rebuild, so that a failure leaves the old rows.
+pub fn truncate_tables(conn: &PgConnection) -> QueryResult<()> {
+    diesel::delete(agents::table).execute(conn)?;
+    diesel::delete(organizations::table).execute(conn)?;
+    diesel::delete(blocks::table).execute(conn)?;
+    Ok(())
+}
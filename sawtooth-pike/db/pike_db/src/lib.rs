@@ -26,15 +26,18 @@ use diesel::result::Error;
 mod schema;
 mod agents_helper;
 mod orgs_helper;
+mod blocks_helper;
 
 pub mod pools;
 pub mod models;
 
 pub use orgs_helper::*;
 pub use agents_helper::*;
+pub use blocks_helper::*;
 
 pub use Error::NotFound;
 pub use diesel::pg::PgConnection;
+pub use diesel::Connection;
 pub use r2d2_diesel::ConnectionManager;
 pub use r2d2::PooledConnection;
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use schema::agents;
+use schema::blocks;
 use schema::organizations;
 use serde_json;
 
@@ -45,7 +46,9 @@ pub struct NewAgent<'a> {
 pub struct Organization {
     pub id: String,
     pub name: String,
-    pub address: String
+    pub address: String,
+    pub metadata: Vec<serde_json::Value>,
+    pub status: String
 }
 
 #[derive(Insertable)]
@@ -54,7 +57,23 @@ pub struct Organization {
 pub struct NewOrganization<'a> {
     pub id: &'a str,
     pub name: &'a str,
-    pub address: &'a str
+    pub address: &'a str,
+    pub metadata: Vec<serde_json::Value>,
+    pub status: &'a str
+}
+
+#[derive(Queryable)]
+#[derive(Debug)]
+pub struct Block {
+    pub id: i32,
+    pub block_id: String
+}
+
+#[derive(Insertable)]
+#[table_name = "blocks"]
+#[derive(Debug)]
+pub struct NewBlock<'a> {
+    pub block_id: &'a str
 }
 
 #[derive(Queryable)]
@@ -33,10 +33,18 @@ pub fn update_organization(conn: &PgConnection, id: &str, org: NewOrganization)
         .set((
             dsl::name.eq(org.name),
             dsl::address.eq(org.address),
+            dsl::metadata.eq(org.metadata),
+            dsl::status.eq(org.status),
         ))
         .get_result::<Organization>(conn)
 }
 
+pub fn delete_organization(conn: &PgConnection, id: &str) -> QueryResult<usize> {
+    diesel::delete(organizations::table)
+        .filter(dsl::id.eq(id))
+        .execute(conn)
+}
+
 pub fn get_org(conn: &PgConnection, id: &str) -> QueryResult<Organization> {
     organizations::table
         .select(organizations::all_columns)
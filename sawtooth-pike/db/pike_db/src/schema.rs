@@ -27,5 +27,14 @@ table! {
         id -> Varchar,
         name -> Varchar,
         address -> Varchar,
+        metadata -> Array<Json>,
+        status -> Varchar,
+    }
+}
+
+table! {
+    blocks (id) {
+        id -> Int4,
+        block_id -> Varchar,
     }
 }
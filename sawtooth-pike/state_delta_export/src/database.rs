@@ -12,44 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use protobuf;
-
 use sawtooth_sdk::messages::transaction_receipt::StateChange;
 use sawtooth_sdk::messages::transaction_receipt::StateChange_Type;
 
+use serde_json;
+
 use pike_db as db;
-use pike_db::{PgConnection, NotFound, QueryError};
+use pike_db::{Connection, PgConnection, NotFound, QueryError};
 use pike_db::models::{NewAgent, NewOrganization};
 
 use addresser::{Resource, ResourceError, byte_to_resource};
 
-use protos::state::{
+use grid_sdk::addressing::{compute_pike_agent_address, compute_pike_organization_address};
+use grid_sdk::protocol::pike::state::{
     Agent,
+    KeyValueEntry,
     Organization,
+    OrganizationStatus,
     AgentList,
     OrganizationList
 };
+use grid_sdk::protos::{FromBytes, ProtoConversionError};
+
+/// Applies the state changes of a committed block and records the block, in a single
+/// transaction. If any change fails, nothing is stored and the block is not recorded, so that a
+/// restarted export resumes from before it.
+pub fn apply_block(
+    conn: &PgConnection,
+    block_id: &str,
+    state_changes: &[StateChange]
+) -> Result<(), StateChangeError> {
+    conn.transaction::<_, StateChangeError, _>(|| {
+        for state_change in state_changes {
+            info!("Applying state change {:?}", state_change);
+            apply_state_change(conn, state_change)?;
+        }
+
+        db::insert_block(conn, block_id)?;
+
+        Ok(())
+    })
+}
 
 pub fn apply_state_change(conn: &PgConnection, state_change: &StateChange) -> Result<(), StateChangeError> {
     match state_change.field_type{
         StateChange_Type::SET => set(conn, &state_change.address, &state_change.value),
+        StateChange_Type::DELETE => delete(conn, &state_change.address),
         _ => Err(
             StateChangeError::UnsupportedTypeError(
                 format!("unsuppoted type {:?}", state_change.field_type)))
     }
 }
 
-fn set(conn: &PgConnection, address: &str, value: &[u8]) -> Result<(), StateChangeError> {
+pub fn set(conn: &PgConnection, address: &str, value: &[u8]) -> Result<(), StateChangeError> {
     let resource_byte = &address[6..8];
 
     let results: Vec<StateChangeError> = match byte_to_resource(resource_byte)? {
-        Resource::AGENT => protobuf::parse_from_bytes::<AgentList>(value)?
-            .get_agents()
+        Resource::AGENT => AgentList::from_bytes(value)?
+            .agents()
             .into_iter()
             .filter_map(|agent| set_agent(conn, agent).err())
             .collect(),
-        Resource::ORG => protobuf::parse_from_bytes::<OrganizationList>(value)?
-            .get_organizations()
+        Resource::ORG => OrganizationList::from_bytes(value)?
+            .organizations()
             .into_iter()
             .filter_map(|org| set_org(conn, org).err())
             .collect()
@@ -62,21 +87,40 @@ fn set(conn: &PgConnection, address: &str, value: &[u8]) -> Result<(), StateChan
     }
 }
 
+/// A DELETE state change carries only the address, so the deleted agents and organizations are
+/// found by computing the address of each one stored
+fn delete(conn: &PgConnection, address: &str) -> Result<(), StateChangeError> {
+    let resource_byte = &address[6..8];
+
+    let results: Vec<StateChangeError> = match byte_to_resource(resource_byte)? {
+        Resource::AGENT => db::get_agents(conn)?
+            .into_iter()
+            .filter(|agent| compute_pike_agent_address(&agent.public_key) == address)
+            .filter_map(|agent| db::delete_agent(conn, &agent.public_key).err())
+            .map(StateChangeError::from)
+            .collect(),
+        Resource::ORG => db::get_orgs(conn)?
+            .into_iter()
+            .filter(|org| compute_pike_organization_address(&org.id) == address)
+            .filter_map(|org| db::delete_organization(conn, &org.id).err())
+            .map(StateChangeError::from)
+            .collect()
+    };
+
+    if results.is_empty() {
+        Ok(())
+    } else {
+        Err(StateChangeError::DeleteErrors(results))
+    }
+}
+
 fn set_agent(conn: &PgConnection, agent: &Agent) -> Result<(), StateChangeError> {
-    let metadata = agent
-        .metadata
-        .iter()
-        .map(|x| json!({
-            "key": x.get_key(),
-            "value": x.get_value()
-        }))
-    .collect();
     let new_agent = NewAgent {
-        org_id: &agent.org_id,
-        public_key: &agent.public_key,
-        active: agent.active,
-        roles: agent.roles.to_vec(),
-        metadata,
+        org_id: agent.org_id(),
+        public_key: agent.public_key(),
+        active: *agent.active(),
+        roles: agent.roles().to_vec(),
+        metadata: metadata_to_json(agent.metadata()),
     };
     match db::get_agent(conn, &new_agent.public_key) {
         Ok(_) => db::update_agent(conn, &new_agent.public_key, new_agent)
@@ -91,9 +135,14 @@ fn set_agent(conn: &PgConnection, agent: &Agent) -> Result<(), StateChangeError>
 
 fn set_org(conn: &PgConnection, org: &Organization) -> Result<(), StateChangeError> {
     let new_org = NewOrganization {
-        id: &org.org_id,
-        name: &org.name,
-        address: &org.address
+        id: org.org_id(),
+        name: org.name(),
+        address: org.address(),
+        metadata: metadata_to_json(org.metadata()),
+        status: match *org.status() {
+            OrganizationStatus::Active => "ACTIVE",
+            OrganizationStatus::Suspended => "SUSPENDED"
+        }
     };
 
     match db::get_org(conn, &new_org.id) {
@@ -107,18 +156,29 @@ fn set_org(conn: &PgConnection, org: &Organization) -> Result<(), StateChangeErr
     }
 }
 
+fn metadata_to_json(metadata: &[KeyValueEntry]) -> Vec<serde_json::Value> {
+    metadata
+        .iter()
+        .map(|x| json!({
+            "key": x.key(),
+            "value": x.value()
+        }))
+        .collect()
+}
+
 #[derive(Debug)]
 pub enum StateChangeError {
     UnsupportedTypeError(String),
     ResourceError(ResourceError),
     UnsupportedResourceError(String),
     SqlQueryError(QueryError),
-    ParseError(protobuf::ProtobufError),
-    SetErrors(Vec<StateChangeError>)
+    ParseError(ProtoConversionError),
+    SetErrors(Vec<StateChangeError>),
+    DeleteErrors(Vec<StateChangeError>)
 }
 
-impl From<protobuf::ProtobufError> for StateChangeError {
-    fn from(e: protobuf::ProtobufError) -> Self {
+impl From<ProtoConversionError> for StateChangeError {
+    fn from(e: ProtoConversionError) -> Self {
         StateChangeError::ParseError(e)
     }
 }
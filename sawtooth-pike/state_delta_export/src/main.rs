@@ -20,28 +20,35 @@ extern crate pike_db;
 extern crate addresser;
 extern crate simple_logger;
 extern crate protobuf;
+extern crate grid_sdk;
 extern crate uuid;
 extern crate chan_signal;
 extern crate regex;
 
 mod subscriber;
 mod database;
-mod protos;
+mod resync;
 
 use std::{thread, env};
 use chan_signal::Signal;
 use subscriber::Subscriber;
 use log::LogLevel;
 use regex::Regex;
-use database::apply_state_change;
+use database::apply_block;
+use resync::resync;
+use pike_db as db;
 use pike_db::pools::init_pg_pool;
 
-use sawtooth_sdk::messages::transaction_receipt::StateChangeList;
+use sawtooth_sdk::messages::transaction_receipt::{StateChange, StateChangeList};
 
 const APP_NAME: &'static str = env!("CARGO_PKG_NAME");
 const VERSION: &'static str = env!("CARGO_PKG_VERSION");
 const AUTHOR: &'static str = "Cargill";
 
+// The number of recently exported block ids to resume from, in case the most recent are no longer
+// on the chain after a fork
+const LAST_KNOWN_BLOCK_COUNT: i64 = 10;
+
 fn url_is_valid(url: &str) -> bool {
     Regex::new(r"^[a-zA-Z]+://(.+:.+@)?.+:[0-9]{2,5}/?.*$")
         .unwrap()
@@ -57,6 +64,7 @@ fn main() {
         (@arg verbose: -v +multiple "Log verbosely")
         (@arg connect: -c  --connect +takes_value "Validator to connect to")
         (@arg db: -d --database +takes_value "Full url to database")
+        (@arg resync: --resync "Rebuild the database from the current state of the validator")
     ).get_matches();
 
     let logger = match matches.occurrences_of("verbose") {
@@ -98,23 +106,48 @@ fn main() {
         .get()
         .expect("Failed to create postgress database connection");
 
-    thread::spawn(move || subscriber.start(connect, |e| {
-        e.events
+    let last_known_block_ids = if matches.is_present("resync") {
+        match resync(&db_conn, &connect) {
+            Ok(block_id) => vec![block_id],
+            Err(err) => {
+                error!("Failed to rebuild database: {:?}", err);
+                std::process::exit(1);
+            }
+        }
+    } else {
+        db::get_last_block_ids(&db_conn, LAST_KNOWN_BLOCK_COUNT)
+            .expect("Failed to read the last exported blocks")
+    };
+
+    thread::spawn(move || subscriber.start(connect, last_known_block_ids, |e| {
+        let block_id = match e.events
+            .iter()
+            .filter(|x| "sawtooth/block-commit" == x.event_type)
+            .filter_map(|x| x.attributes.iter().find(|attr| attr.key == "block_id"))
+            .next()
+        {
+            Some(attr) => attr.value.clone(),
+            None => {
+                error!("Received events without a block commit");
+                return;
+            }
+        };
+
+        let state_changes: Vec<StateChange> = e.events
             .iter()
             .filter(|x| "sawtooth/state-delta" == x.event_type)
             .filter_map(|x| -> Option<StateChangeList> {
                 protobuf::parse_from_bytes(&x.data).ok()
             })
             .flat_map(|x| x.state_changes.into_iter())
-            .for_each(|x| {
-                info!("Applying state change {:?}", x);
-                apply_state_change(&db_conn, &x)
-                    .and_then(|_| {
-                        info!("State change applied successfully");
-                        Ok(())
-                    })
-                    .unwrap_or_else(|err| error!("{:?}", err))
-            })
+            .collect();
+
+        // the block is only recorded if all of its state changes were applied, so that a
+        // restarted export resumes from before a block that failed
+        match apply_block(&db_conn, &block_id, &state_changes) {
+            Ok(()) => info!("Exported block {}", block_id),
+            Err(err) => error!("Failed to export block {}: {:?}", block_id, err)
+        }
     }));
 
     signal
@@ -0,0 +1,180 @@
+// Copyright 2018 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf;
+use uuid;
+
+use sawtooth_sdk::messaging::stream::MessageSender;
+use sawtooth_sdk::messaging::stream::MessageConnection;
+use sawtooth_sdk::messaging::zmq_stream::{ZmqMessageConnection, ZmqMessageSender};
+use sawtooth_sdk::messages::block::BlockHeader;
+use sawtooth_sdk::messages::client_block::{ClientBlockListRequest, ClientBlockListResponse};
+use sawtooth_sdk::messages::client_block::ClientBlockListResponse_Status;
+use sawtooth_sdk::messages::client_list_control::ClientPagingControls;
+use sawtooth_sdk::messages::client_state::{ClientStateListRequest, ClientStateListResponse};
+use sawtooth_sdk::messages::client_state::ClientStateListResponse_Status;
+use sawtooth_sdk::messages::validator::Message_MessageType;
+use sawtooth_sdk::messages::validator::Message_MessageType::{CLIENT_BLOCK_LIST_REQUEST, CLIENT_STATE_LIST_REQUEST};
+
+use pike_db as db;
+use pike_db::{Connection, PgConnection, QueryError};
+
+use grid_sdk::addressing::PIKE_NAMESPACE;
+
+use database::{set, StateChangeError};
+
+const PAGE_SIZE: i32 = 100;
+
+/// Empties the exported tables and rebuilds them from the Pike state at the current chain head.
+/// Returns the id of the chain head, which is recorded as the last exported block.
+///
+/// The tables are rebuilt in a single transaction, so a failed resync leaves the database as
+/// it was.
+pub fn resync(conn: &PgConnection, validator: &str) -> Result<String, ResyncError> {
+    info!("Establishing connection with validator {}", validator);
+
+    let connection = ZmqMessageConnection::new(validator);
+    let (sender, _receiver) = connection.create();
+
+    let (block_id, state_root) = get_chain_head(&sender)?;
+
+    info!("Rebuilding database from state at block {}", block_id);
+
+    conn.transaction::<_, ResyncError, _>(|| rebuild(conn, &sender, &block_id, &state_root))?;
+
+    Ok(block_id)
+}
+
+fn rebuild(
+    conn: &PgConnection,
+    sender: &ZmqMessageSender,
+    block_id: &str,
+    state_root: &str
+) -> Result<(), ResyncError> {
+    db::truncate_tables(conn)?;
+
+    let mut start = String::new();
+    loop {
+        let mut paging = ClientPagingControls::new();
+        paging.set_start(start);
+        paging.set_limit(PAGE_SIZE);
+
+        let mut request = ClientStateListRequest::new();
+        request.set_state_root(state_root.to_string());
+        request.set_address(String::from(PIKE_NAMESPACE));
+        request.set_paging(paging);
+
+        let response: ClientStateListResponse =
+            send_request(sender, CLIENT_STATE_LIST_REQUEST, &request)?;
+
+        match response.status {
+            ClientStateListResponse_Status::OK => (),
+            // nothing has been stored under the namespace yet
+            ClientStateListResponse_Status::NO_RESOURCE => break,
+            status => return Err(ResyncError::ValidatorError(
+                format!("state list status: {:?}", status)))
+        }
+
+        for entry in response.get_entries() {
+            set(conn, entry.get_address(), entry.get_data())?;
+        }
+
+        start = response.get_paging().get_next().to_string();
+        if start.is_empty() {
+            break;
+        }
+    }
+
+    db::insert_block(conn, block_id)?;
+
+    Ok(())
+}
+
+/// Returns the id and state root hash of the current chain head
+fn get_chain_head(sender: &ZmqMessageSender) -> Result<(String, String), ResyncError> {
+    let mut paging = ClientPagingControls::new();
+    paging.set_limit(1);
+
+    let mut request = ClientBlockListRequest::new();
+    request.set_paging(paging);
+
+    let response: ClientBlockListResponse =
+        send_request(sender, CLIENT_BLOCK_LIST_REQUEST, &request)?;
+
+    if response.status != ClientBlockListResponse_Status::OK {
+        return Err(ResyncError::ValidatorError(
+            format!("block list status: {:?}", response.status)));
+    }
+
+    let block = match response.get_blocks().first() {
+        Some(block) => block,
+        None => return Err(ResyncError::ValidatorError(
+            String::from("no blocks have been committed")))
+    };
+
+    let header: BlockHeader = protobuf::parse_from_bytes(block.get_header())
+        .map_err(|err| ResyncError::CommunicationError(
+            format!("Error parsing block header: {:?}", err)))?;
+
+    Ok((block.get_header_signature().to_string(), header.get_state_root_hash().to_string()))
+}
+
+fn send_request<T, R>(
+    sender: &ZmqMessageSender,
+    message_type: Message_MessageType,
+    request: &T
+) -> Result<R, ResyncError>
+where T: protobuf::Message, R: protobuf::Message {
+    let msg_bytes = protobuf::Message::write_to_bytes(request)
+        .map_err(|err| ResyncError::CommunicationError(
+            format!("Error serializing request: {:?}", err)))?;
+
+    let correlation_id = match uuid::Uuid::new(uuid::UuidVersion::Random) {
+        Some(cid) => cid.to_string(),
+        None => return Err(ResyncError::CommunicationError(
+            String::from("Error generating UUID")))
+    };
+
+    let mut future = sender.send(message_type, &correlation_id, &msg_bytes)
+        .map_err(|err| ResyncError::CommunicationError(
+            format!("Error sending request: {:?}", err)))?;
+
+    let response_msg = future.get()
+        .map_err(|err| ResyncError::CommunicationError(
+            format!("Error getting response: {:?}", err)))?;
+
+    protobuf::parse_from_bytes(&response_msg.content)
+        .map_err(|err| ResyncError::CommunicationError(
+            format!("Error parsing response: {:?}", err)))
+}
+
+#[derive(Debug)]
+pub enum ResyncError {
+    CommunicationError(String),
+    ValidatorError(String),
+    SqlQueryError(QueryError),
+    StateChangeError(StateChangeError)
+}
+
+impl From<QueryError> for ResyncError {
+    fn from(e: QueryError) -> Self {
+        ResyncError::SqlQueryError(e)
+    }
+}
+
+impl From<StateChangeError> for ResyncError {
+    fn from(e: StateChangeError) -> Self {
+        ResyncError::StateChangeError(e)
+    }
+}
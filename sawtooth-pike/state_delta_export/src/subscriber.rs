@@ -40,7 +40,10 @@ impl Subscriber {
         }
     }
 
-    pub fn start<F>(&mut self, validator: String, handler: F) where F : Fn(EventList) {
+    /// Subscribes to Pike state deltas, starting after the most recent of `last_known_block_ids`
+    /// found on the chain, or from the genesis block if none are given
+    pub fn start<F>(&mut self, validator: String, last_known_block_ids: Vec<String>, handler: F)
+        where F : Fn(EventList) {
 
         // Connect to Validator
 
@@ -69,8 +72,14 @@ impl Subscriber {
 
         let mut subscription_request = ClientEventsSubscribeRequest::new();
 
+        let last_known_block_ids = if last_known_block_ids.is_empty() {
+            vec![String::from(NULL_BLOCK_ID)]
+        } else {
+            last_known_block_ids
+        };
+
         subscription_request.set_last_known_block_ids(
-            protobuf::RepeatedField::from_vec(vec![String::from(NULL_BLOCK_ID)]));
+            protobuf::RepeatedField::from_vec(last_known_block_ids));
         subscription_request.set_subscriptions(
             protobuf::RepeatedField::from_vec(vec![state_delta_sub, block_commit_sub]));
 
@@ -118,6 +127,11 @@ impl Subscriber {
 
         // Validate response
 
+        if response.status == ClientEventsSubscribeResponse_Status::UNKNOWN_BLOCK {
+            error!("None of the exported blocks are on the chain; restart with --resync");
+            return;
+        }
+
         if !(response.status == ClientEventsSubscribeResponse_Status::OK) {
             error!("subscription status: {:?}", response.status);
             return;
@@ -12,35 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Runs the Pike transaction family from the `pike` library, either as a native transaction
+//! processor or as a Sabre smart contract
+
 #[macro_use]
 extern crate cfg_if;
-extern crate protobuf;
-extern crate crypto;
-extern crate addresser;
+extern crate pike;
 
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
         extern crate sabre_sdk;
+
+        use sabre_sdk::{WasmPtr, execute_entrypoint};
     } else {
         #[macro_use]
         extern crate clap;
-        #[macro_use]
         extern crate log;
         extern crate sawtooth_sdk;
         extern crate simple_logger;
 
         use log::LogLevel;
         use sawtooth_sdk::processor::TransactionProcessor;
-        use handler::PikeTransactionHandler;
+        use pike::handler::PikeTransactionHandler;
     }
 }
 
-pub mod handler;
-mod protos;
-
-//use sawtooth_sdk::processor::TransactionProcessor;
-//use handler::PikeTransactionHandler;
-
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
     let matches = clap_app!(wasm_store_tp =>
@@ -74,3 +70,9 @@ fn main() {
 #[cfg(target_arch = "wasm32")]
 fn main() {
 }
+
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub unsafe fn entrypoint(payload: WasmPtr, signer: WasmPtr, signature: WasmPtr) -> i32 {
+    execute_entrypoint(payload, signer, signature, pike::handler::apply)
+}
@@ -0,0 +1,290 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error as StdError;
+
+use crypto::digest::Digest;
+use crypto::sha2::{Sha256, Sha512};
+
+/// Length, in hex characters, of a state address
+pub const ADDRESS_LENGTH: usize = 70;
+
+pub const SETTINGS_NAMESPACE: &str = "000000";
+/// Number of dot-separated parts of a setting key that are hashed into its address
+const SETTINGS_KEY_PARTS: usize = 4;
+
+pub const BLOCK_INFO_NAMESPACE: &str = "00b10c";
+pub const BLOCK_INFO_PREFIX: &str = "00b10c00";
+pub const BLOCK_INFO_CONFIG_PREFIX: &str = "00b10c01";
+
+pub const PIKE_NAMESPACE: &str = "cad11d";
+pub const PIKE_AGENT_PREFIX: &str = "cad11d00";
+pub const PIKE_ORGANIZATION_PREFIX: &str = "cad11d01";
+
+pub const GRID_NAMESPACE: &str = "621dee";
+pub const GRID_SCHEMA_PREFIX: &str = "621dee01";
+
+pub const TRACK_AND_TRACE_NAMESPACE: &str = "a43b46";
+pub const TRACK_AND_TRACE_AGENT_PREFIX: &str = "a43b46ae";
+pub const TRACK_AND_TRACE_PROPERTY_PREFIX: &str = "a43b46ea";
+pub const TRACK_AND_TRACE_PROPOSAL_PREFIX: &str = "a43b46aa";
+pub const TRACK_AND_TRACE_RECORD_PREFIX: &str = "a43b46ec";
+pub const TRACK_AND_TRACE_RECORD_TYPE_PREFIX: &str = "a43b46ee";
+
+/// The kind of state stored at an address
+#[derive(Debug, Clone, PartialEq)]
+pub enum Resource {
+    PikeAgent,
+    PikeOrganization,
+    GridSchema,
+    TrackAndTraceAgent,
+    /// A page of a track and trace property; page 0 holds the property itself
+    TrackAndTraceProperty {
+        page: u32,
+    },
+    TrackAndTraceProposal,
+    TrackAndTraceRecord,
+    TrackAndTraceRecordType,
+}
+
+#[derive(Debug)]
+pub enum AddressingError {
+    /// The address is not 70 lowercase hex characters
+    InvalidAddress(String),
+    /// The address does not start with a known namespace and resource prefix
+    UnknownResource(String),
+}
+
+impl StdError for AddressingError {}
+
+impl std::fmt::Display for AddressingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AddressingError::InvalidAddress(ref s) => write!(f, "InvalidAddress: {}", s),
+            AddressingError::UnknownResource(ref s) => write!(f, "UnknownResource: {}", s),
+        }
+    }
+}
+
+/// Returns the first `num` hex characters of the SHA-512 hash of `to_hash`
+fn hash(to_hash: &str, num: usize) -> String {
+    let mut sha = Sha512::new();
+    sha.input_str(to_hash);
+    sha.result_str()[..num].to_string()
+}
+
+/// Computes the address of a Sawtooth setting. Each of the first three dot-separated parts of
+/// the key, and the remainder of the key, contribute 16 characters of their SHA-256 hash; keys
+/// with fewer parts are padded with the hash of the empty string.
+pub fn compute_setting_address(key: &str) -> String {
+    let mut parts: Vec<&str> = key.splitn(SETTINGS_KEY_PARTS, '.').collect();
+    parts.resize(SETTINGS_KEY_PARTS, "");
+
+    parts
+        .iter()
+        .fold(String::from(SETTINGS_NAMESPACE), |address, part| {
+            let mut sha = Sha256::new();
+            sha.input_str(part);
+            address + &sha.result_str()[..16]
+        })
+}
+
+/// Computes the address of the BlockInfo config, which records the range of blocks whose info
+/// is stored in state
+pub fn compute_block_info_config_address() -> String {
+    String::from(BLOCK_INFO_CONFIG_PREFIX) + &"0".repeat(62)
+}
+
+/// Computes the address of the info stored by the BlockInfo transaction processor for a block
+pub fn compute_block_info_address(block_num: u64) -> String {
+    format!("{}{:062x}", BLOCK_INFO_PREFIX, block_num)
+}
+
+pub fn compute_pike_agent_address(public_key: &str) -> String {
+    String::from(PIKE_AGENT_PREFIX) + &hash(public_key, 62)
+}
+
+pub fn compute_pike_organization_address(org_id: &str) -> String {
+    String::from(PIKE_ORGANIZATION_PREFIX) + &hash(org_id, 62)
+}
+
+pub fn compute_schema_address(name: &str) -> String {
+    String::from(GRID_SCHEMA_PREFIX) + &hash(name, 62)
+}
+
+pub fn compute_track_and_trace_agent_address(public_key: &str) -> String {
+    String::from(TRACK_AND_TRACE_AGENT_PREFIX) + &hash(public_key, 62)
+}
+
+pub fn compute_track_and_trace_record_address(record_id: &str) -> String {
+    String::from(TRACK_AND_TRACE_RECORD_PREFIX) + &hash(record_id, 62)
+}
+
+pub fn compute_track_and_trace_record_type_address(name: &str) -> String {
+    String::from(TRACK_AND_TRACE_RECORD_TYPE_PREFIX) + &hash(name, 62)
+}
+
+/// Computes the address of a page of a property. Page 0 holds the property itself; its values
+/// are reported on pages 1 through 0xffff.
+pub fn compute_track_and_trace_property_address(
+    record_id: &str,
+    property_name: &str,
+    page: u32,
+) -> String {
+    compute_track_and_trace_property_address_range(record_id)
+        + &hash(property_name, 22)
+        + &format!("{:04x}", page)
+}
+
+/// Computes the prefix shared by the addresses of every property of a record
+pub fn compute_track_and_trace_property_address_range(record_id: &str) -> String {
+    String::from(TRACK_AND_TRACE_PROPERTY_PREFIX) + &hash(record_id, 36)
+}
+
+pub fn compute_track_and_trace_proposal_address(record_id: &str, agent_id: &str) -> String {
+    String::from(TRACK_AND_TRACE_PROPOSAL_PREFIX) + &hash(record_id, 36) + &hash(agent_id, 26)
+}
+
+/// Determines the kind of state stored at an address from its namespace and resource prefix
+pub fn parse_address(address: &str) -> Result<Resource, AddressingError> {
+    if address.len() != ADDRESS_LENGTH
+        || !address
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    {
+        return Err(AddressingError::InvalidAddress(format!(
+            "Address must be {} lowercase hex characters: {}",
+            ADDRESS_LENGTH, address
+        )));
+    }
+
+    match &address[..8] {
+        PIKE_AGENT_PREFIX => Ok(Resource::PikeAgent),
+        PIKE_ORGANIZATION_PREFIX => Ok(Resource::PikeOrganization),
+        GRID_SCHEMA_PREFIX => Ok(Resource::GridSchema),
+        TRACK_AND_TRACE_AGENT_PREFIX => Ok(Resource::TrackAndTraceAgent),
+        TRACK_AND_TRACE_PROPERTY_PREFIX => {
+            let page = u32::from_str_radix(&address[ADDRESS_LENGTH - 4..], 16).map_err(|err| {
+                AddressingError::InvalidAddress(format!("Invalid property page: {}", err))
+            })?;
+            Ok(Resource::TrackAndTraceProperty { page })
+        }
+        TRACK_AND_TRACE_PROPOSAL_PREFIX => Ok(Resource::TrackAndTraceProposal),
+        TRACK_AND_TRACE_RECORD_PREFIX => Ok(Resource::TrackAndTraceRecord),
+        TRACK_AND_TRACE_RECORD_TYPE_PREFIX => Ok(Resource::TrackAndTraceRecordType),
+        prefix => Err(AddressingError::UnknownResource(format!(
+            "No resource found matching prefix {}",
+            prefix
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    #[test]
+    // check that addresses match the ones computed by the existing transaction processors
+    fn check_known_addresses() {
+        assert_eq!(
+            compute_pike_agent_address("agent"),
+            String::from("cad11d00") + &hash("agent", 62)
+        );
+        assert_eq!(
+            compute_track_and_trace_property_address("record", "weight", 0x1a2),
+            String::from("a43b46ea") + &hash("record", 36) + &hash("weight", 22) + "01a2"
+        );
+    }
+
+    #[test]
+    // check that setting addresses match the ones computed by the settings transaction processor
+    fn check_setting_addresses() {
+        assert_eq!(
+            compute_setting_address("sawtooth.settings.vote.authorized_keys"),
+            "000000a87cb5eafdcca6a8cde0fb0dec1400c5ab274474a6aa82c12840f169a04216b7"
+        );
+        assert_eq!(compute_setting_address("grid").len(), ADDRESS_LENGTH);
+    }
+
+    #[test]
+    // check that block info addresses match the ones used by the BlockInfo transaction processor
+    fn check_block_info_addresses() {
+        assert_eq!(
+            compute_block_info_config_address(),
+            String::from("00b10c01") + &"0".repeat(62)
+        );
+        assert_eq!(
+            compute_block_info_address(0x1a2),
+            String::from("00b10c00") + &"0".repeat(59) + "1a2"
+        );
+        assert_eq!(
+            compute_block_info_address(u64::max_value()).len(),
+            ADDRESS_LENGTH
+        );
+    }
+
+    #[test]
+    // check that malformed and unknown addresses are rejected
+    fn check_parse_address_errors() {
+        match parse_address("cad11d00") {
+            Err(AddressingError::InvalidAddress(_)) => (),
+            res => panic!("Expected InvalidAddress, got {:?}", res),
+        }
+        match parse_address(&compute_pike_agent_address("agent").to_uppercase()) {
+            Err(AddressingError::InvalidAddress(_)) => (),
+            res => panic!("Expected InvalidAddress, got {:?}", res),
+        }
+        match parse_address(&"0".repeat(ADDRESS_LENGTH)) {
+            Err(AddressingError::UnknownResource(_)) => (),
+            res => panic!("Expected UnknownResource, got {:?}", res),
+        }
+    }
+
+    proptest! {
+        #[test]
+        // check that parsing a computed address gives back the resource it was computed for
+        fn check_parse_computed_address(
+            key in ".*",
+            other_key in ".*",
+            page in 0u32..=0xffff,
+        ) {
+            let cases = vec![
+                (compute_pike_agent_address(&key), Resource::PikeAgent),
+                (compute_pike_organization_address(&key), Resource::PikeOrganization),
+                (compute_schema_address(&key), Resource::GridSchema),
+                (compute_track_and_trace_agent_address(&key), Resource::TrackAndTraceAgent),
+                (
+                    compute_track_and_trace_property_address(&key, &other_key, page),
+                    Resource::TrackAndTraceProperty { page },
+                ),
+                (
+                    compute_track_and_trace_proposal_address(&key, &other_key),
+                    Resource::TrackAndTraceProposal,
+                ),
+                (compute_track_and_trace_record_address(&key), Resource::TrackAndTraceRecord),
+                (
+                    compute_track_and_trace_record_type_address(&key),
+                    Resource::TrackAndTraceRecordType,
+                ),
+            ];
+
+            for (address, resource) in cases {
+                prop_assert_eq!(address.len(), ADDRESS_LENGTH);
+                prop_assert_eq!(parse_address(&address).unwrap(), resource);
+            }
+        }
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::str::FromStr;
+
+/// A fixed-point decimal number, `mantissa * 10^exponent`.
+///
+/// NUMBER properties store the mantissa as their value and the exponent as the
+/// `number_exponent` of their definition, so `12.345` with an exponent of -3 is stored as 12345.
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i64,
+    exponent: i32,
+}
+
+impl Decimal {
+    pub fn new(mantissa: i64, exponent: i32) -> Self {
+        Decimal { mantissa, exponent }
+    }
+
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Returns the same value with the given exponent. Fails if the mantissa no longer fits in
+    /// an `i64`, or if digits would have to be dropped to reach the exponent.
+    pub fn rescale(&self, exponent: i32) -> Result<Decimal, DecimalError> {
+        let diff = i64::from(exponent) - i64::from(self.exponent);
+        let factor = 10i64.checked_pow(u32::try_from(diff.abs()).unwrap_or(u32::MAX));
+
+        let mantissa = if self.mantissa == 0 {
+            0
+        } else if diff < 0 {
+            factor
+                .and_then(|factor| self.mantissa.checked_mul(factor))
+                .ok_or_else(|| {
+                    DecimalError::Overflow(format!(
+                        "{} cannot be represented with an exponent of {}",
+                        self, exponent
+                    ))
+                })?
+        } else {
+            match factor {
+                Some(factor) if self.mantissa % factor == 0 => self.mantissa / factor,
+                _ => {
+                    return Err(DecimalError::PrecisionLoss(format!(
+                        "{} cannot be represented with an exponent of {}",
+                        self, exponent
+                    )));
+                }
+            }
+        };
+
+        Ok(Decimal { mantissa, exponent })
+    }
+
+    /// Removes trailing zeros from the mantissa, raising the exponent to match
+    fn normalize(&self) -> Decimal {
+        let mut normalized = *self;
+        while normalized.mantissa != 0
+            && normalized.mantissa % 10 == 0
+            && normalized.exponent < i32::MAX
+        {
+            normalized.mantissa /= 10;
+            normalized.exponent += 1;
+        }
+        normalized
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    /// Parses a decimal string such as `12.345`, `-0.5` or `100`. The exponent is the negated
+    /// number of digits after the decimal point.
+    fn from_str(s: &str) -> Result<Decimal, DecimalError> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let mut parts = digits.splitn(2, '.');
+        let integer = parts.next().unwrap_or("");
+        let fraction = parts.next().unwrap_or("");
+
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(DecimalError::ParseError(format!(
+                "Invalid decimal: '{}'",
+                s
+            )));
+        }
+
+        let overflow = || DecimalError::Overflow(format!("Decimal is out of range: {}", s));
+
+        let mut mantissa: i128 = 0;
+        for digit in integer.chars().chain(fraction.chars()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(digit as u8 - b'0')))
+                .ok_or_else(overflow)?;
+        }
+        if negative {
+            mantissa = -mantissa;
+        }
+
+        let mantissa = i64::try_from(mantissa).map_err(|_| overflow())?;
+        let exponent = i32::try_from(fraction.len())
+            .map(|len| -len)
+            .map_err(|_| overflow())?;
+
+        Ok(Decimal { mantissa, exponent })
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.mantissa == 0 || self.exponent == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = self.mantissa.unsigned_abs().to_string();
+
+        if self.exponent > 0 {
+            return write!(
+                f,
+                "{}{}{}",
+                sign,
+                digits,
+                "0".repeat(self.exponent as usize)
+            );
+        }
+
+        let scale = self.exponent.unsigned_abs() as usize;
+        let digits = if digits.len() <= scale {
+            "0".repeat(scale - digits.len() + 1) + &digits
+        } else {
+            digits
+        };
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, integer, fraction)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        let sign = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if sign != Ordering::Equal || self.mantissa == 0 {
+            return sign;
+        }
+
+        let (left, right) = (self.normalize(), other.normalize());
+        let exponent = left.exponent.min(right.exponent);
+        // Scale both mantissas to the smaller exponent. A mantissa too large to scale is
+        // larger in magnitude than any i64 at that exponent.
+        let scale = |decimal: &Decimal| {
+            let diff = (i64::from(decimal.exponent) - i64::from(exponent)) as u32;
+            10i128
+                .checked_pow(diff)
+                .and_then(|factor| i128::from(decimal.mantissa).checked_mul(factor))
+        };
+
+        let magnitude = match (scale(&left), scale(&right)) {
+            (Some(left), Some(right)) => left.abs().cmp(&right.abs()),
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        };
+
+        if self.mantissa < 0 {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecimalError {
+    /// The string is not a decimal number
+    ParseError(String),
+    /// The mantissa does not fit in an i64
+    Overflow(String),
+    /// Rescaling would drop non-zero digits
+    PrecisionLoss(String),
+}
+
+impl StdError for DecimalError {}
+
+impl std::fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DecimalError::ParseError(ref s) => write!(f, "ParseError: {}", s),
+            DecimalError::Overflow(ref s) => write!(f, "Overflow: {}", s),
+            DecimalError::PrecisionLoss(ref s) => write!(f, "PrecisionLoss: {}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that decimal strings are parsed into a mantissa and exponent
+    fn check_parse() {
+        let decimal: Decimal = "12.345".parse().unwrap();
+        assert_eq!((decimal.mantissa(), decimal.exponent()), (12345, -3));
+
+        let decimal: Decimal = "-0.050".parse().unwrap();
+        assert_eq!((decimal.mantissa(), decimal.exponent()), (-50, -3));
+
+        let decimal: Decimal = "+100".parse().unwrap();
+        assert_eq!((decimal.mantissa(), decimal.exponent()), (100, 0));
+
+        let decimal: Decimal = ".5".parse().unwrap();
+        assert_eq!((decimal.mantissa(), decimal.exponent()), (5, -1));
+
+        let decimal: Decimal = "-9223372036854775808".parse().unwrap();
+        assert_eq!(decimal.mantissa(), i64::MIN);
+    }
+
+    #[test]
+    // check that malformed or out of range strings are rejected
+    fn check_parse_errors() {
+        for invalid in &["", "-", ".", "1.2.3", "1e5", "12a", " 1", "--1"] {
+            match invalid.parse::<Decimal>() {
+                Err(DecimalError::ParseError(_)) => (),
+                res => panic!("Expected ParseError for '{}', got {:?}", invalid, res),
+            }
+        }
+
+        match "9223372036854775808".parse::<Decimal>() {
+            Err(DecimalError::Overflow(_)) => (),
+            res => panic!("Expected Overflow, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that decimals are formatted with their exponent
+    fn check_display() {
+        assert_eq!(Decimal::new(12345, -3).to_string(), "12.345");
+        assert_eq!(Decimal::new(-5, -3).to_string(), "-0.005");
+        assert_eq!(Decimal::new(12300, -2).to_string(), "123.00");
+        assert_eq!(Decimal::new(42, 2).to_string(), "4200");
+        assert_eq!(Decimal::new(0, -2).to_string(), "0");
+        assert_eq!(
+            Decimal::new(i64::MIN, -1).to_string(),
+            "-922337203685477580.8"
+        );
+    }
+
+    #[test]
+    // check that rescaling keeps the value or fails explicitly
+    fn check_rescale() {
+        let decimal = Decimal::new(12345, -3);
+        assert_eq!(decimal.rescale(-5).unwrap().mantissa(), 1_234_500);
+        assert_eq!(Decimal::new(1200, -2).rescale(0).unwrap().mantissa(), 12);
+        assert_eq!(Decimal::new(0, 0).rescale(-40).unwrap().mantissa(), 0);
+
+        match decimal.rescale(-2) {
+            Err(DecimalError::PrecisionLoss(_)) => (),
+            res => panic!("Expected PrecisionLoss, got {:?}", res),
+        }
+        match decimal.rescale(-20) {
+            Err(DecimalError::Overflow(_)) => (),
+            res => panic!("Expected Overflow, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that decimals compare by value regardless of exponent
+    fn check_compare() {
+        assert_eq!(Decimal::new(1, 0), Decimal::new(100, -2));
+        assert!(Decimal::new(12345, -3) < Decimal::new(12346, -3));
+        assert!(Decimal::new(-1, 0) < Decimal::new(1, -5));
+        assert!(Decimal::new(-2, 0) < Decimal::new(-1, 0));
+        assert!(Decimal::new(1, 40) > Decimal::new(i64::MAX, 0));
+        assert!(Decimal::new(-1, 40) < Decimal::new(i64::MIN, 0));
+        assert_eq!(Decimal::new(0, 5), Decimal::new(0, -5));
+    }
+}
@@ -12,5 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod addressing;
+pub mod decimal;
 pub mod protocol;
 pub mod protos;
+pub mod signing;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod transaction;
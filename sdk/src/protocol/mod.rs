@@ -14,3 +14,4 @@
 
 pub mod pike;
 pub mod schema;
+pub mod track_and_trace;
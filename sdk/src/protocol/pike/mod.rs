@@ -13,4 +13,5 @@
 // limitations under the License.
 
 pub mod payload;
+pub mod permissions;
 pub mod state;
@@ -17,7 +17,7 @@ use protobuf::RepeatedField;
 
 use std::error::Error as StdError;
 
-use crate::protocol::pike::state::KeyValueEntry;
+use crate::protocol::pike::state::{KeyValueEntry, OrganizationStatus};
 use crate::protos;
 use crate::protos::{
     FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
@@ -30,6 +30,8 @@ pub enum Action {
     UpdateAgent,
     CreateOrganization,
     UpdateOrganization,
+    DeleteAgent,
+    DeleteOrganization,
 }
 
 impl FromProto<protos::pike_payload::PikePayload_Action> for Action {
@@ -45,6 +47,10 @@ impl FromProto<protos::pike_payload::PikePayload_Action> for Action {
             protos::pike_payload::PikePayload_Action::UPDATE_ORGANIZATION => {
                 Ok(Action::UpdateOrganization)
             }
+            protos::pike_payload::PikePayload_Action::DELETE_AGENT => Ok(Action::DeleteAgent),
+            protos::pike_payload::PikePayload_Action::DELETE_ORGANIZATION => {
+                Ok(Action::DeleteOrganization)
+            }
             protos::pike_payload::PikePayload_Action::ACTION_UNSET => {
                 Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert PikePayload_Action with type unset.".to_string(),
@@ -65,6 +71,10 @@ impl FromNative<Action> for protos::pike_payload::PikePayload_Action {
             Action::UpdateOrganization => {
                 Ok(protos::pike_payload::PikePayload_Action::UPDATE_ORGANIZATION)
             }
+            Action::DeleteAgent => Ok(protos::pike_payload::PikePayload_Action::DELETE_AGENT),
+            Action::DeleteOrganization => {
+                Ok(protos::pike_payload::PikePayload_Action::DELETE_ORGANIZATION)
+            }
         }
     }
 }
@@ -460,6 +470,7 @@ pub struct CreateOrganizationAction {
     org_id: String,
     name: String,
     address: String,
+    metadata: Vec<KeyValueEntry>,
 }
 
 impl CreateOrganizationAction {
@@ -474,6 +485,10 @@ impl CreateOrganizationAction {
     pub fn address(&self) -> &str {
         &self.address
     }
+
+    pub fn metadata(&self) -> &[KeyValueEntry] {
+        &self.metadata
+    }
 }
 
 impl FromProto<protos::pike_payload::CreateOrganizationAction> for CreateOrganizationAction {
@@ -484,6 +499,12 @@ impl FromProto<protos::pike_payload::CreateOrganizationAction> for CreateOrganiz
             org_id: create_org.get_id().to_string(),
             name: create_org.get_name().to_string(),
             address: create_org.get_address().to_string(),
+            metadata: create_org
+                .get_metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::from_proto)
+                .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
         })
     }
 }
@@ -495,6 +516,15 @@ impl FromNative<CreateOrganizationAction> for protos::pike_payload::CreateOrgani
         proto_create_org.set_id(create_org.org_id().to_string());
         proto_create_org.set_name(create_org.name().to_string());
         proto_create_org.set_address(create_org.address().to_string());
+        proto_create_org.set_metadata(RepeatedField::from_vec(
+            create_org
+                .metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::into_proto)
+                .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
+                )?,
+        ));
 
         Ok(proto_create_org)
     }
@@ -562,6 +592,7 @@ pub struct CreateOrganizationActionBuilder {
     pub org_id: Option<String>,
     pub name: Option<String>,
     pub address: Option<String>,
+    pub metadata: Vec<KeyValueEntry>,
 }
 
 impl CreateOrganizationActionBuilder {
@@ -584,6 +615,14 @@ impl CreateOrganizationActionBuilder {
         self
     }
 
+    pub fn with_metadata(
+        mut self,
+        metadata: Vec<KeyValueEntry>,
+    ) -> CreateOrganizationActionBuilder {
+        self.metadata = metadata;
+        self
+    }
+
     pub fn build(self) -> Result<CreateOrganizationAction, CreateOrganizationActionBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             CreateOrganizationActionBuildError::MissingField(
@@ -601,10 +640,13 @@ impl CreateOrganizationActionBuilder {
             )
         })?;
 
+        let metadata = self.metadata;
+
         Ok(CreateOrganizationAction {
             org_id,
             name,
             address,
+            metadata,
         })
     }
 }
@@ -615,6 +657,8 @@ pub struct UpdateOrganizationAction {
     org_id: String,
     name: String,
     address: String,
+    metadata: Vec<KeyValueEntry>,
+    status: Option<OrganizationStatus>,
 }
 
 impl UpdateOrganizationAction {
@@ -629,16 +673,42 @@ impl UpdateOrganizationAction {
     pub fn address(&self) -> &str {
         &self.address
     }
+
+    pub fn metadata(&self) -> &[KeyValueEntry] {
+        &self.metadata
+    }
+
+    /// The status to move the organization to, or `None` to leave it unchanged
+    pub fn status(&self) -> Option<&OrganizationStatus> {
+        self.status.as_ref()
+    }
 }
 
 impl FromProto<protos::pike_payload::UpdateOrganizationAction> for UpdateOrganizationAction {
     fn from_proto(
         create_org: protos::pike_payload::UpdateOrganizationAction,
     ) -> Result<Self, ProtoConversionError> {
+        let status = match create_org.get_status() {
+            protos::pike_payload::UpdateOrganizationAction_Status::UNCHANGED => None,
+            protos::pike_payload::UpdateOrganizationAction_Status::ACTIVE => {
+                Some(OrganizationStatus::Active)
+            }
+            protos::pike_payload::UpdateOrganizationAction_Status::SUSPENDED => {
+                Some(OrganizationStatus::Suspended)
+            }
+        };
+
         Ok(UpdateOrganizationAction {
             org_id: create_org.get_id().to_string(),
             name: create_org.get_name().to_string(),
             address: create_org.get_address().to_string(),
+            metadata: create_org
+                .get_metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::from_proto)
+                .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            status,
         })
     }
 }
@@ -650,6 +720,24 @@ impl FromNative<UpdateOrganizationAction> for protos::pike_payload::UpdateOrgani
         proto_update_org.set_id(update_org.org_id().to_string());
         proto_update_org.set_name(update_org.name().to_string());
         proto_update_org.set_address(update_org.address().to_string());
+        proto_update_org.set_metadata(RepeatedField::from_vec(
+            update_org
+                .metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::into_proto)
+                .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
+                )?,
+        ));
+        proto_update_org.set_status(match update_org.status() {
+            None => protos::pike_payload::UpdateOrganizationAction_Status::UNCHANGED,
+            Some(OrganizationStatus::Active) => {
+                protos::pike_payload::UpdateOrganizationAction_Status::ACTIVE
+            }
+            Some(OrganizationStatus::Suspended) => {
+                protos::pike_payload::UpdateOrganizationAction_Status::SUSPENDED
+            }
+        });
 
         Ok(proto_update_org)
     }
@@ -717,6 +805,8 @@ pub struct UpdateOrganizationActionBuilder {
     pub org_id: Option<String>,
     pub name: Option<String>,
     pub address: Option<String>,
+    pub metadata: Vec<KeyValueEntry>,
+    pub status: Option<OrganizationStatus>,
 }
 
 impl UpdateOrganizationActionBuilder {
@@ -739,6 +829,19 @@ impl UpdateOrganizationActionBuilder {
         self
     }
 
+    pub fn with_metadata(
+        mut self,
+        metadata: Vec<KeyValueEntry>,
+    ) -> UpdateOrganizationActionBuilder {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_status(mut self, status: OrganizationStatus) -> UpdateOrganizationActionBuilder {
+        self.status = Some(status);
+        self
+    }
+
     pub fn build(self) -> Result<UpdateOrganizationAction, UpdateOrganizationActionBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             UpdateOrganizationActionBuildError::MissingField(
@@ -750,14 +853,262 @@ impl UpdateOrganizationActionBuilder {
 
         let address = self.address.unwrap_or_default();
 
+        let metadata = self.metadata;
+        let status = self.status;
+
         Ok(UpdateOrganizationAction {
             org_id,
             name,
             address,
+            metadata,
+            status,
+        })
+    }
+}
+
+/// Native implementation for DeleteAgentAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteAgentAction {
+    org_id: String,
+    public_key: String,
+}
+
+impl DeleteAgentAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+}
+
+impl FromProto<protos::pike_payload::DeleteAgentAction> for DeleteAgentAction {
+    fn from_proto(
+        proto: protos::pike_payload::DeleteAgentAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DeleteAgentAction {
+            org_id: proto.get_org_id().to_string(),
+            public_key: proto.get_public_key().to_string(),
+        })
+    }
+}
+
+impl FromNative<DeleteAgentAction> for protos::pike_payload::DeleteAgentAction {
+    fn from_native(native: DeleteAgentAction) -> Result<Self, ProtoConversionError> {
+        let mut proto = protos::pike_payload::DeleteAgentAction::new();
+
+        proto.set_org_id(native.org_id().to_string());
+        proto.set_public_key(native.public_key().to_string());
+
+        Ok(proto)
+    }
+}
+
+impl FromBytes<DeleteAgentAction> for DeleteAgentAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteAgentAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteAgentAction = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DeleteAgentAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for DeleteAgentAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DeleteAgentAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::DeleteAgentAction> for DeleteAgentAction {}
+impl IntoNative<DeleteAgentAction> for protos::pike_payload::DeleteAgentAction {}
+
+#[derive(Debug)]
+pub enum DeleteAgentActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for DeleteAgentActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteAgentActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            DeleteAgentActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteAgentActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DeleteAgentActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a DeleteAgentAction
+#[derive(Default, Clone)]
+pub struct DeleteAgentActionBuilder {
+    pub org_id: Option<String>,
+    pub public_key: Option<String>,
+}
+
+impl DeleteAgentActionBuilder {
+    pub fn new() -> Self {
+        DeleteAgentActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DeleteAgentActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn with_public_key(mut self, public_key: String) -> DeleteAgentActionBuilder {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteAgentAction, DeleteAgentActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteAgentActionBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        let public_key = self.public_key.ok_or_else(|| {
+            DeleteAgentActionBuildError::MissingField("'public_key' field is required".to_string())
+        })?;
+
+        Ok(DeleteAgentAction { org_id, public_key })
+    }
+}
+
+/// Native implementation for DeleteOrganizationAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DeleteOrganizationAction {
+    org_id: String,
+}
+
+impl DeleteOrganizationAction {
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+}
+
+impl FromProto<protos::pike_payload::DeleteOrganizationAction> for DeleteOrganizationAction {
+    fn from_proto(
+        proto: protos::pike_payload::DeleteOrganizationAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(DeleteOrganizationAction {
+            org_id: proto.get_id().to_string(),
         })
     }
 }
 
+impl FromNative<DeleteOrganizationAction> for protos::pike_payload::DeleteOrganizationAction {
+    fn from_native(native: DeleteOrganizationAction) -> Result<Self, ProtoConversionError> {
+        let mut proto = protos::pike_payload::DeleteOrganizationAction::new();
+
+        proto.set_id(native.org_id().to_string());
+
+        Ok(proto)
+    }
+}
+
+impl FromBytes<DeleteOrganizationAction> for DeleteOrganizationAction {
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteOrganizationAction, ProtoConversionError> {
+        let proto: protos::pike_payload::DeleteOrganizationAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get DeleteOrganizationAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for DeleteOrganizationAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from DeleteOrganizationAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::pike_payload::DeleteOrganizationAction> for DeleteOrganizationAction {}
+impl IntoNative<DeleteOrganizationAction> for protos::pike_payload::DeleteOrganizationAction {}
+
+#[derive(Debug)]
+pub enum DeleteOrganizationActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for DeleteOrganizationActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DeleteOrganizationActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            DeleteOrganizationActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a DeleteOrganizationAction
+#[derive(Default, Clone)]
+pub struct DeleteOrganizationActionBuilder {
+    pub org_id: Option<String>,
+}
+
+impl DeleteOrganizationActionBuilder {
+    pub fn new() -> Self {
+        DeleteOrganizationActionBuilder::default()
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> DeleteOrganizationActionBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn build(self) -> Result<DeleteOrganizationAction, DeleteOrganizationActionBuildError> {
+        let org_id = self.org_id.ok_or_else(|| {
+            DeleteOrganizationActionBuildError::MissingField(
+                "'org_id' field is required".to_string(),
+            )
+        })?;
+
+        Ok(DeleteOrganizationAction { org_id })
+    }
+}
+
 /// Native implementation for PikePayload
 #[derive(Debug, Clone, PartialEq)]
 pub struct PikePayload {
@@ -766,6 +1117,8 @@ pub struct PikePayload {
     update_agent: UpdateAgentAction,
     create_organization: CreateOrganizationAction,
     update_organization: UpdateOrganizationAction,
+    delete_agent: DeleteAgentAction,
+    delete_organization: DeleteOrganizationAction,
 }
 
 impl PikePayload {
@@ -788,6 +1141,14 @@ impl PikePayload {
     pub fn update_organization(&self) -> &UpdateOrganizationAction {
         &self.update_organization
     }
+
+    pub fn delete_agent(&self) -> &DeleteAgentAction {
+        &self.delete_agent
+    }
+
+    pub fn delete_organization(&self) -> &DeleteOrganizationAction {
+        &self.delete_organization
+    }
 }
 
 impl FromProto<protos::pike_payload::PikePayload> for PikePayload {
@@ -804,6 +1165,10 @@ impl FromProto<protos::pike_payload::PikePayload> for PikePayload {
             update_organization: UpdateOrganizationAction::from_proto(
                 payload.get_update_organization().clone(),
             )?,
+            delete_agent: DeleteAgentAction::from_proto(payload.get_delete_agent().clone())?,
+            delete_organization: DeleteOrganizationAction::from_proto(
+                payload.get_delete_organization().clone(),
+            )?,
         })
     }
 }
@@ -817,6 +1182,8 @@ impl FromNative<PikePayload> for protos::pike_payload::PikePayload {
         proto_payload.set_update_agent(payload.update_agent().clone().into_proto()?);
         proto_payload.set_create_organization(payload.create_organization().clone().into_proto()?);
         proto_payload.set_update_organization(payload.update_organization().clone().into_proto()?);
+        proto_payload.set_delete_agent(payload.delete_agent().clone().into_proto()?);
+        proto_payload.set_delete_organization(payload.delete_organization().clone().into_proto()?);
 
         Ok(proto_payload)
     }
@@ -884,6 +1251,8 @@ pub struct PikePayloadBuilder {
     pub update_agent: Option<UpdateAgentAction>,
     pub create_organization: Option<CreateOrganizationAction>,
     pub update_organization: Option<UpdateOrganizationAction>,
+    pub delete_agent: Option<DeleteAgentAction>,
+    pub delete_organization: Option<DeleteOrganizationAction>,
 }
 
 impl PikePayloadBuilder {
@@ -922,6 +1291,19 @@ impl PikePayloadBuilder {
         self
     }
 
+    pub fn with_delete_agent(mut self, delete_agent: DeleteAgentAction) -> PikePayloadBuilder {
+        self.delete_agent = Some(delete_agent);
+        self
+    }
+
+    pub fn with_delete_organization(
+        mut self,
+        delete_organization: DeleteOrganizationAction,
+    ) -> PikePayloadBuilder {
+        self.delete_organization = Some(delete_organization);
+        self
+    }
+
     pub fn build(self) -> Result<PikePayload, PikePayloadBuildError> {
         let action = self.action.ok_or_else(|| {
             PikePayloadBuildError::MissingField("'action' field is required".to_string())
@@ -975,12 +1357,38 @@ impl PikePayloadBuilder {
             }
         };
 
+        let delete_agent = {
+            if action == Action::DeleteAgent {
+                self.delete_agent.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_agent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteAgentAction::default()
+            }
+        };
+
+        let delete_organization = {
+            if action == Action::DeleteOrganization {
+                self.delete_organization.ok_or_else(|| {
+                    PikePayloadBuildError::MissingField(
+                        "'delete_organization' field is required".to_string(),
+                    )
+                })?
+            } else {
+                DeleteOrganizationAction::default()
+            }
+        };
+
         Ok(PikePayload {
             action,
             create_agent,
             update_agent,
             create_organization,
             update_organization,
+            delete_agent,
+            delete_organization,
         })
     }
 }
@@ -1114,11 +1522,19 @@ mod tests {
     #[test]
     // check that a create_organization can be converted to bytes and back
     fn check_create_organization_bytes() {
+        let builder = KeyValueEntryBuilder::new();
+        let key_value = builder
+            .with_key("Key".to_string())
+            .with_value("Value".to_string())
+            .build()
+            .unwrap();
+
         let builder = CreateOrganizationActionBuilder::new();
         let original = builder
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_metadata(vec![key_value])
             .build()
             .unwrap();
 
@@ -1130,17 +1546,31 @@ mod tests {
     #[test]
     // check that a update_organization is built correctly
     fn check_update_organization_builder() {
+        let builder = KeyValueEntryBuilder::new();
+        let key_value = builder
+            .with_key("Key".to_string())
+            .with_value("Value".to_string())
+            .build()
+            .unwrap();
+
         let builder = UpdateOrganizationActionBuilder::new();
         let update_organization = builder
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_metadata(vec![key_value.clone()])
+            .with_status(OrganizationStatus::Suspended)
             .build()
             .unwrap();
 
         assert_eq!(update_organization.org_id(), "organization");
         assert_eq!(update_organization.name(), "name");
         assert_eq!(update_organization.address(), "address");
+        assert_eq!(update_organization.metadata(), [key_value]);
+        assert_eq!(
+            update_organization.status(),
+            Some(&OrganizationStatus::Suspended)
+        );
     }
 
     #[test]
@@ -1151,11 +1581,61 @@ mod tests {
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_status(OrganizationStatus::Active)
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+        let org = UpdateOrganizationAction::from_bytes(&bytes).unwrap();
+        assert_eq!(org, original);
+    }
+
+    #[test]
+    // check that a update_organization without a status leaves the status unchanged
+    fn check_update_organization_unchanged_status() {
+        let original = UpdateOrganizationActionBuilder::new()
+            .with_org_id("organization".to_string())
             .build()
             .unwrap();
 
         let bytes = original.clone().into_bytes().unwrap();
         let org = UpdateOrganizationAction::from_bytes(&bytes).unwrap();
+        assert_eq!(org.status(), None);
+    }
+
+    #[test]
+    // check that a delete_agent action is built correctly
+    fn check_delete_agent_builder() {
+        let builder = DeleteAgentActionBuilder::new();
+        let delete_agent = builder
+            .with_org_id("organization".to_string())
+            .with_public_key("public_key".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(delete_agent.org_id(), "organization");
+        assert_eq!(delete_agent.public_key(), "public_key");
+
+        match DeleteAgentActionBuilder::new()
+            .with_org_id("organization".to_string())
+            .build()
+        {
+            Err(DeleteAgentActionBuildError::MissingField(_)) => (),
+            res => panic!("Expected MissingField, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that a delete_organization can be converted to bytes and back
+    fn check_delete_organization_bytes() {
+        let builder = DeleteOrganizationActionBuilder::new();
+        let original = builder
+            .with_org_id("organization".to_string())
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+        let org = DeleteOrganizationAction::from_bytes(&bytes).unwrap();
         assert_eq!(org, original);
     }
 
@@ -1293,6 +1773,39 @@ mod tests {
             CreateOrganizationAction::default()
         );
         assert_eq!(payload.update_organization, action);
+        assert_eq!(payload.delete_agent, DeleteAgentAction::default());
+        assert_eq!(
+            payload.delete_organization,
+            DeleteOrganizationAction::default()
+        );
+    }
+
+    #[test]
+    // check that a pike payload with delete_agent is built correctly
+    fn check_pike_delete_agent_payload() {
+        let builder = DeleteAgentActionBuilder::new();
+        let action = builder
+            .with_org_id("organization".to_string())
+            .with_public_key("public_key".to_string())
+            .build()
+            .unwrap();
+
+        let builder = PikePayloadBuilder::new();
+        let payload = builder
+            .with_action(Action::DeleteAgent)
+            .with_delete_agent(action.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(payload.action, Action::DeleteAgent);
+        assert_eq!(payload.delete_agent, action);
+        assert_eq!(
+            payload.delete_organization,
+            DeleteOrganizationAction::default()
+        );
+
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(PikePayload::from_bytes(&bytes).unwrap(), payload);
     }
 
     #[test]
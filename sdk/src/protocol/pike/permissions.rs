@@ -0,0 +1,90 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks used by smart contracts that authorize their signers as Pike agents.
+//!
+//! An agent may only act while both the agent and its organization are active. Suspending an
+//! organization therefore deactivates all of its agents without changing the agent records.
+
+use crate::protocol::pike::state::{Agent, Organization, OrganizationStatus};
+
+/// Returns whether `agent` may currently act on behalf of `organization`
+pub fn is_agent_active(agent: &Agent, organization: &Organization) -> bool {
+    *agent.active()
+        && agent.org_id() == organization.org_id()
+        && *organization.status() == OrganizationStatus::Active
+}
+
+/// Returns whether `agent` may currently act on behalf of `organization` and holds `role`
+pub fn has_role(agent: &Agent, organization: &Organization, role: &str) -> bool {
+    is_agent_active(agent, organization) && agent.roles().iter().any(|r| r == role)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::pike::state::{AgentBuilder, OrganizationBuilder};
+
+    fn agent(org_id: &str, active: bool) -> Agent {
+        AgentBuilder::new()
+            .with_org_id(org_id.to_string())
+            .with_public_key("public_key".to_string())
+            .with_active(active)
+            .with_roles(vec!["admin".to_string()])
+            .build()
+            .unwrap()
+    }
+
+    fn organization(status: OrganizationStatus) -> Organization {
+        OrganizationBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_name("name".to_string())
+            .with_address("address".to_string())
+            .with_status(status)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    // check that an active agent of an active organization is active
+    fn check_active_agent() {
+        let org = organization(OrganizationStatus::Active);
+
+        assert!(is_agent_active(&agent("organization", true), &org));
+        assert!(!is_agent_active(&agent("organization", false), &org));
+        assert!(!is_agent_active(&agent("other", true), &org));
+    }
+
+    #[test]
+    // check that the agents of a suspended organization are not active
+    fn check_suspended_organization() {
+        let org = organization(OrganizationStatus::Suspended);
+        let agent = agent("organization", true);
+
+        assert!(*agent.active());
+        assert!(!is_agent_active(&agent, &org));
+        assert!(!has_role(&agent, &org, "admin"));
+    }
+
+    #[test]
+    // check that a role is only granted to active agents holding it
+    fn check_has_role() {
+        let org = organization(OrganizationStatus::Active);
+
+        assert!(has_role(&agent("organization", true), &org, "admin"));
+        assert!(!has_role(&agent("organization", true), &org, "reporter"));
+        assert!(!has_role(&agent("organization", false), &org, "admin"));
+    }
+}
@@ -461,12 +461,45 @@ impl AgentListBuilder {
     }
 }
 
+/// Native implementation of Organization_Status enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrganizationStatus {
+    Active,
+    Suspended,
+}
+
+impl FromProto<protos::pike_state::Organization_Status> for OrganizationStatus {
+    fn from_proto(
+        status: protos::pike_state::Organization_Status,
+    ) -> Result<Self, ProtoConversionError> {
+        match status {
+            protos::pike_state::Organization_Status::ACTIVE => Ok(OrganizationStatus::Active),
+            protos::pike_state::Organization_Status::SUSPENDED => Ok(OrganizationStatus::Suspended),
+        }
+    }
+}
+
+impl FromNative<OrganizationStatus> for protos::pike_state::Organization_Status {
+    fn from_native(status: OrganizationStatus) -> Result<Self, ProtoConversionError> {
+        match status {
+            OrganizationStatus::Active => Ok(protos::pike_state::Organization_Status::ACTIVE),
+            OrganizationStatus::Suspended => Ok(protos::pike_state::Organization_Status::SUSPENDED),
+        }
+    }
+}
+
+impl IntoProto<protos::pike_state::Organization_Status> for OrganizationStatus {}
+impl IntoNative<OrganizationStatus> for protos::pike_state::Organization_Status {}
+
 /// Native implementation for Organization
 #[derive(Debug, Clone, PartialEq)]
 pub struct Organization {
     org_id: String,
     name: String,
     address: String,
+    metadata: Vec<KeyValueEntry>,
+    status: OrganizationStatus,
+    agent_count: u32,
 }
 
 impl Organization {
@@ -481,6 +514,19 @@ impl Organization {
     pub fn address(&self) -> &str {
         &self.address
     }
+
+    pub fn metadata(&self) -> &[KeyValueEntry] {
+        &self.metadata
+    }
+
+    pub fn status(&self) -> &OrganizationStatus {
+        &self.status
+    }
+
+    /// Returns the number of agents that belong to the organization
+    pub fn agent_count(&self) -> &u32 {
+        &self.agent_count
+    }
 }
 
 impl FromProto<protos::pike_state::Organization> for Organization {
@@ -489,6 +535,14 @@ impl FromProto<protos::pike_state::Organization> for Organization {
             org_id: org.get_org_id().to_string(),
             name: org.get_name().to_string(),
             address: org.get_address().to_string(),
+            metadata: org
+                .get_metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::from_proto)
+                .collect::<Result<Vec<KeyValueEntry>, ProtoConversionError>>()?,
+            status: OrganizationStatus::from_proto(org.get_status())?,
+            agent_count: org.get_agent_count(),
         })
     }
 }
@@ -500,6 +554,16 @@ impl FromNative<Organization> for protos::pike_state::Organization {
         org_proto.set_org_id(org.org_id().to_string());
         org_proto.set_name(org.name().to_string());
         org_proto.set_address(org.address().to_string());
+        org_proto.set_metadata(RepeatedField::from_vec(
+            org.metadata()
+                .to_vec()
+                .into_iter()
+                .map(KeyValueEntry::into_proto)
+                .collect::<Result<Vec<protos::pike_state::KeyValueEntry>, ProtoConversionError>>(
+                )?,
+        ));
+        org_proto.set_status(org.status().clone().into_proto()?);
+        org_proto.set_agent_count(*org.agent_count());
 
         Ok(org_proto)
     }
@@ -565,6 +629,9 @@ pub struct OrganizationBuilder {
     pub org_id: Option<String>,
     pub name: Option<String>,
     pub address: Option<String>,
+    pub metadata: Vec<KeyValueEntry>,
+    pub status: Option<OrganizationStatus>,
+    pub agent_count: u32,
 }
 
 impl OrganizationBuilder {
@@ -587,6 +654,21 @@ impl OrganizationBuilder {
         self
     }
 
+    pub fn with_metadata(mut self, metadata: Vec<KeyValueEntry>) -> OrganizationBuilder {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_status(mut self, status: OrganizationStatus) -> OrganizationBuilder {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_agent_count(mut self, agent_count: u32) -> OrganizationBuilder {
+        self.agent_count = agent_count;
+        self
+    }
+
     pub fn build(self) -> Result<Organization, OrganizationBuildError> {
         let org_id = self.org_id.ok_or_else(|| {
             OrganizationBuildError::MissingField("'org_id' field is required".to_string())
@@ -600,10 +682,17 @@ impl OrganizationBuilder {
             OrganizationBuildError::MissingField("'address' field is required".to_string())
         })?;
 
+        let metadata = self.metadata;
+        let status = self.status.unwrap_or(OrganizationStatus::Active);
+        let agent_count = self.agent_count;
+
         Ok(Organization {
             org_id,
             name,
             address,
+            metadata,
+            status,
+            agent_count,
         })
     }
 }
@@ -882,27 +971,64 @@ mod tests {
     #[test]
     // check that a Organization is built correctly
     fn check_organization_builder() {
+        let builder = KeyValueEntryBuilder::new();
+        let key_value = builder
+            .with_key("Key".to_string())
+            .with_value("Value".to_string())
+            .build()
+            .unwrap();
+
         let builder = OrganizationBuilder::new();
         let organization = builder
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_metadata(vec![key_value.clone()])
+            .with_status(OrganizationStatus::Suspended)
+            .with_agent_count(2)
             .build()
             .unwrap();
 
         assert_eq!(organization.org_id(), "organization");
         assert_eq!(organization.name(), "name");
         assert_eq!(organization.address(), "address");
+        assert_eq!(organization.metadata(), [key_value]);
+        assert_eq!(organization.status(), &OrganizationStatus::Suspended);
+        assert_eq!(organization.agent_count(), &2);
+    }
+
+    #[test]
+    // check that a Organization defaults to being active
+    fn check_organization_builder_default_status() {
+        let organization = OrganizationBuilder::new()
+            .with_org_id("organization".to_string())
+            .with_name("name".to_string())
+            .with_address("address".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(organization.status(), &OrganizationStatus::Active);
+        assert!(organization.metadata().is_empty());
     }
 
     #[test]
     // check that a Organization can be converted to bytes and back
     fn check_organization_bytes() {
+        let builder = KeyValueEntryBuilder::new();
+        let key_value = builder
+            .with_key("Key".to_string())
+            .with_value("Value".to_string())
+            .build()
+            .unwrap();
+
         let builder = OrganizationBuilder::new();
         let original = builder
             .with_org_id("organization".to_string())
             .with_name("name".to_string())
             .with_address("address".to_string())
+            .with_metadata(vec![key_value])
+            .with_status(OrganizationStatus::Suspended)
+            .with_agent_count(2)
             .build()
             .unwrap();
 
@@ -17,6 +17,7 @@ use protobuf::RepeatedField;
 
 use std::error::Error as StdError;
 
+use crate::decimal::{Decimal, DecimalError};
 use crate::protos;
 use crate::protos::{
     FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
@@ -31,6 +32,7 @@ pub enum DataType {
     String,
     Enum,
     Struct,
+    Location,
 }
 
 impl FromProto<protos::schema_state::PropertyDefinition_DataType> for DataType {
@@ -44,6 +46,7 @@ impl FromProto<protos::schema_state::PropertyDefinition_DataType> for DataType {
             protos::schema_state::PropertyDefinition_DataType::STRING => Ok(DataType::String),
             protos::schema_state::PropertyDefinition_DataType::ENUM => Ok(DataType::Enum),
             protos::schema_state::PropertyDefinition_DataType::STRUCT => Ok(DataType::Struct),
+            protos::schema_state::PropertyDefinition_DataType::LOCATION => Ok(DataType::Location),
             protos::schema_state::PropertyDefinition_DataType::UNSET_DATA_TYPE => {
                 Err(ProtoConversionError::InvalidTypeError(
                     "Cannot convert PropertyDefinition_DataType with type unset.".to_string(),
@@ -62,6 +65,7 @@ impl FromNative<DataType> for protos::schema_state::PropertyDefinition_DataType
             DataType::String => Ok(protos::schema_state::PropertyDefinition_DataType::STRING),
             DataType::Enum => Ok(protos::schema_state::PropertyDefinition_DataType::ENUM),
             DataType::Struct => Ok(protos::schema_state::PropertyDefinition_DataType::STRUCT),
+            DataType::Location => Ok(protos::schema_state::PropertyDefinition_DataType::LOCATION),
         }
     }
 }
@@ -507,6 +511,135 @@ impl SchemaBuilder {
     }
 }
 
+/// Native implementation of SchemaList
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaList {
+    schemas: Vec<Schema>,
+}
+
+impl SchemaList {
+    pub fn schemas(&self) -> &[Schema] {
+        &self.schemas
+    }
+}
+
+impl FromProto<protos::schema_state::SchemaList> for SchemaList {
+    fn from_proto(
+        schema_list: protos::schema_state::SchemaList,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(SchemaList {
+            schemas: schema_list
+                .get_schemas()
+                .to_vec()
+                .into_iter()
+                .map(Schema::from_proto)
+                .collect::<Result<Vec<Schema>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<SchemaList> for protos::schema_state::SchemaList {
+    fn from_native(schema_list: SchemaList) -> Result<Self, ProtoConversionError> {
+        let mut schema_list_proto = protos::schema_state::SchemaList::new();
+
+        schema_list_proto.set_schemas(RepeatedField::from_vec(
+            schema_list
+                .schemas()
+                .to_vec()
+                .into_iter()
+                .map(Schema::into_proto)
+                .collect::<Result<Vec<protos::schema_state::Schema>, ProtoConversionError>>()?,
+        ));
+
+        Ok(schema_list_proto)
+    }
+}
+
+impl FromBytes<SchemaList> for SchemaList {
+    fn from_bytes(bytes: &[u8]) -> Result<SchemaList, ProtoConversionError> {
+        let proto: protos::schema_state::SchemaList =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get SchemaList from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for SchemaList {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from SchemaList".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::schema_state::SchemaList> for SchemaList {}
+impl IntoNative<SchemaList> for protos::schema_state::SchemaList {}
+
+#[derive(Debug)]
+pub enum SchemaListBuildError {
+    MissingField(String),
+}
+
+impl StdError for SchemaListBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            SchemaListBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            SchemaListBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaListBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SchemaListBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a SchemaList
+#[derive(Default, Clone)]
+pub struct SchemaListBuilder {
+    pub schemas: Vec<Schema>,
+}
+
+impl SchemaListBuilder {
+    pub fn new() -> Self {
+        SchemaListBuilder::default()
+    }
+
+    pub fn with_schemas(mut self, schemas: Vec<Schema>) -> SchemaListBuilder {
+        self.schemas = schemas;
+        self
+    }
+
+    pub fn build(self) -> Result<SchemaList, SchemaListBuildError> {
+        let schemas = {
+            if self.schemas.is_empty() {
+                return Err(SchemaListBuildError::MissingField(
+                    "'schemas' cannot be empty".to_string(),
+                ));
+            } else {
+                self.schemas
+            }
+        };
+
+        Ok(SchemaList { schemas })
+    }
+}
+
 /// Native implementation of PropertyValue
 #[derive(Debug, Clone, PartialEq)]
 pub struct PropertyValue {
@@ -518,6 +651,7 @@ pub struct PropertyValue {
     string_value: String,
     enum_value: u32,
     struct_values: Vec<PropertyValue>,
+    location_value: LatLong,
 }
 
 impl PropertyValue {
@@ -552,6 +686,16 @@ impl PropertyValue {
     pub fn struct_values(&self) -> &[PropertyValue] {
         &self.struct_values
     }
+
+    pub fn location_value(&self) -> &LatLong {
+        &self.location_value
+    }
+
+    /// Returns the number value as a decimal, scaled by the `number_exponent` of the
+    /// property's definition
+    pub fn decimal_value(&self, definition: &PropertyDefinition) -> Decimal {
+        Decimal::new(self.number_value, *definition.number_exponent())
+    }
 }
 
 impl FromProto<protos::schema_state::PropertyValue> for PropertyValue {
@@ -572,6 +716,7 @@ impl FromProto<protos::schema_state::PropertyValue> for PropertyValue {
                 .into_iter()
                 .map(PropertyValue::from_proto)
                 .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            location_value: LatLong::from_proto(property_value.get_location_value().clone())?,
         })
     }
 }
@@ -595,6 +740,8 @@ impl FromNative<PropertyValue> for protos::schema_state::PropertyValue {
                 .collect::<Result<Vec<protos::schema_state::PropertyValue>, ProtoConversionError>>(
                 )?,
         ));
+        proto_property_value
+            .set_location_value(property_value.location_value().clone().into_proto()?);
         Ok(proto_property_value)
     }
 }
@@ -664,6 +811,7 @@ pub struct PropertyValueBuilder {
     pub string_value: Option<String>,
     pub enum_value: Option<u32>,
     pub struct_values: Vec<PropertyValue>,
+    pub location_value: Option<LatLong>,
 }
 
 impl PropertyValueBuilder {
@@ -696,6 +844,19 @@ impl PropertyValueBuilder {
         self
     }
 
+    /// Sets the number value from a decimal string such as "12.345", rescaled to the
+    /// `number_exponent` of the property's definition
+    pub fn with_decimal_value(
+        self,
+        value: &str,
+        definition: &PropertyDefinition,
+    ) -> Result<PropertyValueBuilder, DecimalError> {
+        let decimal = value
+            .parse::<Decimal>()?
+            .rescale(*definition.number_exponent())?;
+        Ok(self.with_number_value(decimal.mantissa()))
+    }
+
     pub fn with_enum_value(mut self, enum_value: u32) -> PropertyValueBuilder {
         self.enum_value = Some(enum_value);
         self
@@ -711,6 +872,11 @@ impl PropertyValueBuilder {
         self
     }
 
+    pub fn with_location_value(mut self, location_value: LatLong) -> PropertyValueBuilder {
+        self.location_value = Some(location_value);
+        self
+    }
+
     pub fn build(self) -> Result<PropertyValue, PropertyValueBuildError> {
         let name = self.name.ok_or_else(|| {
             PropertyValueBuildError::MissingField("'name' field is required".to_string())
@@ -794,6 +960,18 @@ impl PropertyValueBuilder {
             }
         };
 
+        let location_value = {
+            if data_type == DataType::Location {
+                self.location_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'location_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                LatLong::default()
+            }
+        };
+
         Ok(PropertyValue {
             name,
             data_type,
@@ -803,6 +981,132 @@ impl PropertyValueBuilder {
             string_value,
             enum_value,
             struct_values,
+            location_value,
+        })
+    }
+}
+
+/// Native implementation of LatLong; coordinates are in millionths of a degree
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LatLong {
+    latitude: i64,
+    longitude: i64,
+}
+
+impl LatLong {
+    pub fn latitude(&self) -> &i64 {
+        &self.latitude
+    }
+
+    pub fn longitude(&self) -> &i64 {
+        &self.longitude
+    }
+}
+
+impl FromProto<protos::schema_state::LatLong> for LatLong {
+    fn from_proto(lat_long: protos::schema_state::LatLong) -> Result<Self, ProtoConversionError> {
+        Ok(LatLong {
+            latitude: lat_long.get_latitude(),
+            longitude: lat_long.get_longitude(),
+        })
+    }
+}
+
+impl FromNative<LatLong> for protos::schema_state::LatLong {
+    fn from_native(lat_long: LatLong) -> Result<Self, ProtoConversionError> {
+        let mut proto_lat_long = protos::schema_state::LatLong::new();
+        proto_lat_long.set_latitude(*lat_long.latitude());
+        proto_lat_long.set_longitude(*lat_long.longitude());
+        Ok(proto_lat_long)
+    }
+}
+
+impl FromBytes<LatLong> for LatLong {
+    fn from_bytes(bytes: &[u8]) -> Result<LatLong, ProtoConversionError> {
+        let proto: protos::schema_state::LatLong =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get LatLong from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for LatLong {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError("Unable to get bytes from LatLong".to_string())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::schema_state::LatLong> for LatLong {}
+impl IntoNative<LatLong> for protos::schema_state::LatLong {}
+
+#[derive(Debug)]
+pub enum LatLongBuildError {
+    MissingField(String),
+}
+
+impl StdError for LatLongBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            LatLongBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            LatLongBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LatLongBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            LatLongBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a LatLong
+#[derive(Default, Clone)]
+pub struct LatLongBuilder {
+    pub latitude: Option<i64>,
+    pub longitude: Option<i64>,
+}
+
+impl LatLongBuilder {
+    pub fn new() -> Self {
+        LatLongBuilder::default()
+    }
+
+    pub fn with_latitude(mut self, latitude: i64) -> LatLongBuilder {
+        self.latitude = Some(latitude);
+        self
+    }
+
+    pub fn with_longitude(mut self, longitude: i64) -> LatLongBuilder {
+        self.longitude = Some(longitude);
+        self
+    }
+
+    pub fn build(self) -> Result<LatLong, LatLongBuildError> {
+        let latitude = self.latitude.ok_or_else(|| {
+            LatLongBuildError::MissingField("'latitude' field is required".to_string())
+        })?;
+
+        let longitude = self.longitude.ok_or_else(|| {
+            LatLongBuildError::MissingField("'longitude' field is required".to_string())
+        })?;
+
+        Ok(LatLong {
+            latitude,
+            longitude,
         })
     }
 }
@@ -962,6 +1266,36 @@ mod tests {
         assert_eq!(schema, original);
     }
 
+    #[test]
+    // check that a SchemaList can be converted to bytes and back
+    fn check_schema_list_bytes() {
+        let builder = PropertyDefinitionBuilder::new();
+        let property_definition = builder
+            .with_name("TEST".to_string())
+            .with_data_type(DataType::String)
+            .with_description("Optional".to_string())
+            .build()
+            .unwrap();
+
+        let builder = SchemaBuilder::new();
+        let schema = builder
+            .with_name("TestSchema".to_string())
+            .with_description("Test Schema".to_string())
+            .with_owner("owner".to_string())
+            .with_properties(vec![property_definition.clone()])
+            .build()
+            .unwrap();
+
+        let builder = SchemaListBuilder::new();
+        let original = builder.with_schemas(vec![schema.clone()]).build().unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let schema_list = SchemaList::from_bytes(&bytes).unwrap();
+        assert_eq!(schema_list, original);
+        assert_eq!(schema_list.schemas(), &[schema]);
+    }
+
     #[test]
     // check that a property value with a string data type is built correctly
     fn check_property_value_builder_string() {
@@ -1002,6 +1336,67 @@ mod tests {
         assert_eq!(property_value.struct_values, vec![string_value]);
     }
 
+    #[test]
+    // check that a number property value is built from a decimal string
+    fn check_property_value_builder_decimal() {
+        let definition = PropertyDefinitionBuilder::new()
+            .with_name("TEST".to_string())
+            .with_data_type(DataType::Number)
+            .with_description("Optional".to_string())
+            .with_number_exponent(-4)
+            .build()
+            .unwrap();
+
+        let property_value = PropertyValueBuilder::new()
+            .with_name("TEST".to_string())
+            .with_data_type(DataType::Number)
+            .with_decimal_value("12.345", &definition)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(property_value.number_value, 123450);
+        assert_eq!(
+            property_value.decimal_value(&definition).to_string(),
+            "12.3450"
+        );
+
+        match PropertyValueBuilder::new().with_decimal_value("1.23456", &definition) {
+            Err(DecimalError::PrecisionLoss(_)) => (),
+            Err(err) => panic!("Expected PrecisionLoss, got {}", err),
+            Ok(_) => panic!("Expected PrecisionLoss"),
+        }
+    }
+
+    #[test]
+    // check that a location property value requires a location
+    fn check_property_value_builder_location() {
+        let location = LatLongBuilder::new()
+            .with_latitude(44977753)
+            .with_longitude(-93265015)
+            .build()
+            .unwrap();
+
+        let property_value = PropertyValueBuilder::new()
+            .with_name("TEST_LOCATION".to_string())
+            .with_data_type(DataType::Location)
+            .with_location_value(location.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(property_value.data_type, DataType::Location);
+        assert_eq!(property_value.location_value, location);
+
+        assert!(PropertyValueBuilder::new()
+            .with_name("TEST_LOCATION".to_string())
+            .with_data_type(DataType::Location)
+            .build()
+            .is_err());
+
+        let bytes = property_value.clone().into_bytes().unwrap();
+        assert_eq!(PropertyValue::from_bytes(&bytes).unwrap(), property_value);
+    }
+
     #[test]
     // check that a property value can be converted to bytes and back
     fn check_property_value_bytes() {
@@ -0,0 +1,442 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::error::Error as StdError;
+
+use crate::protocol::schema::state::{DataType, PropertyDefinition, PropertyValue, Schema};
+
+/// A single way in which a property value disagrees with its definition. `path` names the
+/// property, with the properties of a STRUCT joined to their parent by a `.`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValueViolation {
+    /// A required property has no value
+    MissingRequired { path: String },
+    /// A value was given for a property the schema does not define
+    UnknownProperty { path: String },
+    /// More than one value was given for the same property
+    DuplicateProperty { path: String },
+    /// The value's data type differs from the definition's
+    TypeMismatch {
+        path: String,
+        expected: DataType,
+        actual: DataType,
+    },
+    /// The enum value is not an index into the definition's `enum_options`
+    EnumOutOfBounds {
+        path: String,
+        index: u32,
+        options: usize,
+    },
+}
+
+impl PropertyValueViolation {
+    pub fn path(&self) -> &str {
+        match self {
+            PropertyValueViolation::MissingRequired { path }
+            | PropertyValueViolation::UnknownProperty { path }
+            | PropertyValueViolation::DuplicateProperty { path }
+            | PropertyValueViolation::TypeMismatch { path, .. }
+            | PropertyValueViolation::EnumOutOfBounds { path, .. } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyValueViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PropertyValueViolation::MissingRequired { path } => {
+                write!(f, "{}: required property is missing", path)
+            }
+            PropertyValueViolation::UnknownProperty { path } => {
+                write!(f, "{}: property is not defined by the schema", path)
+            }
+            PropertyValueViolation::DuplicateProperty { path } => {
+                write!(f, "{}: property has more than one value", path)
+            }
+            PropertyValueViolation::TypeMismatch {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: expected {:?} but found {:?}",
+                path, expected, actual
+            ),
+            PropertyValueViolation::EnumOutOfBounds {
+                path,
+                index,
+                options,
+            } => write!(
+                f,
+                "{}: enum value {} is out of bounds for {} options",
+                path, index, options
+            ),
+        }
+    }
+}
+
+/// Returned when property values do not agree with a schema; holds every violation found
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaValidationError {
+    violations: Vec<PropertyValueViolation>,
+}
+
+impl SchemaValidationError {
+    pub fn violations(&self) -> &[PropertyValueViolation] {
+        &self.violations
+    }
+}
+
+impl StdError for SchemaValidationError {}
+
+impl std::fmt::Display for SchemaValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let violations = self
+            .violations
+            .iter()
+            .map(PropertyValueViolation::to_string)
+            .collect::<Vec<_>>();
+        write!(f, "Invalid property values: {}", violations.join("; "))
+    }
+}
+
+/// Returned when a list of property definitions cannot make up a schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyDefinitionError {
+    /// The list has no definitions
+    NoProperties,
+    /// A definition has an empty name
+    EmptyName,
+    /// More than one definition in the same list has this name
+    DuplicateName(String),
+}
+
+impl StdError for PropertyDefinitionError {}
+
+impl std::fmt::Display for PropertyDefinitionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PropertyDefinitionError::NoProperties => {
+                write!(f, "Schema must have at least one property")
+            }
+            PropertyDefinitionError::EmptyName => {
+                write!(f, "Property name cannot be an empty string")
+            }
+            PropertyDefinitionError::DuplicateName(name) => {
+                write!(f, "Duplicate property name: {}", name)
+            }
+        }
+    }
+}
+
+/// Checks that a list of property definitions can make up a schema: it must not be empty and
+/// every name must be set and unique among its siblings, including within STRUCT definitions
+pub fn validate_property_definitions(
+    definitions: &[PropertyDefinition],
+) -> Result<(), PropertyDefinitionError> {
+    if definitions.is_empty() {
+        return Err(PropertyDefinitionError::NoProperties);
+    }
+
+    let mut names = HashSet::new();
+    for definition in definitions {
+        if definition.name().is_empty() {
+            return Err(PropertyDefinitionError::EmptyName);
+        }
+        if !names.insert(definition.name()) {
+            return Err(PropertyDefinitionError::DuplicateName(
+                definition.name().to_string(),
+            ));
+        }
+        if !definition.struct_properties().is_empty() {
+            validate_property_definitions(definition.struct_properties())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a set of property values against the property definitions of a schema
+pub fn validate_property_values(
+    schema: &Schema,
+    values: &[PropertyValue],
+) -> Result<(), SchemaValidationError> {
+    validate_against_definitions(schema.properties(), values)
+}
+
+/// Checks a set of property values against a list of property definitions, such as the
+/// `struct_properties` of a STRUCT definition
+pub fn validate_against_definitions(
+    definitions: &[PropertyDefinition],
+    values: &[PropertyValue],
+) -> Result<(), SchemaValidationError> {
+    let mut violations = vec![];
+    check_values(definitions, values, "", &mut violations);
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(SchemaValidationError { violations })
+    }
+}
+
+fn check_values(
+    definitions: &[PropertyDefinition],
+    values: &[PropertyValue],
+    parent_path: &str,
+    violations: &mut Vec<PropertyValueViolation>,
+) {
+    let path_of = |name: &str| {
+        if parent_path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", parent_path, name)
+        }
+    };
+
+    let mut seen = HashSet::new();
+    for value in values {
+        let path = path_of(value.name());
+        if !seen.insert(value.name()) {
+            violations.push(PropertyValueViolation::DuplicateProperty { path });
+            continue;
+        }
+
+        match definitions.iter().find(|def| def.name() == value.name()) {
+            Some(definition) => check_value(definition, value, path, violations),
+            None => violations.push(PropertyValueViolation::UnknownProperty { path }),
+        }
+    }
+
+    for definition in definitions {
+        if *definition.required() && !seen.contains(definition.name()) {
+            violations.push(PropertyValueViolation::MissingRequired {
+                path: path_of(definition.name()),
+            });
+        }
+    }
+}
+
+fn check_value(
+    definition: &PropertyDefinition,
+    value: &PropertyValue,
+    path: String,
+    violations: &mut Vec<PropertyValueViolation>,
+) {
+    if value.data_type() != definition.data_type() {
+        violations.push(PropertyValueViolation::TypeMismatch {
+            path,
+            expected: definition.data_type().clone(),
+            actual: value.data_type().clone(),
+        });
+        return;
+    }
+
+    match definition.data_type() {
+        DataType::Enum => {
+            let index = *value.enum_value();
+            if index as usize >= definition.enum_options().len() {
+                violations.push(PropertyValueViolation::EnumOutOfBounds {
+                    path,
+                    index,
+                    options: definition.enum_options().len(),
+                });
+            }
+        }
+        DataType::Struct => check_values(
+            definition.struct_properties(),
+            value.struct_values(),
+            &path,
+            violations,
+        ),
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::schema::state::{
+        PropertyDefinitionBuilder, PropertyValueBuilder, SchemaBuilder,
+    };
+
+    fn string_definition(name: &str, required: bool) -> PropertyDefinition {
+        PropertyDefinitionBuilder::new()
+            .with_name(name.to_string())
+            .with_data_type(DataType::String)
+            .with_description("".to_string())
+            .with_required(required)
+            .build()
+            .unwrap()
+    }
+
+    fn string_value(name: &str) -> PropertyValue {
+        PropertyValueBuilder::new()
+            .with_name(name.to_string())
+            .with_data_type(DataType::String)
+            .with_string_value("value".to_string())
+            .build()
+            .unwrap()
+    }
+
+    fn enum_value(name: &str, index: u32) -> PropertyValue {
+        PropertyValueBuilder::new()
+            .with_name(name.to_string())
+            .with_data_type(DataType::Enum)
+            .with_enum_value(index)
+            .build()
+            .unwrap()
+    }
+
+    fn test_schema() -> Schema {
+        let color = PropertyDefinitionBuilder::new()
+            .with_name("color".to_string())
+            .with_data_type(DataType::Enum)
+            .with_description("".to_string())
+            .with_enum_options(vec!["red".to_string(), "green".to_string()])
+            .build()
+            .unwrap();
+        let dimensions = PropertyDefinitionBuilder::new()
+            .with_name("dimensions".to_string())
+            .with_data_type(DataType::Struct)
+            .with_description("".to_string())
+            .with_struct_properties(vec![
+                string_definition("unit", true),
+                string_definition("note", false),
+            ])
+            .build()
+            .unwrap();
+
+        SchemaBuilder::new()
+            .with_name("product".to_string())
+            .with_owner("org".to_string())
+            .with_properties(vec![string_definition("name", true), color, dimensions])
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    // check that values agreeing with the schema are accepted
+    fn check_valid_values() {
+        let dimensions = PropertyValueBuilder::new()
+            .with_name("dimensions".to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_values(vec![string_value("unit")])
+            .build()
+            .unwrap();
+
+        assert!(validate_property_values(
+            &test_schema(),
+            &[string_value("name"), enum_value("color", 1), dimensions]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    // check that every violation is reported with its property path
+    fn check_invalid_values() {
+        let dimensions = PropertyValueBuilder::new()
+            .with_name("dimensions".to_string())
+            .with_data_type(DataType::Struct)
+            .with_struct_values(vec![enum_value("note", 0), string_value("depth")])
+            .build()
+            .unwrap();
+
+        let err = validate_property_values(
+            &test_schema(),
+            &[
+                enum_value("color", 2),
+                enum_value("color", 0),
+                dimensions,
+                string_value("weight"),
+            ],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.violations(),
+            &[
+                PropertyValueViolation::EnumOutOfBounds {
+                    path: "color".to_string(),
+                    index: 2,
+                    options: 2,
+                },
+                PropertyValueViolation::DuplicateProperty {
+                    path: "color".to_string(),
+                },
+                PropertyValueViolation::TypeMismatch {
+                    path: "dimensions.note".to_string(),
+                    expected: DataType::String,
+                    actual: DataType::Enum,
+                },
+                PropertyValueViolation::UnknownProperty {
+                    path: "dimensions.depth".to_string(),
+                },
+                PropertyValueViolation::MissingRequired {
+                    path: "dimensions.unit".to_string(),
+                },
+                PropertyValueViolation::UnknownProperty {
+                    path: "weight".to_string(),
+                },
+                PropertyValueViolation::MissingRequired {
+                    path: "name".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    // check that the definitions of a valid schema are accepted
+    fn check_valid_definitions() {
+        assert!(validate_property_definitions(test_schema().properties()).is_ok());
+    }
+
+    #[test]
+    // check that empty lists, empty names and duplicate names are rejected, including within
+    // a STRUCT definition
+    fn check_invalid_definitions() {
+        assert_eq!(
+            validate_property_definitions(&[]),
+            Err(PropertyDefinitionError::NoProperties)
+        );
+        assert_eq!(
+            validate_property_definitions(&[string_definition("", false)]),
+            Err(PropertyDefinitionError::EmptyName)
+        );
+        assert_eq!(
+            validate_property_definitions(&[
+                string_definition("name", false),
+                string_definition("name", true),
+            ]),
+            Err(PropertyDefinitionError::DuplicateName("name".to_string()))
+        );
+
+        let dimensions = PropertyDefinitionBuilder::new()
+            .with_name("dimensions".to_string())
+            .with_data_type(DataType::Struct)
+            .with_description("".to_string())
+            .with_struct_properties(vec![
+                string_definition("unit", true),
+                string_definition("unit", false),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            validate_property_definitions(&[string_definition("unit", true), dimensions]),
+            Err(PropertyDefinitionError::DuplicateName("unit".to_string()))
+        );
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf::Message;
+use protobuf::RepeatedField;
+
+use std::error::Error as StdError;
+
+use crate::protos;
+use crate::protos::{
+    FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
+};
+
+/// Native implementation of TrackAndTraceAgent
+#[derive(Debug, Clone, PartialEq)]
+pub struct Agent {
+    public_key: String,
+    name: String,
+    timestamp: u64,
+}
+
+impl Agent {
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+}
+
+impl FromProto<protos::track_and_trace_agent::TrackAndTraceAgent> for Agent {
+    fn from_proto(
+        agent: protos::track_and_trace_agent::TrackAndTraceAgent,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Agent {
+            public_key: agent.get_public_key().to_string(),
+            name: agent.get_name().to_string(),
+            timestamp: agent.get_timestamp(),
+        })
+    }
+}
+
+impl FromNative<Agent> for protos::track_and_trace_agent::TrackAndTraceAgent {
+    fn from_native(agent: Agent) -> Result<Self, ProtoConversionError> {
+        let mut agent_proto = protos::track_and_trace_agent::TrackAndTraceAgent::new();
+
+        agent_proto.set_public_key(agent.public_key().to_string());
+        agent_proto.set_name(agent.name().to_string());
+        agent_proto.set_timestamp(*agent.timestamp());
+
+        Ok(agent_proto)
+    }
+}
+
+impl FromBytes<Agent> for Agent {
+    fn from_bytes(bytes: &[u8]) -> Result<Agent, ProtoConversionError> {
+        let proto: protos::track_and_trace_agent::TrackAndTraceAgent =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get Agent from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Agent {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError("Unable to get bytes from Agent".to_string())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_agent::TrackAndTraceAgent> for Agent {}
+impl IntoNative<Agent> for protos::track_and_trace_agent::TrackAndTraceAgent {}
+
+#[derive(Debug)]
+pub enum AgentBuildError {
+    MissingField(String),
+}
+
+impl StdError for AgentBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            AgentBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            AgentBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AgentBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AgentBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Agent
+#[derive(Default, Clone)]
+pub struct AgentBuilder {
+    pub public_key: Option<String>,
+    pub name: Option<String>,
+    pub timestamp: Option<u64>,
+}
+
+impl AgentBuilder {
+    pub fn new() -> Self {
+        AgentBuilder::default()
+    }
+
+    pub fn with_public_key(mut self, public_key: String) -> AgentBuilder {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    pub fn with_name(mut self, name: String) -> AgentBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> AgentBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn build(self) -> Result<Agent, AgentBuildError> {
+        let public_key = self.public_key.ok_or_else(|| {
+            AgentBuildError::MissingField("'public_key' field is required".to_string())
+        })?;
+
+        let name = self
+            .name
+            .ok_or_else(|| AgentBuildError::MissingField("'name' field is required".to_string()))?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            AgentBuildError::MissingField("'timestamp' field is required".to_string())
+        })?;
+
+        Ok(Agent {
+            public_key,
+            name,
+            timestamp,
+        })
+    }
+}
+
+/// Native implementation of TrackAndTraceAgentContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentContainer {
+    entries: Vec<Agent>,
+}
+
+impl AgentContainer {
+    pub fn entries(&self) -> &[Agent] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_agent::TrackAndTraceAgentContainer> for AgentContainer {
+    fn from_proto(
+        agent_container: protos::track_and_trace_agent::TrackAndTraceAgentContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(AgentContainer {
+            entries: agent_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(Agent::from_proto)
+                .collect::<Result<Vec<Agent>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<AgentContainer> for protos::track_and_trace_agent::TrackAndTraceAgentContainer {
+    fn from_native(agent_container: AgentContainer) -> Result<Self, ProtoConversionError> {
+        let mut agent_container_proto =
+            protos::track_and_trace_agent::TrackAndTraceAgentContainer::new();
+
+        agent_container_proto.set_entries(RepeatedField::from_vec(
+            agent_container
+                .entries()
+                .to_vec()
+                .into_iter()
+                .map(Agent::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_agent::TrackAndTraceAgent>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(agent_container_proto)
+    }
+}
+
+impl FromBytes<AgentContainer> for AgentContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<AgentContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_agent::TrackAndTraceAgentContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get AgentContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for AgentContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from AgentContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_agent::TrackAndTraceAgentContainer> for AgentContainer {}
+impl IntoNative<AgentContainer> for protos::track_and_trace_agent::TrackAndTraceAgentContainer {}
+
+#[derive(Debug)]
+pub enum AgentContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for AgentContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            AgentContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            AgentContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AgentContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AgentContainerBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a AgentContainer
+#[derive(Default, Clone)]
+pub struct AgentContainerBuilder {
+    pub entries: Vec<Agent>,
+}
+
+impl AgentContainerBuilder {
+    pub fn new() -> Self {
+        AgentContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<Agent>) -> AgentContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<AgentContainer, AgentContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(AgentContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(AgentContainer { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that an agent is built correctly
+    fn check_agent_builder() {
+        let agent = AgentBuilder::new()
+            .with_public_key("public_key".to_string())
+            .with_name("name".to_string())
+            .with_timestamp(1)
+            .build()
+            .unwrap();
+
+        assert_eq!(agent.public_key, "public_key");
+        assert_eq!(agent.name, "name");
+        assert_eq!(agent.timestamp, 1);
+    }
+
+    #[test]
+    // check that an agent container can be converted to bytes and back
+    fn check_agent_container_bytes() {
+        let agent = AgentBuilder::new()
+            .with_public_key("public_key".to_string())
+            .with_name("name".to_string())
+            .with_timestamp(1)
+            .build()
+            .unwrap();
+        let original = AgentContainerBuilder::new()
+            .with_entries(vec![agent])
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let container = AgentContainer::from_bytes(&bytes).unwrap();
+        assert_eq!(container, original);
+    }
+}
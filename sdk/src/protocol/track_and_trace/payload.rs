@@ -0,0 +1,2777 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf::Message;
+use protobuf::RepeatedField;
+
+use std::error::Error as StdError;
+
+use crate::protocol::track_and_trace::property::{PropertySchema, PropertyValue};
+use crate::protocol::track_and_trace::proposal::Role;
+use crate::protos;
+use crate::protos::{
+    FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
+};
+
+/// Native implementation of SCPayload_Action enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    CreateAgent,
+    CreateRecord,
+    FinalizeRecord,
+    CreateRecordType,
+    UpdateProperties,
+    CreateProposal,
+    AnswerProposal,
+    RevokeReporter,
+    MigrateRecordType,
+    ExpireProposals,
+    TransferCustody,
+}
+
+impl FromProto<protos::track_and_trace_payload::SCPayload_Action> for Action {
+    fn from_proto(
+        action: protos::track_and_trace_payload::SCPayload_Action,
+    ) -> Result<Self, ProtoConversionError> {
+        match action {
+            protos::track_and_trace_payload::SCPayload_Action::CREATE_AGENT => {
+                Ok(Action::CreateAgent)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::CREATE_RECORD => {
+                Ok(Action::CreateRecord)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::FINALIZE_RECORD => {
+                Ok(Action::FinalizeRecord)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::CREATE_RECORD_TYPE => {
+                Ok(Action::CreateRecordType)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::UPDATE_PROPERTIES => {
+                Ok(Action::UpdateProperties)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::CREATE_PROPOSAL => {
+                Ok(Action::CreateProposal)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::ANSWER_PROPOSAL => {
+                Ok(Action::AnswerProposal)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::REVOKE_REPORTER => {
+                Ok(Action::RevokeReporter)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::MIGRATE_RECORD_TYPE => {
+                Ok(Action::MigrateRecordType)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::EXPIRE_PROPOSALS => {
+                Ok(Action::ExpireProposals)
+            }
+            protos::track_and_trace_payload::SCPayload_Action::TRANSFER_CUSTODY => {
+                Ok(Action::TransferCustody)
+            }
+        }
+    }
+}
+
+impl FromNative<Action> for protos::track_and_trace_payload::SCPayload_Action {
+    fn from_native(action: Action) -> Result<Self, ProtoConversionError> {
+        match action {
+            Action::CreateAgent => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::CREATE_AGENT)
+            }
+            Action::CreateRecord => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::CREATE_RECORD)
+            }
+            Action::FinalizeRecord => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::FINALIZE_RECORD)
+            }
+            Action::CreateRecordType => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::CREATE_RECORD_TYPE)
+            }
+            Action::UpdateProperties => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::UPDATE_PROPERTIES)
+            }
+            Action::CreateProposal => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::CREATE_PROPOSAL)
+            }
+            Action::AnswerProposal => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::ANSWER_PROPOSAL)
+            }
+            Action::RevokeReporter => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::REVOKE_REPORTER)
+            }
+            Action::MigrateRecordType => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::MIGRATE_RECORD_TYPE)
+            }
+            Action::ExpireProposals => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::EXPIRE_PROPOSALS)
+            }
+            Action::TransferCustody => {
+                Ok(protos::track_and_trace_payload::SCPayload_Action::TRANSFER_CUSTODY)
+            }
+        }
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::SCPayload_Action> for Action {}
+impl IntoNative<Action> for protos::track_and_trace_payload::SCPayload_Action {}
+
+/// Native implementation of SCPayload
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackAndTracePayload {
+    action: Action,
+    timestamp: u64,
+    create_agent: CreateAgentAction,
+    create_record: CreateRecordAction,
+    finalize_record: FinalizeRecordAction,
+    create_record_type: CreateRecordTypeAction,
+    update_properties: UpdatePropertiesAction,
+    create_proposal: CreateProposalAction,
+    answer_proposal: AnswerProposalAction,
+    revoke_reporter: RevokeReporterAction,
+    migrate_record_type: MigrateRecordTypeAction,
+    expire_proposals: ExpireProposalsAction,
+    transfer_custody: TransferCustodyAction,
+}
+
+impl TrackAndTracePayload {
+    pub fn action(&self) -> &Action {
+        &self.action
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+
+    pub fn create_agent(&self) -> &CreateAgentAction {
+        &self.create_agent
+    }
+
+    pub fn create_record(&self) -> &CreateRecordAction {
+        &self.create_record
+    }
+
+    pub fn finalize_record(&self) -> &FinalizeRecordAction {
+        &self.finalize_record
+    }
+
+    pub fn create_record_type(&self) -> &CreateRecordTypeAction {
+        &self.create_record_type
+    }
+
+    pub fn update_properties(&self) -> &UpdatePropertiesAction {
+        &self.update_properties
+    }
+
+    pub fn create_proposal(&self) -> &CreateProposalAction {
+        &self.create_proposal
+    }
+
+    pub fn answer_proposal(&self) -> &AnswerProposalAction {
+        &self.answer_proposal
+    }
+
+    pub fn revoke_reporter(&self) -> &RevokeReporterAction {
+        &self.revoke_reporter
+    }
+
+    pub fn migrate_record_type(&self) -> &MigrateRecordTypeAction {
+        &self.migrate_record_type
+    }
+
+    pub fn expire_proposals(&self) -> &ExpireProposalsAction {
+        &self.expire_proposals
+    }
+
+    pub fn transfer_custody(&self) -> &TransferCustodyAction {
+        &self.transfer_custody
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::SCPayload> for TrackAndTracePayload {
+    fn from_proto(
+        track_and_trace_payload: protos::track_and_trace_payload::SCPayload,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(TrackAndTracePayload {
+            action: Action::from_proto(track_and_trace_payload.get_action())?,
+            timestamp: track_and_trace_payload.get_timestamp(),
+            create_agent: CreateAgentAction::from_proto(
+                track_and_trace_payload.get_create_agent().clone(),
+            )?,
+            create_record: CreateRecordAction::from_proto(
+                track_and_trace_payload.get_create_record().clone(),
+            )?,
+            finalize_record: FinalizeRecordAction::from_proto(
+                track_and_trace_payload.get_finalize_record().clone(),
+            )?,
+            create_record_type: CreateRecordTypeAction::from_proto(
+                track_and_trace_payload.get_create_record_type().clone(),
+            )?,
+            update_properties: UpdatePropertiesAction::from_proto(
+                track_and_trace_payload.get_update_properties().clone(),
+            )?,
+            create_proposal: CreateProposalAction::from_proto(
+                track_and_trace_payload.get_create_proposal().clone(),
+            )?,
+            answer_proposal: AnswerProposalAction::from_proto(
+                track_and_trace_payload.get_answer_proposal().clone(),
+            )?,
+            revoke_reporter: RevokeReporterAction::from_proto(
+                track_and_trace_payload.get_revoke_reporter().clone(),
+            )?,
+            migrate_record_type: MigrateRecordTypeAction::from_proto(
+                track_and_trace_payload.get_migrate_record_type().clone(),
+            )?,
+            expire_proposals: ExpireProposalsAction::from_proto(
+                track_and_trace_payload.get_expire_proposals().clone(),
+            )?,
+            transfer_custody: TransferCustodyAction::from_proto(
+                track_and_trace_payload.get_transfer_custody().clone(),
+            )?,
+        })
+    }
+}
+
+impl FromNative<TrackAndTracePayload> for protos::track_and_trace_payload::SCPayload {
+    fn from_native(
+        track_and_trace_payload: TrackAndTracePayload,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut track_and_trace_payload_proto = protos::track_and_trace_payload::SCPayload::new();
+
+        track_and_trace_payload_proto
+            .set_action(track_and_trace_payload.action().clone().into_proto()?);
+        track_and_trace_payload_proto.set_timestamp(*track_and_trace_payload.timestamp());
+        track_and_trace_payload_proto.set_create_agent(
+            track_and_trace_payload
+                .create_agent()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_create_record(
+            track_and_trace_payload
+                .create_record()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_finalize_record(
+            track_and_trace_payload
+                .finalize_record()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_create_record_type(
+            track_and_trace_payload
+                .create_record_type()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_update_properties(
+            track_and_trace_payload
+                .update_properties()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_create_proposal(
+            track_and_trace_payload
+                .create_proposal()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_answer_proposal(
+            track_and_trace_payload
+                .answer_proposal()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_revoke_reporter(
+            track_and_trace_payload
+                .revoke_reporter()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_migrate_record_type(
+            track_and_trace_payload
+                .migrate_record_type()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_expire_proposals(
+            track_and_trace_payload
+                .expire_proposals()
+                .clone()
+                .into_proto()?,
+        );
+        track_and_trace_payload_proto.set_transfer_custody(
+            track_and_trace_payload
+                .transfer_custody()
+                .clone()
+                .into_proto()?,
+        );
+
+        Ok(track_and_trace_payload_proto)
+    }
+}
+
+impl FromBytes<TrackAndTracePayload> for TrackAndTracePayload {
+    fn from_bytes(bytes: &[u8]) -> Result<TrackAndTracePayload, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::SCPayload = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get TrackAndTracePayload from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for TrackAndTracePayload {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from TrackAndTracePayload".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::SCPayload> for TrackAndTracePayload {}
+impl IntoNative<TrackAndTracePayload> for protos::track_and_trace_payload::SCPayload {}
+
+#[derive(Debug)]
+pub enum TrackAndTracePayloadBuildError {
+    MissingField(String),
+}
+
+impl StdError for TrackAndTracePayloadBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            TrackAndTracePayloadBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            TrackAndTracePayloadBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TrackAndTracePayloadBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            TrackAndTracePayloadBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a TrackAndTracePayload
+#[derive(Default, Clone)]
+pub struct TrackAndTracePayloadBuilder {
+    action: Option<Action>,
+    timestamp: Option<u64>,
+    create_agent: Option<CreateAgentAction>,
+    create_record: Option<CreateRecordAction>,
+    finalize_record: Option<FinalizeRecordAction>,
+    create_record_type: Option<CreateRecordTypeAction>,
+    update_properties: Option<UpdatePropertiesAction>,
+    create_proposal: Option<CreateProposalAction>,
+    answer_proposal: Option<AnswerProposalAction>,
+    revoke_reporter: Option<RevokeReporterAction>,
+    migrate_record_type: Option<MigrateRecordTypeAction>,
+    expire_proposals: Option<ExpireProposalsAction>,
+    transfer_custody: Option<TransferCustodyAction>,
+}
+
+impl TrackAndTracePayloadBuilder {
+    pub fn new() -> Self {
+        TrackAndTracePayloadBuilder::default()
+    }
+
+    pub fn with_action(mut self, action: Action) -> TrackAndTracePayloadBuilder {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> TrackAndTracePayloadBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn with_create_agent(
+        mut self,
+        create_agent: CreateAgentAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.create_agent = Some(create_agent);
+        self
+    }
+
+    pub fn with_create_record(
+        mut self,
+        create_record: CreateRecordAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.create_record = Some(create_record);
+        self
+    }
+
+    pub fn with_finalize_record(
+        mut self,
+        finalize_record: FinalizeRecordAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.finalize_record = Some(finalize_record);
+        self
+    }
+
+    pub fn with_create_record_type(
+        mut self,
+        create_record_type: CreateRecordTypeAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.create_record_type = Some(create_record_type);
+        self
+    }
+
+    pub fn with_update_properties(
+        mut self,
+        update_properties: UpdatePropertiesAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.update_properties = Some(update_properties);
+        self
+    }
+
+    pub fn with_create_proposal(
+        mut self,
+        create_proposal: CreateProposalAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.create_proposal = Some(create_proposal);
+        self
+    }
+
+    pub fn with_answer_proposal(
+        mut self,
+        answer_proposal: AnswerProposalAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.answer_proposal = Some(answer_proposal);
+        self
+    }
+
+    pub fn with_revoke_reporter(
+        mut self,
+        revoke_reporter: RevokeReporterAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.revoke_reporter = Some(revoke_reporter);
+        self
+    }
+
+    pub fn with_migrate_record_type(
+        mut self,
+        migrate_record_type: MigrateRecordTypeAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.migrate_record_type = Some(migrate_record_type);
+        self
+    }
+
+    pub fn with_expire_proposals(
+        mut self,
+        expire_proposals: ExpireProposalsAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.expire_proposals = Some(expire_proposals);
+        self
+    }
+
+    pub fn with_transfer_custody(
+        mut self,
+        transfer_custody: TransferCustodyAction,
+    ) -> TrackAndTracePayloadBuilder {
+        self.transfer_custody = Some(transfer_custody);
+        self
+    }
+
+    pub fn build(self) -> Result<TrackAndTracePayload, TrackAndTracePayloadBuildError> {
+        let action = self.action.ok_or_else(|| {
+            TrackAndTracePayloadBuildError::MissingField("'action' field is required".to_string())
+        })?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            TrackAndTracePayloadBuildError::MissingField(
+                "'timestamp' field is required".to_string(),
+            )
+        })?;
+        if timestamp == 0 {
+            return Err(TrackAndTracePayloadBuildError::MissingField(
+                "'timestamp' cannot be 0".to_string(),
+            ));
+        }
+
+        let create_agent = {
+            if action == Action::CreateAgent {
+                self.create_agent.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'create_agent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateAgentAction::default()
+            }
+        };
+
+        let create_record = {
+            if action == Action::CreateRecord {
+                self.create_record.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'create_record' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateRecordAction::default()
+            }
+        };
+
+        let finalize_record = {
+            if action == Action::FinalizeRecord {
+                self.finalize_record.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'finalize_record' field is required".to_string(),
+                    )
+                })?
+            } else {
+                FinalizeRecordAction::default()
+            }
+        };
+
+        let create_record_type = {
+            if action == Action::CreateRecordType {
+                self.create_record_type.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'create_record_type' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateRecordTypeAction::default()
+            }
+        };
+
+        let update_properties = {
+            if action == Action::UpdateProperties {
+                self.update_properties.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'update_properties' field is required".to_string(),
+                    )
+                })?
+            } else {
+                UpdatePropertiesAction::default()
+            }
+        };
+
+        let create_proposal = {
+            if action == Action::CreateProposal {
+                self.create_proposal.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'create_proposal' field is required".to_string(),
+                    )
+                })?
+            } else {
+                CreateProposalAction::default()
+            }
+        };
+
+        let answer_proposal = {
+            if action == Action::AnswerProposal {
+                self.answer_proposal.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'answer_proposal' field is required".to_string(),
+                    )
+                })?
+            } else {
+                AnswerProposalAction::default()
+            }
+        };
+
+        let revoke_reporter = {
+            if action == Action::RevokeReporter {
+                self.revoke_reporter.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'revoke_reporter' field is required".to_string(),
+                    )
+                })?
+            } else {
+                RevokeReporterAction::default()
+            }
+        };
+
+        let migrate_record_type = {
+            if action == Action::MigrateRecordType {
+                self.migrate_record_type.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'migrate_record_type' field is required".to_string(),
+                    )
+                })?
+            } else {
+                MigrateRecordTypeAction::default()
+            }
+        };
+
+        let expire_proposals = {
+            if action == Action::ExpireProposals {
+                self.expire_proposals.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'expire_proposals' field is required".to_string(),
+                    )
+                })?
+            } else {
+                ExpireProposalsAction::default()
+            }
+        };
+
+        let transfer_custody = {
+            if action == Action::TransferCustody {
+                self.transfer_custody.ok_or_else(|| {
+                    TrackAndTracePayloadBuildError::MissingField(
+                        "'transfer_custody' field is required".to_string(),
+                    )
+                })?
+            } else {
+                TransferCustodyAction::default()
+            }
+        };
+
+        Ok(TrackAndTracePayload {
+            action,
+            timestamp,
+            create_agent,
+            create_record,
+            finalize_record,
+            create_record_type,
+            update_properties,
+            create_proposal,
+            answer_proposal,
+            revoke_reporter,
+            migrate_record_type,
+            expire_proposals,
+            transfer_custody,
+        })
+    }
+}
+
+/// Native implementation of CreateTrackAndTraceAgentAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateAgentAction {
+    name: String,
+}
+
+impl CreateAgentAction {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::CreateTrackAndTraceAgentAction>
+    for CreateAgentAction
+{
+    fn from_proto(
+        create_agent_action: protos::track_and_trace_payload::CreateTrackAndTraceAgentAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateAgentAction {
+            name: create_agent_action.get_name().to_string(),
+        })
+    }
+}
+
+impl FromNative<CreateAgentAction>
+    for protos::track_and_trace_payload::CreateTrackAndTraceAgentAction
+{
+    fn from_native(create_agent_action: CreateAgentAction) -> Result<Self, ProtoConversionError> {
+        let mut create_agent_action_proto =
+            protos::track_and_trace_payload::CreateTrackAndTraceAgentAction::new();
+
+        create_agent_action_proto.set_name(create_agent_action.name().to_string());
+
+        Ok(create_agent_action_proto)
+    }
+}
+
+impl FromBytes<CreateAgentAction> for CreateAgentAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateAgentAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::CreateTrackAndTraceAgentAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateAgentAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateAgentAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateAgentAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::CreateTrackAndTraceAgentAction>
+    for CreateAgentAction
+{
+}
+impl IntoNative<CreateAgentAction>
+    for protos::track_and_trace_payload::CreateTrackAndTraceAgentAction
+{
+}
+
+#[derive(Debug)]
+pub enum CreateAgentActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateAgentActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateAgentActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            CreateAgentActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateAgentActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateAgentActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a CreateAgentAction
+#[derive(Default, Clone)]
+pub struct CreateAgentActionBuilder {
+    name: Option<String>,
+}
+
+impl CreateAgentActionBuilder {
+    pub fn new() -> Self {
+        CreateAgentActionBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> CreateAgentActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn build(self) -> Result<CreateAgentAction, CreateAgentActionBuildError> {
+        let name = self.name.ok_or_else(|| {
+            CreateAgentActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+        if name.is_empty() {
+            return Err(CreateAgentActionBuildError::MissingField(
+                "'name' cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(CreateAgentAction { name })
+    }
+}
+
+/// Native implementation of CreateRecordAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateRecordAction {
+    record_id: String,
+    record_type: String,
+    properties: Vec<PropertyValue>,
+}
+
+impl CreateRecordAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn record_type(&self) -> &str {
+        &self.record_type
+    }
+
+    pub fn properties(&self) -> &[PropertyValue] {
+        &self.properties
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::CreateRecordAction> for CreateRecordAction {
+    fn from_proto(
+        create_record_action: protos::track_and_trace_payload::CreateRecordAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateRecordAction {
+            record_id: create_record_action.get_record_id().to_string(),
+            record_type: create_record_action.get_record_type().to_string(),
+            properties: create_record_action
+                .get_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::from_proto)
+                .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<CreateRecordAction> for protos::track_and_trace_payload::CreateRecordAction {
+    fn from_native(create_record_action: CreateRecordAction) -> Result<Self, ProtoConversionError> {
+        let mut create_record_action_proto =
+            protos::track_and_trace_payload::CreateRecordAction::new();
+
+        create_record_action_proto.set_record_id(create_record_action.record_id().to_string());
+        create_record_action_proto.set_record_type(create_record_action.record_type().to_string());
+        create_record_action_proto.set_properties(RepeatedField::from_vec(
+            create_record_action
+                .properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::TrackAndTracePropertyValue>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(create_record_action_proto)
+    }
+}
+
+impl FromBytes<CreateRecordAction> for CreateRecordAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateRecordAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::CreateRecordAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateRecordAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateRecordAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateRecordAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::CreateRecordAction> for CreateRecordAction {}
+impl IntoNative<CreateRecordAction> for protos::track_and_trace_payload::CreateRecordAction {}
+
+#[derive(Debug)]
+pub enum CreateRecordActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateRecordActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateRecordActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            CreateRecordActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateRecordActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateRecordActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a CreateRecordAction
+#[derive(Default, Clone)]
+pub struct CreateRecordActionBuilder {
+    record_id: Option<String>,
+    record_type: Option<String>,
+    properties: Vec<PropertyValue>,
+}
+
+impl CreateRecordActionBuilder {
+    pub fn new() -> Self {
+        CreateRecordActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> CreateRecordActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_record_type(mut self, record_type: String) -> CreateRecordActionBuilder {
+        self.record_type = Some(record_type);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Vec<PropertyValue>) -> CreateRecordActionBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn build(self) -> Result<CreateRecordAction, CreateRecordActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            CreateRecordActionBuildError::MissingField("'record_id' field is required".to_string())
+        })?;
+        if record_id.is_empty() {
+            return Err(CreateRecordActionBuildError::MissingField(
+                "'record_id' cannot be empty".to_string(),
+            ));
+        }
+
+        let record_type = self.record_type.ok_or_else(|| {
+            CreateRecordActionBuildError::MissingField(
+                "'record_type' field is required".to_string(),
+            )
+        })?;
+
+        let properties = self.properties;
+
+        Ok(CreateRecordAction {
+            record_id,
+            record_type,
+            properties,
+        })
+    }
+}
+
+/// Native implementation of FinalizeRecordAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FinalizeRecordAction {
+    record_id: String,
+}
+
+impl FinalizeRecordAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::FinalizeRecordAction> for FinalizeRecordAction {
+    fn from_proto(
+        finalize_record_action: protos::track_and_trace_payload::FinalizeRecordAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(FinalizeRecordAction {
+            record_id: finalize_record_action.get_record_id().to_string(),
+        })
+    }
+}
+
+impl FromNative<FinalizeRecordAction> for protos::track_and_trace_payload::FinalizeRecordAction {
+    fn from_native(
+        finalize_record_action: FinalizeRecordAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut finalize_record_action_proto =
+            protos::track_and_trace_payload::FinalizeRecordAction::new();
+
+        finalize_record_action_proto.set_record_id(finalize_record_action.record_id().to_string());
+
+        Ok(finalize_record_action_proto)
+    }
+}
+
+impl FromBytes<FinalizeRecordAction> for FinalizeRecordAction {
+    fn from_bytes(bytes: &[u8]) -> Result<FinalizeRecordAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::FinalizeRecordAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get FinalizeRecordAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for FinalizeRecordAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from FinalizeRecordAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::FinalizeRecordAction> for FinalizeRecordAction {}
+impl IntoNative<FinalizeRecordAction> for protos::track_and_trace_payload::FinalizeRecordAction {}
+
+#[derive(Debug)]
+pub enum FinalizeRecordActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for FinalizeRecordActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            FinalizeRecordActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            FinalizeRecordActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FinalizeRecordActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            FinalizeRecordActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a FinalizeRecordAction
+#[derive(Default, Clone)]
+pub struct FinalizeRecordActionBuilder {
+    record_id: Option<String>,
+}
+
+impl FinalizeRecordActionBuilder {
+    pub fn new() -> Self {
+        FinalizeRecordActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> FinalizeRecordActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn build(self) -> Result<FinalizeRecordAction, FinalizeRecordActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            FinalizeRecordActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        Ok(FinalizeRecordAction { record_id })
+    }
+}
+
+/// Native implementation of CreateRecordTypeAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateRecordTypeAction {
+    name: String,
+    properties: Vec<PropertySchema>,
+}
+
+impl CreateRecordTypeAction {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn properties(&self) -> &[PropertySchema] {
+        &self.properties
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::CreateRecordTypeAction> for CreateRecordTypeAction {
+    fn from_proto(
+        create_record_type_action: protos::track_and_trace_payload::CreateRecordTypeAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateRecordTypeAction {
+            name: create_record_type_action.get_name().to_string(),
+            properties: create_record_type_action
+                .get_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::from_proto)
+                .collect::<Result<Vec<PropertySchema>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<CreateRecordTypeAction>
+    for protos::track_and_trace_payload::CreateRecordTypeAction
+{
+    fn from_native(
+        create_record_type_action: CreateRecordTypeAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut create_record_type_action_proto =
+            protos::track_and_trace_payload::CreateRecordTypeAction::new();
+
+        create_record_type_action_proto.set_name(create_record_type_action.name().to_string());
+        create_record_type_action_proto.set_properties(RepeatedField::from_vec(
+            create_record_type_action
+                .properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::PropertySchema>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(create_record_type_action_proto)
+    }
+}
+
+impl FromBytes<CreateRecordTypeAction> for CreateRecordTypeAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateRecordTypeAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::CreateRecordTypeAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateRecordTypeAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateRecordTypeAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateRecordTypeAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::CreateRecordTypeAction> for CreateRecordTypeAction {}
+impl IntoNative<CreateRecordTypeAction>
+    for protos::track_and_trace_payload::CreateRecordTypeAction
+{
+}
+
+#[derive(Debug)]
+pub enum CreateRecordTypeActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateRecordTypeActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateRecordTypeActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            CreateRecordTypeActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateRecordTypeActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateRecordTypeActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a CreateRecordTypeAction
+#[derive(Default, Clone)]
+pub struct CreateRecordTypeActionBuilder {
+    name: Option<String>,
+    properties: Vec<PropertySchema>,
+}
+
+impl CreateRecordTypeActionBuilder {
+    pub fn new() -> Self {
+        CreateRecordTypeActionBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> CreateRecordTypeActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_properties(
+        mut self,
+        properties: Vec<PropertySchema>,
+    ) -> CreateRecordTypeActionBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn build(self) -> Result<CreateRecordTypeAction, CreateRecordTypeActionBuildError> {
+        let name = self.name.ok_or_else(|| {
+            CreateRecordTypeActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+        if name.is_empty() {
+            return Err(CreateRecordTypeActionBuildError::MissingField(
+                "'name' cannot be empty".to_string(),
+            ));
+        }
+
+        let properties = {
+            if self.properties.is_empty() {
+                return Err(CreateRecordTypeActionBuildError::MissingField(
+                    "'properties' cannot be empty".to_string(),
+                ));
+            } else {
+                self.properties
+            }
+        };
+
+        Ok(CreateRecordTypeAction { name, properties })
+    }
+}
+
+/// Native implementation of UpdatePropertiesAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UpdatePropertiesAction {
+    record_id: String,
+    properties: Vec<PropertyValue>,
+}
+
+impl UpdatePropertiesAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn properties(&self) -> &[PropertyValue] {
+        &self.properties
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::UpdatePropertiesAction> for UpdatePropertiesAction {
+    fn from_proto(
+        update_properties_action: protos::track_and_trace_payload::UpdatePropertiesAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(UpdatePropertiesAction {
+            record_id: update_properties_action.get_record_id().to_string(),
+            properties: update_properties_action
+                .get_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::from_proto)
+                .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<UpdatePropertiesAction>
+    for protos::track_and_trace_payload::UpdatePropertiesAction
+{
+    fn from_native(
+        update_properties_action: UpdatePropertiesAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut update_properties_action_proto =
+            protos::track_and_trace_payload::UpdatePropertiesAction::new();
+
+        update_properties_action_proto
+            .set_record_id(update_properties_action.record_id().to_string());
+        update_properties_action_proto.set_properties(RepeatedField::from_vec(
+            update_properties_action
+                .properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::TrackAndTracePropertyValue>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(update_properties_action_proto)
+    }
+}
+
+impl FromBytes<UpdatePropertiesAction> for UpdatePropertiesAction {
+    fn from_bytes(bytes: &[u8]) -> Result<UpdatePropertiesAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::UpdatePropertiesAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get UpdatePropertiesAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for UpdatePropertiesAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from UpdatePropertiesAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::UpdatePropertiesAction> for UpdatePropertiesAction {}
+impl IntoNative<UpdatePropertiesAction>
+    for protos::track_and_trace_payload::UpdatePropertiesAction
+{
+}
+
+#[derive(Debug)]
+pub enum UpdatePropertiesActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for UpdatePropertiesActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdatePropertiesActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            UpdatePropertiesActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for UpdatePropertiesActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            UpdatePropertiesActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a UpdatePropertiesAction
+#[derive(Default, Clone)]
+pub struct UpdatePropertiesActionBuilder {
+    record_id: Option<String>,
+    properties: Vec<PropertyValue>,
+}
+
+impl UpdatePropertiesActionBuilder {
+    pub fn new() -> Self {
+        UpdatePropertiesActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> UpdatePropertiesActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_properties(
+        mut self,
+        properties: Vec<PropertyValue>,
+    ) -> UpdatePropertiesActionBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn build(self) -> Result<UpdatePropertiesAction, UpdatePropertiesActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            UpdatePropertiesActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let properties = {
+            if self.properties.is_empty() {
+                return Err(UpdatePropertiesActionBuildError::MissingField(
+                    "'properties' cannot be empty".to_string(),
+                ));
+            } else {
+                self.properties
+            }
+        };
+
+        Ok(UpdatePropertiesAction {
+            record_id,
+            properties,
+        })
+    }
+}
+
+/// Native implementation of CreateProposalAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CreateProposalAction {
+    record_id: String,
+    receiving_agent: String,
+    role: Role,
+    properties: Vec<String>,
+    terms: String,
+    expiry: u64,
+}
+
+impl CreateProposalAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn receiving_agent(&self) -> &str {
+        &self.receiving_agent
+    }
+
+    pub fn role(&self) -> &Role {
+        &self.role
+    }
+
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+
+    pub fn terms(&self) -> &str {
+        &self.terms
+    }
+
+    pub fn expiry(&self) -> &u64 {
+        &self.expiry
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::CreateProposalAction> for CreateProposalAction {
+    fn from_proto(
+        create_proposal_action: protos::track_and_trace_payload::CreateProposalAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(CreateProposalAction {
+            record_id: create_proposal_action.get_record_id().to_string(),
+            receiving_agent: create_proposal_action.get_receiving_agent().to_string(),
+            role: Role::from_proto(create_proposal_action.get_role())?,
+            properties: create_proposal_action.get_properties().to_vec(),
+            terms: create_proposal_action.get_terms().to_string(),
+            expiry: create_proposal_action.get_expiry(),
+        })
+    }
+}
+
+impl FromNative<CreateProposalAction> for protos::track_and_trace_payload::CreateProposalAction {
+    fn from_native(
+        create_proposal_action: CreateProposalAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut create_proposal_action_proto =
+            protos::track_and_trace_payload::CreateProposalAction::new();
+
+        create_proposal_action_proto.set_record_id(create_proposal_action.record_id().to_string());
+        create_proposal_action_proto
+            .set_receiving_agent(create_proposal_action.receiving_agent().to_string());
+        create_proposal_action_proto.set_role(create_proposal_action.role().clone().into_proto()?);
+        create_proposal_action_proto.set_properties(RepeatedField::from_vec(
+            create_proposal_action.properties().to_vec(),
+        ));
+        create_proposal_action_proto.set_terms(create_proposal_action.terms().to_string());
+        create_proposal_action_proto.set_expiry(*create_proposal_action.expiry());
+
+        Ok(create_proposal_action_proto)
+    }
+}
+
+impl FromBytes<CreateProposalAction> for CreateProposalAction {
+    fn from_bytes(bytes: &[u8]) -> Result<CreateProposalAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::CreateProposalAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get CreateProposalAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for CreateProposalAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from CreateProposalAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::CreateProposalAction> for CreateProposalAction {}
+impl IntoNative<CreateProposalAction> for protos::track_and_trace_payload::CreateProposalAction {}
+
+#[derive(Debug)]
+pub enum CreateProposalActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for CreateProposalActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            CreateProposalActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            CreateProposalActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CreateProposalActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            CreateProposalActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a CreateProposalAction
+#[derive(Default, Clone)]
+pub struct CreateProposalActionBuilder {
+    record_id: Option<String>,
+    receiving_agent: Option<String>,
+    role: Option<Role>,
+    properties: Vec<String>,
+    terms: Option<String>,
+    expiry: Option<u64>,
+}
+
+impl CreateProposalActionBuilder {
+    pub fn new() -> Self {
+        CreateProposalActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> CreateProposalActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_receiving_agent(mut self, receiving_agent: String) -> CreateProposalActionBuilder {
+        self.receiving_agent = Some(receiving_agent);
+        self
+    }
+
+    pub fn with_role(mut self, role: Role) -> CreateProposalActionBuilder {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Vec<String>) -> CreateProposalActionBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn with_terms(mut self, terms: String) -> CreateProposalActionBuilder {
+        self.terms = Some(terms);
+        self
+    }
+
+    pub fn with_expiry(mut self, expiry: u64) -> CreateProposalActionBuilder {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    pub fn build(self) -> Result<CreateProposalAction, CreateProposalActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            CreateProposalActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let receiving_agent = self.receiving_agent.ok_or_else(|| {
+            CreateProposalActionBuildError::MissingField(
+                "'receiving_agent' field is required".to_string(),
+            )
+        })?;
+
+        let role = self.role.ok_or_else(|| {
+            CreateProposalActionBuildError::MissingField("'role' field is required".to_string())
+        })?;
+
+        let properties = self.properties;
+
+        let terms = self.terms.unwrap_or_default();
+
+        let expiry = self.expiry.unwrap_or_default();
+
+        Ok(CreateProposalAction {
+            record_id,
+            receiving_agent,
+            role,
+            properties,
+            terms,
+            expiry,
+        })
+    }
+}
+
+/// Native implementation of AnswerProposalAction_Response enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum Response {
+    Accept,
+    Reject,
+    Cancel,
+}
+
+impl Default for Response {
+    fn default() -> Self {
+        Response::Accept
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::AnswerProposalAction_Response> for Response {
+    fn from_proto(
+        response: protos::track_and_trace_payload::AnswerProposalAction_Response,
+    ) -> Result<Self, ProtoConversionError> {
+        match response {
+            protos::track_and_trace_payload::AnswerProposalAction_Response::ACCEPT => {
+                Ok(Response::Accept)
+            }
+            protos::track_and_trace_payload::AnswerProposalAction_Response::REJECT => {
+                Ok(Response::Reject)
+            }
+            protos::track_and_trace_payload::AnswerProposalAction_Response::CANCEL => {
+                Ok(Response::Cancel)
+            }
+        }
+    }
+}
+
+impl FromNative<Response> for protos::track_and_trace_payload::AnswerProposalAction_Response {
+    fn from_native(response: Response) -> Result<Self, ProtoConversionError> {
+        match response {
+            Response::Accept => {
+                Ok(protos::track_and_trace_payload::AnswerProposalAction_Response::ACCEPT)
+            }
+            Response::Reject => {
+                Ok(protos::track_and_trace_payload::AnswerProposalAction_Response::REJECT)
+            }
+            Response::Cancel => {
+                Ok(protos::track_and_trace_payload::AnswerProposalAction_Response::CANCEL)
+            }
+        }
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::AnswerProposalAction_Response> for Response {}
+impl IntoNative<Response> for protos::track_and_trace_payload::AnswerProposalAction_Response {}
+
+/// Native implementation of AnswerProposalAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AnswerProposalAction {
+    record_id: String,
+    receiving_agent: String,
+    role: Role,
+    response: Response,
+}
+
+impl AnswerProposalAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn receiving_agent(&self) -> &str {
+        &self.receiving_agent
+    }
+
+    pub fn role(&self) -> &Role {
+        &self.role
+    }
+
+    pub fn response(&self) -> &Response {
+        &self.response
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::AnswerProposalAction> for AnswerProposalAction {
+    fn from_proto(
+        answer_proposal_action: protos::track_and_trace_payload::AnswerProposalAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(AnswerProposalAction {
+            record_id: answer_proposal_action.get_record_id().to_string(),
+            receiving_agent: answer_proposal_action.get_receiving_agent().to_string(),
+            role: Role::from_proto(answer_proposal_action.get_role())?,
+            response: Response::from_proto(answer_proposal_action.get_response())?,
+        })
+    }
+}
+
+impl FromNative<AnswerProposalAction> for protos::track_and_trace_payload::AnswerProposalAction {
+    fn from_native(
+        answer_proposal_action: AnswerProposalAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut answer_proposal_action_proto =
+            protos::track_and_trace_payload::AnswerProposalAction::new();
+
+        answer_proposal_action_proto.set_record_id(answer_proposal_action.record_id().to_string());
+        answer_proposal_action_proto
+            .set_receiving_agent(answer_proposal_action.receiving_agent().to_string());
+        answer_proposal_action_proto.set_role(answer_proposal_action.role().clone().into_proto()?);
+        answer_proposal_action_proto
+            .set_response(answer_proposal_action.response().clone().into_proto()?);
+
+        Ok(answer_proposal_action_proto)
+    }
+}
+
+impl FromBytes<AnswerProposalAction> for AnswerProposalAction {
+    fn from_bytes(bytes: &[u8]) -> Result<AnswerProposalAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::AnswerProposalAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get AnswerProposalAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for AnswerProposalAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from AnswerProposalAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::AnswerProposalAction> for AnswerProposalAction {}
+impl IntoNative<AnswerProposalAction> for protos::track_and_trace_payload::AnswerProposalAction {}
+
+#[derive(Debug)]
+pub enum AnswerProposalActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for AnswerProposalActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            AnswerProposalActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            AnswerProposalActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AnswerProposalActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AnswerProposalActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a AnswerProposalAction
+#[derive(Default, Clone)]
+pub struct AnswerProposalActionBuilder {
+    record_id: Option<String>,
+    receiving_agent: Option<String>,
+    role: Option<Role>,
+    response: Option<Response>,
+}
+
+impl AnswerProposalActionBuilder {
+    pub fn new() -> Self {
+        AnswerProposalActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> AnswerProposalActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_receiving_agent(mut self, receiving_agent: String) -> AnswerProposalActionBuilder {
+        self.receiving_agent = Some(receiving_agent);
+        self
+    }
+
+    pub fn with_role(mut self, role: Role) -> AnswerProposalActionBuilder {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_response(mut self, response: Response) -> AnswerProposalActionBuilder {
+        self.response = Some(response);
+        self
+    }
+
+    pub fn build(self) -> Result<AnswerProposalAction, AnswerProposalActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            AnswerProposalActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let receiving_agent = self.receiving_agent.ok_or_else(|| {
+            AnswerProposalActionBuildError::MissingField(
+                "'receiving_agent' field is required".to_string(),
+            )
+        })?;
+
+        let role = self.role.ok_or_else(|| {
+            AnswerProposalActionBuildError::MissingField("'role' field is required".to_string())
+        })?;
+
+        let response = self.response.ok_or_else(|| {
+            AnswerProposalActionBuildError::MissingField("'response' field is required".to_string())
+        })?;
+
+        Ok(AnswerProposalAction {
+            record_id,
+            receiving_agent,
+            role,
+            response,
+        })
+    }
+}
+
+/// Native implementation of RevokeReporterAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RevokeReporterAction {
+    record_id: String,
+    reporter_id: String,
+    properties: Vec<String>,
+}
+
+impl RevokeReporterAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn reporter_id(&self) -> &str {
+        &self.reporter_id
+    }
+
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::RevokeReporterAction> for RevokeReporterAction {
+    fn from_proto(
+        revoke_reporter_action: protos::track_and_trace_payload::RevokeReporterAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(RevokeReporterAction {
+            record_id: revoke_reporter_action.get_record_id().to_string(),
+            reporter_id: revoke_reporter_action.get_reporter_id().to_string(),
+            properties: revoke_reporter_action.get_properties().to_vec(),
+        })
+    }
+}
+
+impl FromNative<RevokeReporterAction> for protos::track_and_trace_payload::RevokeReporterAction {
+    fn from_native(
+        revoke_reporter_action: RevokeReporterAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut revoke_reporter_action_proto =
+            protos::track_and_trace_payload::RevokeReporterAction::new();
+
+        revoke_reporter_action_proto.set_record_id(revoke_reporter_action.record_id().to_string());
+        revoke_reporter_action_proto
+            .set_reporter_id(revoke_reporter_action.reporter_id().to_string());
+        revoke_reporter_action_proto.set_properties(RepeatedField::from_vec(
+            revoke_reporter_action.properties().to_vec(),
+        ));
+
+        Ok(revoke_reporter_action_proto)
+    }
+}
+
+impl FromBytes<RevokeReporterAction> for RevokeReporterAction {
+    fn from_bytes(bytes: &[u8]) -> Result<RevokeReporterAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::RevokeReporterAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get RevokeReporterAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RevokeReporterAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RevokeReporterAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::RevokeReporterAction> for RevokeReporterAction {}
+impl IntoNative<RevokeReporterAction> for protos::track_and_trace_payload::RevokeReporterAction {}
+
+#[derive(Debug)]
+pub enum RevokeReporterActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for RevokeReporterActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RevokeReporterActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            RevokeReporterActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RevokeReporterActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RevokeReporterActionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RevokeReporterAction
+#[derive(Default, Clone)]
+pub struct RevokeReporterActionBuilder {
+    record_id: Option<String>,
+    reporter_id: Option<String>,
+    properties: Vec<String>,
+}
+
+impl RevokeReporterActionBuilder {
+    pub fn new() -> Self {
+        RevokeReporterActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> RevokeReporterActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_reporter_id(mut self, reporter_id: String) -> RevokeReporterActionBuilder {
+        self.reporter_id = Some(reporter_id);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Vec<String>) -> RevokeReporterActionBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn build(self) -> Result<RevokeReporterAction, RevokeReporterActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            RevokeReporterActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let reporter_id = self.reporter_id.ok_or_else(|| {
+            RevokeReporterActionBuildError::MissingField(
+                "'reporter_id' field is required".to_string(),
+            )
+        })?;
+
+        let properties = self.properties;
+
+        Ok(RevokeReporterAction {
+            record_id,
+            reporter_id,
+            properties,
+        })
+    }
+}
+
+/// Native implementation of ExpireProposalsAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExpireProposalsAction {
+    record_id: String,
+    receiving_agent: String,
+}
+
+impl ExpireProposalsAction {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn receiving_agent(&self) -> &str {
+        &self.receiving_agent
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::ExpireProposalsAction> for ExpireProposalsAction {
+    fn from_proto(
+        expire_proposals_action: protos::track_and_trace_payload::ExpireProposalsAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(ExpireProposalsAction {
+            record_id: expire_proposals_action.get_record_id().to_string(),
+            receiving_agent: expire_proposals_action.get_receiving_agent().to_string(),
+        })
+    }
+}
+
+impl FromNative<ExpireProposalsAction> for protos::track_and_trace_payload::ExpireProposalsAction {
+    fn from_native(
+        expire_proposals_action: ExpireProposalsAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut expire_proposals_action_proto =
+            protos::track_and_trace_payload::ExpireProposalsAction::new();
+
+        expire_proposals_action_proto
+            .set_record_id(expire_proposals_action.record_id().to_string());
+        expire_proposals_action_proto
+            .set_receiving_agent(expire_proposals_action.receiving_agent().to_string());
+
+        Ok(expire_proposals_action_proto)
+    }
+}
+
+impl FromBytes<ExpireProposalsAction> for ExpireProposalsAction {
+    fn from_bytes(bytes: &[u8]) -> Result<ExpireProposalsAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::ExpireProposalsAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get ExpireProposalsAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for ExpireProposalsAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from ExpireProposalsAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::ExpireProposalsAction> for ExpireProposalsAction {}
+impl IntoNative<ExpireProposalsAction> for protos::track_and_trace_payload::ExpireProposalsAction {}
+
+#[derive(Debug)]
+pub enum ExpireProposalsActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for ExpireProposalsActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            ExpireProposalsActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            ExpireProposalsActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ExpireProposalsActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ExpireProposalsActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create an ExpireProposalsAction
+#[derive(Default, Clone)]
+pub struct ExpireProposalsActionBuilder {
+    record_id: Option<String>,
+    receiving_agent: Option<String>,
+}
+
+impl ExpireProposalsActionBuilder {
+    pub fn new() -> Self {
+        ExpireProposalsActionBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> ExpireProposalsActionBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_receiving_agent(mut self, receiving_agent: String) -> ExpireProposalsActionBuilder {
+        self.receiving_agent = Some(receiving_agent);
+        self
+    }
+
+    pub fn build(self) -> Result<ExpireProposalsAction, ExpireProposalsActionBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            ExpireProposalsActionBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let receiving_agent = self.receiving_agent.ok_or_else(|| {
+            ExpireProposalsActionBuildError::MissingField(
+                "'receiving_agent' field is required".to_string(),
+            )
+        })?;
+
+        Ok(ExpireProposalsAction {
+            record_id,
+            receiving_agent,
+        })
+    }
+}
+
+/// Native implementation of TransferCustodyAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransferCustodyAction {
+    handoff: Vec<u8>,
+    receiving_agent_signature: String,
+}
+
+impl TransferCustodyAction {
+    /// Returns the serialized `TransferCustodyHandoff` signed by the receiving agent
+    pub fn handoff(&self) -> &[u8] {
+        &self.handoff
+    }
+
+    pub fn receiving_agent_signature(&self) -> &str {
+        &self.receiving_agent_signature
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::TransferCustodyAction> for TransferCustodyAction {
+    fn from_proto(
+        transfer_custody_action: protos::track_and_trace_payload::TransferCustodyAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(TransferCustodyAction {
+            handoff: transfer_custody_action.get_handoff().to_vec(),
+            receiving_agent_signature: transfer_custody_action
+                .get_receiving_agent_signature()
+                .to_string(),
+        })
+    }
+}
+
+impl FromNative<TransferCustodyAction> for protos::track_and_trace_payload::TransferCustodyAction {
+    fn from_native(
+        transfer_custody_action: TransferCustodyAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut transfer_custody_action_proto =
+            protos::track_and_trace_payload::TransferCustodyAction::new();
+
+        transfer_custody_action_proto.set_handoff(transfer_custody_action.handoff().to_vec());
+        transfer_custody_action_proto.set_receiving_agent_signature(
+            transfer_custody_action
+                .receiving_agent_signature()
+                .to_string(),
+        );
+
+        Ok(transfer_custody_action_proto)
+    }
+}
+
+impl FromBytes<TransferCustodyAction> for TransferCustodyAction {
+    fn from_bytes(bytes: &[u8]) -> Result<TransferCustodyAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::TransferCustodyAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get TransferCustodyAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for TransferCustodyAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from TransferCustodyAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::TransferCustodyAction> for TransferCustodyAction {}
+impl IntoNative<TransferCustodyAction> for protos::track_and_trace_payload::TransferCustodyAction {}
+
+#[derive(Debug)]
+pub enum TransferCustodyActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for TransferCustodyActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            TransferCustodyActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            TransferCustodyActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TransferCustodyActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            TransferCustodyActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a TransferCustodyAction
+#[derive(Default, Clone)]
+pub struct TransferCustodyActionBuilder {
+    handoff: Option<Vec<u8>>,
+    receiving_agent_signature: Option<String>,
+}
+
+impl TransferCustodyActionBuilder {
+    pub fn new() -> Self {
+        TransferCustodyActionBuilder::default()
+    }
+
+    pub fn with_handoff(mut self, handoff: Vec<u8>) -> TransferCustodyActionBuilder {
+        self.handoff = Some(handoff);
+        self
+    }
+
+    pub fn with_receiving_agent_signature(
+        mut self,
+        receiving_agent_signature: String,
+    ) -> TransferCustodyActionBuilder {
+        self.receiving_agent_signature = Some(receiving_agent_signature);
+        self
+    }
+
+    pub fn build(self) -> Result<TransferCustodyAction, TransferCustodyActionBuildError> {
+        let handoff = self.handoff.ok_or_else(|| {
+            TransferCustodyActionBuildError::MissingField("'handoff' field is required".to_string())
+        })?;
+
+        let receiving_agent_signature = self.receiving_agent_signature.ok_or_else(|| {
+            TransferCustodyActionBuildError::MissingField(
+                "'receiving_agent_signature' field is required".to_string(),
+            )
+        })?;
+
+        Ok(TransferCustodyAction {
+            handoff,
+            receiving_agent_signature,
+        })
+    }
+}
+
+/// Native implementation of TransferCustodyHandoff
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransferCustodyHandoff {
+    record_id: String,
+    receiving_agent: String,
+    timestamp: u64,
+    custodian_count: u32,
+}
+
+impl TransferCustodyHandoff {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn receiving_agent(&self) -> &str {
+        &self.receiving_agent
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+
+    pub fn custodian_count(&self) -> &u32 {
+        &self.custodian_count
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::TransferCustodyHandoff> for TransferCustodyHandoff {
+    fn from_proto(
+        handoff: protos::track_and_trace_payload::TransferCustodyHandoff,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(TransferCustodyHandoff {
+            record_id: handoff.get_record_id().to_string(),
+            receiving_agent: handoff.get_receiving_agent().to_string(),
+            timestamp: handoff.get_timestamp(),
+            custodian_count: handoff.get_custodian_count(),
+        })
+    }
+}
+
+impl FromNative<TransferCustodyHandoff>
+    for protos::track_and_trace_payload::TransferCustodyHandoff
+{
+    fn from_native(handoff: TransferCustodyHandoff) -> Result<Self, ProtoConversionError> {
+        let mut handoff_proto = protos::track_and_trace_payload::TransferCustodyHandoff::new();
+
+        handoff_proto.set_record_id(handoff.record_id().to_string());
+        handoff_proto.set_receiving_agent(handoff.receiving_agent().to_string());
+        handoff_proto.set_timestamp(*handoff.timestamp());
+        handoff_proto.set_custodian_count(*handoff.custodian_count());
+
+        Ok(handoff_proto)
+    }
+}
+
+impl FromBytes<TransferCustodyHandoff> for TransferCustodyHandoff {
+    fn from_bytes(bytes: &[u8]) -> Result<TransferCustodyHandoff, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::TransferCustodyHandoff =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get TransferCustodyHandoff from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for TransferCustodyHandoff {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from TransferCustodyHandoff".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::TransferCustodyHandoff> for TransferCustodyHandoff {}
+impl IntoNative<TransferCustodyHandoff>
+    for protos::track_and_trace_payload::TransferCustodyHandoff
+{
+}
+
+#[derive(Debug)]
+pub enum TransferCustodyHandoffBuildError {
+    MissingField(String),
+}
+
+impl StdError for TransferCustodyHandoffBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            TransferCustodyHandoffBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            TransferCustodyHandoffBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TransferCustodyHandoffBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            TransferCustodyHandoffBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a TransferCustodyHandoff
+#[derive(Default, Clone)]
+pub struct TransferCustodyHandoffBuilder {
+    record_id: Option<String>,
+    receiving_agent: Option<String>,
+    timestamp: Option<u64>,
+    custodian_count: Option<u32>,
+}
+
+impl TransferCustodyHandoffBuilder {
+    pub fn new() -> Self {
+        TransferCustodyHandoffBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> TransferCustodyHandoffBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_receiving_agent(
+        mut self,
+        receiving_agent: String,
+    ) -> TransferCustodyHandoffBuilder {
+        self.receiving_agent = Some(receiving_agent);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> TransferCustodyHandoffBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn with_custodian_count(mut self, custodian_count: u32) -> TransferCustodyHandoffBuilder {
+        self.custodian_count = Some(custodian_count);
+        self
+    }
+
+    pub fn build(self) -> Result<TransferCustodyHandoff, TransferCustodyHandoffBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            TransferCustodyHandoffBuildError::MissingField(
+                "'record_id' field is required".to_string(),
+            )
+        })?;
+
+        let receiving_agent = self.receiving_agent.ok_or_else(|| {
+            TransferCustodyHandoffBuildError::MissingField(
+                "'receiving_agent' field is required".to_string(),
+            )
+        })?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            TransferCustodyHandoffBuildError::MissingField(
+                "'timestamp' field is required".to_string(),
+            )
+        })?;
+        if timestamp == 0 {
+            return Err(TransferCustodyHandoffBuildError::MissingField(
+                "'timestamp' cannot be 0".to_string(),
+            ));
+        }
+
+        let custodian_count = self.custodian_count.ok_or_else(|| {
+            TransferCustodyHandoffBuildError::MissingField(
+                "'custodian_count' field is required".to_string(),
+            )
+        })?;
+        if custodian_count == 0 {
+            return Err(TransferCustodyHandoffBuildError::MissingField(
+                "'custodian_count' cannot be 0".to_string(),
+            ));
+        }
+
+        Ok(TransferCustodyHandoff {
+            record_id,
+            receiving_agent,
+            timestamp,
+            custodian_count,
+        })
+    }
+}
+
+/// Native implementation of MigrateRecordTypeAction
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MigrateRecordTypeAction {
+    name: String,
+}
+
+impl MigrateRecordTypeAction {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl FromProto<protos::track_and_trace_payload::MigrateRecordTypeAction>
+    for MigrateRecordTypeAction
+{
+    fn from_proto(
+        migrate_record_type_action: protos::track_and_trace_payload::MigrateRecordTypeAction,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(MigrateRecordTypeAction {
+            name: migrate_record_type_action.get_name().to_string(),
+        })
+    }
+}
+
+impl FromNative<MigrateRecordTypeAction>
+    for protos::track_and_trace_payload::MigrateRecordTypeAction
+{
+    fn from_native(
+        migrate_record_type_action: MigrateRecordTypeAction,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut migrate_record_type_action_proto =
+            protos::track_and_trace_payload::MigrateRecordTypeAction::new();
+
+        migrate_record_type_action_proto.set_name(migrate_record_type_action.name().to_string());
+
+        Ok(migrate_record_type_action_proto)
+    }
+}
+
+impl FromBytes<MigrateRecordTypeAction> for MigrateRecordTypeAction {
+    fn from_bytes(bytes: &[u8]) -> Result<MigrateRecordTypeAction, ProtoConversionError> {
+        let proto: protos::track_and_trace_payload::MigrateRecordTypeAction =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get MigrateRecordTypeAction from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for MigrateRecordTypeAction {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from MigrateRecordTypeAction".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_payload::MigrateRecordTypeAction>
+    for MigrateRecordTypeAction
+{
+}
+impl IntoNative<MigrateRecordTypeAction>
+    for protos::track_and_trace_payload::MigrateRecordTypeAction
+{
+}
+
+#[derive(Debug)]
+pub enum MigrateRecordTypeActionBuildError {
+    MissingField(String),
+}
+
+impl StdError for MigrateRecordTypeActionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            MigrateRecordTypeActionBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            MigrateRecordTypeActionBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for MigrateRecordTypeActionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            MigrateRecordTypeActionBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a MigrateRecordTypeAction
+#[derive(Default, Clone)]
+pub struct MigrateRecordTypeActionBuilder {
+    name: Option<String>,
+}
+
+impl MigrateRecordTypeActionBuilder {
+    pub fn new() -> Self {
+        MigrateRecordTypeActionBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> MigrateRecordTypeActionBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn build(self) -> Result<MigrateRecordTypeAction, MigrateRecordTypeActionBuildError> {
+        let name = self.name.ok_or_else(|| {
+            MigrateRecordTypeActionBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        Ok(MigrateRecordTypeAction { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that a payload requires the action for its type and a timestamp
+    fn check_payload_builder() {
+        let result = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::CreateAgent)
+            .with_timestamp(1)
+            .build();
+        assert!(result.is_err());
+
+        let create_agent = CreateAgentActionBuilder::new()
+            .with_name("agent".to_string())
+            .build()
+            .unwrap();
+        let result = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::CreateAgent)
+            .with_create_agent(create_agent.clone())
+            .build();
+        assert!(result.is_err());
+
+        let payload = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::CreateAgent)
+            .with_timestamp(1)
+            .with_create_agent(create_agent.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(payload.action, Action::CreateAgent);
+        assert_eq!(payload.create_agent, create_agent);
+        assert_eq!(payload.create_record, CreateRecordAction::default());
+    }
+
+    #[test]
+    // check that a migrate record type payload requires the migrate record type action
+    fn check_migrate_record_type_payload_builder() {
+        let result = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::MigrateRecordType)
+            .with_timestamp(1)
+            .build();
+        assert!(result.is_err());
+
+        let migrate_record_type = MigrateRecordTypeActionBuilder::new()
+            .with_name("type".to_string())
+            .build()
+            .unwrap();
+        let payload = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::MigrateRecordType)
+            .with_timestamp(1)
+            .with_migrate_record_type(migrate_record_type.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(payload.migrate_record_type, migrate_record_type);
+
+        let bytes = payload.clone().into_bytes().unwrap();
+        assert_eq!(TrackAndTracePayload::from_bytes(&bytes).unwrap(), payload);
+    }
+
+    #[test]
+    // check that a create record action requires a record id
+    fn check_create_record_builder() {
+        let result = CreateRecordActionBuilder::new()
+            .with_record_id("".to_string())
+            .with_record_type("type".to_string())
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    // check that a create proposal action carries its terms and expiry through bytes
+    fn check_create_proposal_bytes() {
+        let original = CreateProposalActionBuilder::new()
+            .with_record_id("record".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_role(Role::Owner)
+            .with_terms("terms".to_string())
+            .with_expiry(10)
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let create_proposal = CreateProposalAction::from_bytes(&bytes).unwrap();
+        assert_eq!(create_proposal, original);
+        assert_eq!(create_proposal.terms(), "terms");
+        assert_eq!(*create_proposal.expiry(), 10);
+    }
+
+    #[test]
+    // check that a transfer custody payload carries the signed handoff bytes unchanged
+    fn check_transfer_custody_payload() {
+        let handoff = TransferCustodyHandoffBuilder::new()
+            .with_record_id("record".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_timestamp(1)
+            .with_custodian_count(1)
+            .build()
+            .unwrap();
+        let handoff_bytes = handoff.clone().into_bytes().unwrap();
+
+        let transfer_custody = TransferCustodyActionBuilder::new()
+            .with_handoff(handoff_bytes.clone())
+            .with_receiving_agent_signature("signature".to_string())
+            .build()
+            .unwrap();
+        let original = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::TransferCustody)
+            .with_timestamp(1)
+            .with_transfer_custody(transfer_custody)
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let payload = TrackAndTracePayload::from_bytes(&bytes).unwrap();
+        assert_eq!(payload, original);
+        assert_eq!(payload.transfer_custody().handoff(), &handoff_bytes[..]);
+        assert_eq!(
+            TransferCustodyHandoff::from_bytes(payload.transfer_custody().handoff()).unwrap(),
+            handoff
+        );
+
+        assert!(TransferCustodyHandoffBuilder::new()
+            .with_record_id("record".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_timestamp(0)
+            .with_custodian_count(1)
+            .build()
+            .is_err());
+        assert!(TransferCustodyHandoffBuilder::new()
+            .with_record_id("record".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_timestamp(1)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    // check that a payload can be converted to bytes and back
+    fn check_payload_bytes() {
+        let answer_proposal = AnswerProposalActionBuilder::new()
+            .with_record_id("record".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_role(Role::Owner)
+            .with_response(Response::Reject)
+            .build()
+            .unwrap();
+        let original = TrackAndTracePayloadBuilder::new()
+            .with_action(Action::AnswerProposal)
+            .with_timestamp(1)
+            .with_answer_proposal(answer_proposal)
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let payload = TrackAndTracePayload::from_bytes(&bytes).unwrap();
+        assert_eq!(payload, original);
+    }
+}
@@ -0,0 +1,2161 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf::Message;
+use protobuf::RepeatedField;
+
+use std::error::Error as StdError;
+
+use crate::decimal::Decimal;
+use crate::protos;
+use crate::protos::{
+    FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
+};
+
+/// Native implementation of PropertySchema_DataType enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataType {
+    Bytes,
+    Boolean,
+    Number,
+    String,
+    Enum,
+    Struct,
+    Location,
+}
+
+impl FromProto<protos::track_and_trace_property::PropertySchema_DataType> for DataType {
+    fn from_proto(
+        data_type: protos::track_and_trace_property::PropertySchema_DataType,
+    ) -> Result<Self, ProtoConversionError> {
+        match data_type {
+            protos::track_and_trace_property::PropertySchema_DataType::BYTES => Ok(DataType::Bytes),
+            protos::track_and_trace_property::PropertySchema_DataType::BOOLEAN => {
+                Ok(DataType::Boolean)
+            }
+            protos::track_and_trace_property::PropertySchema_DataType::NUMBER => {
+                Ok(DataType::Number)
+            }
+            protos::track_and_trace_property::PropertySchema_DataType::STRING => {
+                Ok(DataType::String)
+            }
+            protos::track_and_trace_property::PropertySchema_DataType::ENUM => Ok(DataType::Enum),
+            protos::track_and_trace_property::PropertySchema_DataType::STRUCT => {
+                Ok(DataType::Struct)
+            }
+            protos::track_and_trace_property::PropertySchema_DataType::LOCATION => {
+                Ok(DataType::Location)
+            }
+            protos::track_and_trace_property::PropertySchema_DataType::TYPE_UNSET => {
+                Err(ProtoConversionError::InvalidTypeError(
+                    "Cannot convert PropertySchema_DataType with type unset.".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+impl FromNative<DataType> for protos::track_and_trace_property::PropertySchema_DataType {
+    fn from_native(data_type: DataType) -> Result<Self, ProtoConversionError> {
+        match data_type {
+            DataType::Bytes => Ok(protos::track_and_trace_property::PropertySchema_DataType::BYTES),
+            DataType::Boolean => {
+                Ok(protos::track_and_trace_property::PropertySchema_DataType::BOOLEAN)
+            }
+            DataType::Number => {
+                Ok(protos::track_and_trace_property::PropertySchema_DataType::NUMBER)
+            }
+            DataType::String => {
+                Ok(protos::track_and_trace_property::PropertySchema_DataType::STRING)
+            }
+            DataType::Enum => Ok(protos::track_and_trace_property::PropertySchema_DataType::ENUM),
+            DataType::Struct => {
+                Ok(protos::track_and_trace_property::PropertySchema_DataType::STRUCT)
+            }
+            DataType::Location => {
+                Ok(protos::track_and_trace_property::PropertySchema_DataType::LOCATION)
+            }
+        }
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertySchema_DataType> for DataType {}
+impl IntoNative<DataType> for protos::track_and_trace_property::PropertySchema_DataType {}
+
+/// Native implementation of PropertySchema
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertySchema {
+    name: String,
+    data_type: DataType,
+    required: bool,
+    fixed: bool,
+    delayed: bool,
+    number_exponent: i32,
+    enum_options: Vec<String>,
+    struct_properties: Vec<PropertySchema>,
+    unit: String,
+}
+
+impl PropertySchema {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn required(&self) -> &bool {
+        &self.required
+    }
+
+    pub fn fixed(&self) -> &bool {
+        &self.fixed
+    }
+
+    pub fn delayed(&self) -> &bool {
+        &self.delayed
+    }
+
+    pub fn number_exponent(&self) -> &i32 {
+        &self.number_exponent
+    }
+
+    pub fn enum_options(&self) -> &[String] {
+        &self.enum_options
+    }
+
+    pub fn struct_properties(&self) -> &[PropertySchema] {
+        &self.struct_properties
+    }
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::PropertySchema> for PropertySchema {
+    fn from_proto(
+        property_schema: protos::track_and_trace_property::PropertySchema,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PropertySchema {
+            name: property_schema.get_name().to_string(),
+            data_type: DataType::from_proto(property_schema.get_data_type())?,
+            required: property_schema.get_required(),
+            fixed: property_schema.get_fixed(),
+            delayed: property_schema.get_delayed(),
+            number_exponent: property_schema.get_number_exponent(),
+            enum_options: property_schema.get_enum_options().to_vec(),
+            struct_properties: property_schema
+                .get_struct_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::from_proto)
+                .collect::<Result<Vec<PropertySchema>, ProtoConversionError>>()?,
+            unit: property_schema.get_unit().to_string(),
+        })
+    }
+}
+
+impl FromNative<PropertySchema> for protos::track_and_trace_property::PropertySchema {
+    fn from_native(property_schema: PropertySchema) -> Result<Self, ProtoConversionError> {
+        let mut property_schema_proto = protos::track_and_trace_property::PropertySchema::new();
+
+        property_schema_proto.set_name(property_schema.name().to_string());
+        property_schema_proto.set_data_type(property_schema.data_type().clone().into_proto()?);
+        property_schema_proto.set_required(*property_schema.required());
+        property_schema_proto.set_fixed(*property_schema.fixed());
+        property_schema_proto.set_delayed(*property_schema.delayed());
+        property_schema_proto.set_number_exponent(*property_schema.number_exponent());
+        property_schema_proto.set_enum_options(RepeatedField::from_vec(
+            property_schema.enum_options().to_vec(),
+        ));
+        property_schema_proto.set_struct_properties(RepeatedField::from_vec(
+            property_schema
+                .struct_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::PropertySchema>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        property_schema_proto.set_unit(property_schema.unit().to_string());
+
+        Ok(property_schema_proto)
+    }
+}
+
+impl FromBytes<PropertySchema> for PropertySchema {
+    fn from_bytes(bytes: &[u8]) -> Result<PropertySchema, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::PropertySchema =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PropertySchema from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PropertySchema {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PropertySchema".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertySchema> for PropertySchema {}
+impl IntoNative<PropertySchema> for protos::track_and_trace_property::PropertySchema {}
+
+#[derive(Debug)]
+pub enum PropertySchemaBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertySchemaBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertySchemaBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertySchemaBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertySchemaBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertySchemaBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a PropertySchema
+#[derive(Default, Clone)]
+pub struct PropertySchemaBuilder {
+    pub name: Option<String>,
+    pub data_type: Option<DataType>,
+    pub required: Option<bool>,
+    pub fixed: Option<bool>,
+    pub delayed: Option<bool>,
+    pub number_exponent: Option<i32>,
+    pub enum_options: Vec<String>,
+    pub struct_properties: Vec<PropertySchema>,
+    pub unit: Option<String>,
+}
+
+impl PropertySchemaBuilder {
+    pub fn new() -> Self {
+        PropertySchemaBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> PropertySchemaBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_data_type(mut self, data_type: DataType) -> PropertySchemaBuilder {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    pub fn with_required(mut self, required: bool) -> PropertySchemaBuilder {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn with_fixed(mut self, fixed: bool) -> PropertySchemaBuilder {
+        self.fixed = Some(fixed);
+        self
+    }
+
+    pub fn with_delayed(mut self, delayed: bool) -> PropertySchemaBuilder {
+        self.delayed = Some(delayed);
+        self
+    }
+
+    pub fn with_number_exponent(mut self, number_exponent: i32) -> PropertySchemaBuilder {
+        self.number_exponent = Some(number_exponent);
+        self
+    }
+
+    pub fn with_enum_options(mut self, enum_options: Vec<String>) -> PropertySchemaBuilder {
+        self.enum_options = enum_options;
+        self
+    }
+
+    pub fn with_struct_properties(
+        mut self,
+        struct_properties: Vec<PropertySchema>,
+    ) -> PropertySchemaBuilder {
+        self.struct_properties = struct_properties;
+        self
+    }
+
+    pub fn with_unit(mut self, unit: String) -> PropertySchemaBuilder {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub fn build(self) -> Result<PropertySchema, PropertySchemaBuildError> {
+        let name = self.name.ok_or_else(|| {
+            PropertySchemaBuildError::MissingField("'name' field is required".to_string())
+        })?;
+        if name.is_empty() {
+            return Err(PropertySchemaBuildError::MissingField(
+                "'name' cannot be empty".to_string(),
+            ));
+        }
+
+        let data_type = self.data_type.ok_or_else(|| {
+            PropertySchemaBuildError::MissingField("'data_type' field is required".to_string())
+        })?;
+
+        let required = self.required.unwrap_or_default();
+
+        let fixed = self.fixed.unwrap_or_default();
+
+        let delayed = self.delayed.unwrap_or_default();
+
+        let number_exponent = {
+            if data_type == DataType::Number {
+                self.number_exponent.ok_or_else(|| {
+                    PropertySchemaBuildError::MissingField(
+                        "'number_exponent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                0
+            }
+        };
+
+        let enum_options = {
+            if data_type == DataType::Enum {
+                if self.enum_options.is_empty() {
+                    return Err(PropertySchemaBuildError::MissingField(
+                        "'enum_options' cannot be empty".to_string(),
+                    ));
+                }
+                self.enum_options
+            } else {
+                self.enum_options
+            }
+        };
+
+        let struct_properties = {
+            if data_type == DataType::Struct {
+                if self.struct_properties.is_empty() {
+                    return Err(PropertySchemaBuildError::MissingField(
+                        "'struct_properties' cannot be empty".to_string(),
+                    ));
+                }
+                self.struct_properties
+            } else {
+                self.struct_properties
+            }
+        };
+
+        let unit = self.unit.unwrap_or_default();
+
+        Ok(PropertySchema {
+            name,
+            data_type,
+            required,
+            fixed,
+            delayed,
+            number_exponent,
+            enum_options,
+            struct_properties,
+            unit,
+        })
+    }
+}
+
+/// Native implementation of Property_Reporter
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reporter {
+    public_key: String,
+    authorized: bool,
+    index: u32,
+}
+
+impl Reporter {
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    pub fn authorized(&self) -> &bool {
+        &self.authorized
+    }
+
+    pub fn index(&self) -> &u32 {
+        &self.index
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::Property_Reporter> for Reporter {
+    fn from_proto(
+        reporter: protos::track_and_trace_property::Property_Reporter,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Reporter {
+            public_key: reporter.get_public_key().to_string(),
+            authorized: reporter.get_authorized(),
+            index: reporter.get_index(),
+        })
+    }
+}
+
+impl FromNative<Reporter> for protos::track_and_trace_property::Property_Reporter {
+    fn from_native(reporter: Reporter) -> Result<Self, ProtoConversionError> {
+        let mut reporter_proto = protos::track_and_trace_property::Property_Reporter::new();
+
+        reporter_proto.set_public_key(reporter.public_key().to_string());
+        reporter_proto.set_authorized(*reporter.authorized());
+        reporter_proto.set_index(*reporter.index());
+
+        Ok(reporter_proto)
+    }
+}
+
+impl FromBytes<Reporter> for Reporter {
+    fn from_bytes(bytes: &[u8]) -> Result<Reporter, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::Property_Reporter =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get Reporter from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Reporter {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Reporter".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::Property_Reporter> for Reporter {}
+impl IntoNative<Reporter> for protos::track_and_trace_property::Property_Reporter {}
+
+#[derive(Debug)]
+pub enum ReporterBuildError {
+    MissingField(String),
+}
+
+impl StdError for ReporterBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            ReporterBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            ReporterBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ReporterBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ReporterBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Reporter
+#[derive(Default, Clone)]
+pub struct ReporterBuilder {
+    pub public_key: Option<String>,
+    pub authorized: Option<bool>,
+    pub index: Option<u32>,
+}
+
+impl ReporterBuilder {
+    pub fn new() -> Self {
+        ReporterBuilder::default()
+    }
+
+    pub fn with_public_key(mut self, public_key: String) -> ReporterBuilder {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    pub fn with_authorized(mut self, authorized: bool) -> ReporterBuilder {
+        self.authorized = Some(authorized);
+        self
+    }
+
+    pub fn with_index(mut self, index: u32) -> ReporterBuilder {
+        self.index = Some(index);
+        self
+    }
+
+    pub fn build(self) -> Result<Reporter, ReporterBuildError> {
+        let public_key = self.public_key.ok_or_else(|| {
+            ReporterBuildError::MissingField("'public_key' field is required".to_string())
+        })?;
+
+        let authorized = self.authorized.unwrap_or_default();
+
+        let index = self.index.ok_or_else(|| {
+            ReporterBuildError::MissingField("'index' field is required".to_string())
+        })?;
+
+        Ok(Reporter {
+            public_key,
+            authorized,
+            index,
+        })
+    }
+}
+
+/// Native implementation of Property
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    name: String,
+    record_id: String,
+    data_type: DataType,
+    reporters: Vec<Reporter>,
+    current_page: u32,
+    wrapped: bool,
+    fixed: bool,
+    number_exponent: i32,
+    enum_options: Vec<String>,
+    struct_properties: Vec<PropertySchema>,
+    unit: String,
+}
+
+impl Property {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn reporters(&self) -> &[Reporter] {
+        &self.reporters
+    }
+
+    pub fn current_page(&self) -> &u32 {
+        &self.current_page
+    }
+
+    pub fn wrapped(&self) -> &bool {
+        &self.wrapped
+    }
+
+    pub fn fixed(&self) -> &bool {
+        &self.fixed
+    }
+
+    pub fn number_exponent(&self) -> &i32 {
+        &self.number_exponent
+    }
+
+    pub fn enum_options(&self) -> &[String] {
+        &self.enum_options
+    }
+
+    pub fn struct_properties(&self) -> &[PropertySchema] {
+        &self.struct_properties
+    }
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::Property> for Property {
+    fn from_proto(
+        property: protos::track_and_trace_property::Property,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Property {
+            name: property.get_name().to_string(),
+            record_id: property.get_record_id().to_string(),
+            data_type: DataType::from_proto(property.get_data_type())?,
+            reporters: property
+                .get_reporters()
+                .to_vec()
+                .into_iter()
+                .map(Reporter::from_proto)
+                .collect::<Result<Vec<Reporter>, ProtoConversionError>>()?,
+            current_page: property.get_current_page(),
+            wrapped: property.get_wrapped(),
+            fixed: property.get_fixed(),
+            number_exponent: property.get_number_exponent(),
+            enum_options: property.get_enum_options().to_vec(),
+            struct_properties: property
+                .get_struct_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::from_proto)
+                .collect::<Result<Vec<PropertySchema>, ProtoConversionError>>()?,
+            unit: property.get_unit().to_string(),
+        })
+    }
+}
+
+impl FromNative<Property> for protos::track_and_trace_property::Property {
+    fn from_native(property: Property) -> Result<Self, ProtoConversionError> {
+        let mut property_proto = protos::track_and_trace_property::Property::new();
+
+        property_proto.set_name(property.name().to_string());
+        property_proto.set_record_id(property.record_id().to_string());
+        property_proto.set_data_type(property.data_type().clone().into_proto()?);
+        property_proto.set_reporters(RepeatedField::from_vec(
+            property
+                .reporters()
+                .to_vec()
+                .into_iter()
+                .map(Reporter::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::Property_Reporter>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        property_proto.set_current_page(*property.current_page());
+        property_proto.set_wrapped(*property.wrapped());
+        property_proto.set_fixed(*property.fixed());
+        property_proto.set_number_exponent(*property.number_exponent());
+        property_proto.set_enum_options(RepeatedField::from_vec(property.enum_options().to_vec()));
+        property_proto.set_struct_properties(RepeatedField::from_vec(
+            property
+                .struct_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::PropertySchema>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        property_proto.set_unit(property.unit().to_string());
+
+        Ok(property_proto)
+    }
+}
+
+impl FromBytes<Property> for Property {
+    fn from_bytes(bytes: &[u8]) -> Result<Property, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::Property = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get Property from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Property {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Property".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::Property> for Property {}
+impl IntoNative<Property> for protos::track_and_trace_property::Property {}
+
+#[derive(Debug)]
+pub enum PropertyBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertyBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertyBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Property
+#[derive(Default, Clone)]
+pub struct PropertyBuilder {
+    pub name: Option<String>,
+    pub record_id: Option<String>,
+    pub data_type: Option<DataType>,
+    pub reporters: Vec<Reporter>,
+    pub current_page: Option<u32>,
+    pub wrapped: Option<bool>,
+    pub fixed: Option<bool>,
+    pub number_exponent: Option<i32>,
+    pub enum_options: Vec<String>,
+    pub struct_properties: Vec<PropertySchema>,
+    pub unit: Option<String>,
+}
+
+impl PropertyBuilder {
+    pub fn new() -> Self {
+        PropertyBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> PropertyBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> PropertyBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_data_type(mut self, data_type: DataType) -> PropertyBuilder {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    pub fn with_reporters(mut self, reporters: Vec<Reporter>) -> PropertyBuilder {
+        self.reporters = reporters;
+        self
+    }
+
+    pub fn with_current_page(mut self, current_page: u32) -> PropertyBuilder {
+        self.current_page = Some(current_page);
+        self
+    }
+
+    pub fn with_wrapped(mut self, wrapped: bool) -> PropertyBuilder {
+        self.wrapped = Some(wrapped);
+        self
+    }
+
+    pub fn with_fixed(mut self, fixed: bool) -> PropertyBuilder {
+        self.fixed = Some(fixed);
+        self
+    }
+
+    pub fn with_number_exponent(mut self, number_exponent: i32) -> PropertyBuilder {
+        self.number_exponent = Some(number_exponent);
+        self
+    }
+
+    pub fn with_enum_options(mut self, enum_options: Vec<String>) -> PropertyBuilder {
+        self.enum_options = enum_options;
+        self
+    }
+
+    pub fn with_struct_properties(
+        mut self,
+        struct_properties: Vec<PropertySchema>,
+    ) -> PropertyBuilder {
+        self.struct_properties = struct_properties;
+        self
+    }
+
+    pub fn with_unit(mut self, unit: String) -> PropertyBuilder {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub fn build(self) -> Result<Property, PropertyBuildError> {
+        let name = self.name.ok_or_else(|| {
+            PropertyBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let record_id = self.record_id.ok_or_else(|| {
+            PropertyBuildError::MissingField("'record_id' field is required".to_string())
+        })?;
+
+        let data_type = self.data_type.ok_or_else(|| {
+            PropertyBuildError::MissingField("'data_type' field is required".to_string())
+        })?;
+
+        let reporters = self.reporters;
+
+        let current_page = self.current_page.unwrap_or(1);
+
+        let wrapped = self.wrapped.unwrap_or_default();
+
+        let fixed = self.fixed.unwrap_or_default();
+
+        let number_exponent = {
+            if data_type == DataType::Number {
+                self.number_exponent.ok_or_else(|| {
+                    PropertyBuildError::MissingField(
+                        "'number_exponent' field is required".to_string(),
+                    )
+                })?
+            } else {
+                0
+            }
+        };
+
+        let enum_options = {
+            if data_type == DataType::Enum {
+                if self.enum_options.is_empty() {
+                    return Err(PropertyBuildError::MissingField(
+                        "'enum_options' cannot be empty".to_string(),
+                    ));
+                }
+                self.enum_options
+            } else {
+                self.enum_options
+            }
+        };
+
+        let struct_properties = {
+            if data_type == DataType::Struct {
+                if self.struct_properties.is_empty() {
+                    return Err(PropertyBuildError::MissingField(
+                        "'struct_properties' cannot be empty".to_string(),
+                    ));
+                }
+                self.struct_properties
+            } else {
+                self.struct_properties
+            }
+        };
+
+        let unit = self.unit.unwrap_or_default();
+
+        Ok(Property {
+            name,
+            record_id,
+            data_type,
+            reporters,
+            current_page,
+            wrapped,
+            fixed,
+            number_exponent,
+            enum_options,
+            struct_properties,
+            unit,
+        })
+    }
+}
+
+/// Native implementation of PropertyContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyContainer {
+    entries: Vec<Property>,
+}
+
+impl PropertyContainer {
+    pub fn entries(&self) -> &[Property] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::PropertyContainer> for PropertyContainer {
+    fn from_proto(
+        property_container: protos::track_and_trace_property::PropertyContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PropertyContainer {
+            entries: property_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(Property::from_proto)
+                .collect::<Result<Vec<Property>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<PropertyContainer> for protos::track_and_trace_property::PropertyContainer {
+    fn from_native(property_container: PropertyContainer) -> Result<Self, ProtoConversionError> {
+        let mut property_container_proto =
+            protos::track_and_trace_property::PropertyContainer::new();
+
+        property_container_proto.set_entries(RepeatedField::from_vec(
+            property_container
+                .entries()
+                .to_vec()
+                .into_iter()
+                .map(Property::into_proto)
+                .collect::<Result<Vec<protos::track_and_trace_property::Property>, ProtoConversionError>>()?,
+        ));
+
+        Ok(property_container_proto)
+    }
+}
+
+impl FromBytes<PropertyContainer> for PropertyContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<PropertyContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::PropertyContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PropertyContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PropertyContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PropertyContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertyContainer> for PropertyContainer {}
+impl IntoNative<PropertyContainer> for protos::track_and_trace_property::PropertyContainer {}
+
+#[derive(Debug)]
+pub enum PropertyContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertyContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertyContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyContainerBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a PropertyContainer
+#[derive(Default, Clone)]
+pub struct PropertyContainerBuilder {
+    pub entries: Vec<Property>,
+}
+
+impl PropertyContainerBuilder {
+    pub fn new() -> Self {
+        PropertyContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<Property>) -> PropertyContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<PropertyContainer, PropertyContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(PropertyContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(PropertyContainer { entries })
+    }
+}
+
+/// Native implementation of Location; coordinates are in millionths of a degree
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    latitude: i64,
+    longitude: i64,
+}
+
+impl Location {
+    pub fn latitude(&self) -> &i64 {
+        &self.latitude
+    }
+
+    pub fn longitude(&self) -> &i64 {
+        &self.longitude
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::Location> for Location {
+    fn from_proto(
+        location: protos::track_and_trace_property::Location,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Location {
+            latitude: location.get_latitude(),
+            longitude: location.get_longitude(),
+        })
+    }
+}
+
+impl FromNative<Location> for protos::track_and_trace_property::Location {
+    fn from_native(location: Location) -> Result<Self, ProtoConversionError> {
+        let mut location_proto = protos::track_and_trace_property::Location::new();
+
+        location_proto.set_latitude(*location.latitude());
+        location_proto.set_longitude(*location.longitude());
+
+        Ok(location_proto)
+    }
+}
+
+impl FromBytes<Location> for Location {
+    fn from_bytes(bytes: &[u8]) -> Result<Location, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::Location = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get Location from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Location {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Location".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::Location> for Location {}
+impl IntoNative<Location> for protos::track_and_trace_property::Location {}
+
+#[derive(Debug)]
+pub enum LocationBuildError {
+    MissingField(String),
+}
+
+impl StdError for LocationBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            LocationBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            LocationBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LocationBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            LocationBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Location
+#[derive(Default, Clone)]
+pub struct LocationBuilder {
+    pub latitude: Option<i64>,
+    pub longitude: Option<i64>,
+}
+
+impl LocationBuilder {
+    pub fn new() -> Self {
+        LocationBuilder::default()
+    }
+
+    pub fn with_latitude(mut self, latitude: i64) -> LocationBuilder {
+        self.latitude = Some(latitude);
+        self
+    }
+
+    pub fn with_longitude(mut self, longitude: i64) -> LocationBuilder {
+        self.longitude = Some(longitude);
+        self
+    }
+
+    pub fn build(self) -> Result<Location, LocationBuildError> {
+        let latitude = self.latitude.ok_or_else(|| {
+            LocationBuildError::MissingField("'latitude' field is required".to_string())
+        })?;
+
+        let longitude = self.longitude.ok_or_else(|| {
+            LocationBuildError::MissingField("'longitude' field is required".to_string())
+        })?;
+
+        Ok(Location {
+            latitude,
+            longitude,
+        })
+    }
+}
+
+/// Native implementation of TrackAndTracePropertyValue
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyValue {
+    name: String,
+    data_type: DataType,
+    bytes_value: Vec<u8>,
+    boolean_value: bool,
+    number_value: i64,
+    string_value: String,
+    enum_value: String,
+    struct_values: Vec<PropertyValue>,
+    location_value: Option<Location>,
+}
+
+impl PropertyValue {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    pub fn bytes_value(&self) -> &[u8] {
+        &self.bytes_value
+    }
+
+    pub fn boolean_value(&self) -> &bool {
+        &self.boolean_value
+    }
+
+    pub fn number_value(&self) -> &i64 {
+        &self.number_value
+    }
+
+    pub fn string_value(&self) -> &str {
+        &self.string_value
+    }
+
+    pub fn enum_value(&self) -> &str {
+        &self.enum_value
+    }
+
+    pub fn struct_values(&self) -> &[PropertyValue] {
+        &self.struct_values
+    }
+
+    pub fn location_value(&self) -> Option<&Location> {
+        self.location_value.as_ref()
+    }
+
+    /// Returns the number value as a decimal, scaled by the `number_exponent` of the property
+    pub fn decimal_value(&self, property: &Property) -> Decimal {
+        Decimal::new(self.number_value, *property.number_exponent())
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::TrackAndTracePropertyValue> for PropertyValue {
+    fn from_proto(
+        property_value: protos::track_and_trace_property::TrackAndTracePropertyValue,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PropertyValue {
+            name: property_value.get_name().to_string(),
+            data_type: DataType::from_proto(property_value.get_data_type())?,
+            bytes_value: property_value.get_bytes_value().to_vec(),
+            boolean_value: property_value.get_boolean_value(),
+            number_value: property_value.get_number_value(),
+            string_value: property_value.get_string_value().to_string(),
+            enum_value: property_value.get_enum_value().to_string(),
+            struct_values: property_value
+                .get_struct_values()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::from_proto)
+                .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            location_value: if property_value.has_location_value() {
+                Some(Location::from_proto(
+                    property_value.get_location_value().clone(),
+                )?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl FromNative<PropertyValue> for protos::track_and_trace_property::TrackAndTracePropertyValue {
+    fn from_native(property_value: PropertyValue) -> Result<Self, ProtoConversionError> {
+        let mut property_value_proto =
+            protos::track_and_trace_property::TrackAndTracePropertyValue::new();
+
+        property_value_proto.set_name(property_value.name().to_string());
+        property_value_proto.set_data_type(property_value.data_type().clone().into_proto()?);
+        property_value_proto.set_bytes_value(property_value.bytes_value().to_vec());
+        property_value_proto.set_boolean_value(*property_value.boolean_value());
+        property_value_proto.set_number_value(*property_value.number_value());
+        property_value_proto.set_string_value(property_value.string_value().to_string());
+        property_value_proto.set_enum_value(property_value.enum_value().to_string());
+        property_value_proto.set_struct_values(RepeatedField::from_vec(
+            property_value
+                .struct_values()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::TrackAndTracePropertyValue>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        if let Some(location_value) = property_value.location_value() {
+            property_value_proto.set_location_value(location_value.clone().into_proto()?);
+        }
+
+        Ok(property_value_proto)
+    }
+}
+
+impl FromBytes<PropertyValue> for PropertyValue {
+    fn from_bytes(bytes: &[u8]) -> Result<PropertyValue, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::TrackAndTracePropertyValue =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PropertyValue from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PropertyValue {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PropertyValue".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::TrackAndTracePropertyValue> for PropertyValue {}
+impl IntoNative<PropertyValue> for protos::track_and_trace_property::TrackAndTracePropertyValue {}
+
+#[derive(Debug)]
+pub enum PropertyValueBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertyValueBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyValueBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertyValueBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyValueBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyValueBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a PropertyValue
+#[derive(Default, Clone)]
+pub struct PropertyValueBuilder {
+    pub name: Option<String>,
+    pub data_type: Option<DataType>,
+    pub bytes_value: Option<Vec<u8>>,
+    pub boolean_value: Option<bool>,
+    pub number_value: Option<i64>,
+    pub string_value: Option<String>,
+    pub enum_value: Option<String>,
+    pub struct_values: Vec<PropertyValue>,
+    pub location_value: Option<Location>,
+}
+
+impl PropertyValueBuilder {
+    pub fn new() -> Self {
+        PropertyValueBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> PropertyValueBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_data_type(mut self, data_type: DataType) -> PropertyValueBuilder {
+        self.data_type = Some(data_type);
+        self
+    }
+
+    pub fn with_bytes_value(mut self, bytes_value: Vec<u8>) -> PropertyValueBuilder {
+        self.bytes_value = Some(bytes_value);
+        self
+    }
+
+    pub fn with_boolean_value(mut self, boolean_value: bool) -> PropertyValueBuilder {
+        self.boolean_value = Some(boolean_value);
+        self
+    }
+
+    pub fn with_number_value(mut self, number_value: i64) -> PropertyValueBuilder {
+        self.number_value = Some(number_value);
+        self
+    }
+
+    pub fn with_string_value(mut self, string_value: String) -> PropertyValueBuilder {
+        self.string_value = Some(string_value);
+        self
+    }
+
+    pub fn with_enum_value(mut self, enum_value: String) -> PropertyValueBuilder {
+        self.enum_value = Some(enum_value);
+        self
+    }
+
+    pub fn with_struct_values(mut self, struct_values: Vec<PropertyValue>) -> PropertyValueBuilder {
+        self.struct_values = struct_values;
+        self
+    }
+
+    pub fn with_location_value(mut self, location_value: Location) -> PropertyValueBuilder {
+        self.location_value = Some(location_value);
+        self
+    }
+
+    pub fn build(self) -> Result<PropertyValue, PropertyValueBuildError> {
+        let name = self.name.ok_or_else(|| {
+            PropertyValueBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let data_type = self.data_type.ok_or_else(|| {
+            PropertyValueBuildError::MissingField("'data_type' field is required".to_string())
+        })?;
+
+        let bytes_value = {
+            if data_type == DataType::Bytes {
+                self.bytes_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'bytes_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                vec![]
+            }
+        };
+
+        let boolean_value = {
+            if data_type == DataType::Boolean {
+                self.boolean_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'boolean_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                false
+            }
+        };
+
+        let number_value = {
+            if data_type == DataType::Number {
+                self.number_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'number_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                0
+            }
+        };
+
+        let string_value = {
+            if data_type == DataType::String {
+                self.string_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'string_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                "".to_string()
+            }
+        };
+
+        let enum_value = {
+            if data_type == DataType::Enum {
+                self.enum_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'enum_value' field is required".to_string(),
+                    )
+                })?
+            } else {
+                "".to_string()
+            }
+        };
+
+        let struct_values = {
+            if data_type == DataType::Struct {
+                if self.struct_values.is_empty() {
+                    return Err(PropertyValueBuildError::MissingField(
+                        "'struct_values' cannot be empty".to_string(),
+                    ));
+                }
+                self.struct_values
+            } else {
+                self.struct_values
+            }
+        };
+
+        let location_value = {
+            if data_type == DataType::Location {
+                Some(self.location_value.ok_or_else(|| {
+                    PropertyValueBuildError::MissingField(
+                        "'location_value' field is required".to_string(),
+                    )
+                })?)
+            } else {
+                None
+            }
+        };
+
+        Ok(PropertyValue {
+            name,
+            data_type,
+            bytes_value,
+            boolean_value,
+            number_value,
+            string_value,
+            enum_value,
+            struct_values,
+            location_value,
+        })
+    }
+}
+
+/// Native implementation of PropertyPage_ReportedValue
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportedValue {
+    reporter_index: u32,
+    timestamp: u64,
+    bytes_value: Vec<u8>,
+    boolean_value: bool,
+    number_value: i64,
+    string_value: String,
+    enum_value: u32,
+    struct_values: Vec<PropertyValue>,
+    location_value: Option<Location>,
+}
+
+impl ReportedValue {
+    pub fn reporter_index(&self) -> &u32 {
+        &self.reporter_index
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+
+    pub fn bytes_value(&self) -> &[u8] {
+        &self.bytes_value
+    }
+
+    pub fn boolean_value(&self) -> &bool {
+        &self.boolean_value
+    }
+
+    pub fn number_value(&self) -> &i64 {
+        &self.number_value
+    }
+
+    pub fn string_value(&self) -> &str {
+        &self.string_value
+    }
+
+    pub fn enum_value(&self) -> &u32 {
+        &self.enum_value
+    }
+
+    pub fn struct_values(&self) -> &[PropertyValue] {
+        &self.struct_values
+    }
+
+    pub fn location_value(&self) -> Option<&Location> {
+        self.location_value.as_ref()
+    }
+
+    /// Returns the number value as a decimal, scaled by the `number_exponent` of the property
+    pub fn decimal_value(&self, property: &Property) -> Decimal {
+        Decimal::new(self.number_value, *property.number_exponent())
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::PropertyPage_ReportedValue> for ReportedValue {
+    fn from_proto(
+        reported_value: protos::track_and_trace_property::PropertyPage_ReportedValue,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(ReportedValue {
+            reporter_index: reported_value.get_reporter_index(),
+            timestamp: reported_value.get_timestamp(),
+            bytes_value: reported_value.get_bytes_value().to_vec(),
+            boolean_value: reported_value.get_boolean_value(),
+            number_value: reported_value.get_number_value(),
+            string_value: reported_value.get_string_value().to_string(),
+            enum_value: reported_value.get_enum_value(),
+            struct_values: reported_value
+                .get_struct_values()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::from_proto)
+                .collect::<Result<Vec<PropertyValue>, ProtoConversionError>>()?,
+            location_value: if reported_value.has_location_value() {
+                Some(Location::from_proto(
+                    reported_value.get_location_value().clone(),
+                )?)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl FromNative<ReportedValue> for protos::track_and_trace_property::PropertyPage_ReportedValue {
+    fn from_native(reported_value: ReportedValue) -> Result<Self, ProtoConversionError> {
+        let mut reported_value_proto =
+            protos::track_and_trace_property::PropertyPage_ReportedValue::new();
+
+        reported_value_proto.set_reporter_index(*reported_value.reporter_index());
+        reported_value_proto.set_timestamp(*reported_value.timestamp());
+        reported_value_proto.set_bytes_value(reported_value.bytes_value().to_vec());
+        reported_value_proto.set_boolean_value(*reported_value.boolean_value());
+        reported_value_proto.set_number_value(*reported_value.number_value());
+        reported_value_proto.set_string_value(reported_value.string_value().to_string());
+        reported_value_proto.set_enum_value(*reported_value.enum_value());
+        reported_value_proto.set_struct_values(RepeatedField::from_vec(
+            reported_value
+                .struct_values()
+                .to_vec()
+                .into_iter()
+                .map(PropertyValue::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::TrackAndTracePropertyValue>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        if let Some(location_value) = reported_value.location_value() {
+            reported_value_proto.set_location_value(location_value.clone().into_proto()?);
+        }
+
+        Ok(reported_value_proto)
+    }
+}
+
+impl FromBytes<ReportedValue> for ReportedValue {
+    fn from_bytes(bytes: &[u8]) -> Result<ReportedValue, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::PropertyPage_ReportedValue =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get ReportedValue from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for ReportedValue {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from ReportedValue".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertyPage_ReportedValue> for ReportedValue {}
+impl IntoNative<ReportedValue> for protos::track_and_trace_property::PropertyPage_ReportedValue {}
+
+#[derive(Debug)]
+pub enum ReportedValueBuildError {
+    MissingField(String),
+}
+
+impl StdError for ReportedValueBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            ReportedValueBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            ReportedValueBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ReportedValueBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ReportedValueBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a ReportedValue
+#[derive(Default, Clone)]
+pub struct ReportedValueBuilder {
+    pub reporter_index: Option<u32>,
+    pub timestamp: Option<u64>,
+    pub bytes_value: Option<Vec<u8>>,
+    pub boolean_value: Option<bool>,
+    pub number_value: Option<i64>,
+    pub string_value: Option<String>,
+    pub enum_value: Option<u32>,
+    pub struct_values: Vec<PropertyValue>,
+    pub location_value: Option<Location>,
+}
+
+impl ReportedValueBuilder {
+    pub fn new() -> Self {
+        ReportedValueBuilder::default()
+    }
+
+    pub fn with_reporter_index(mut self, reporter_index: u32) -> ReportedValueBuilder {
+        self.reporter_index = Some(reporter_index);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> ReportedValueBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn with_bytes_value(mut self, bytes_value: Vec<u8>) -> ReportedValueBuilder {
+        self.bytes_value = Some(bytes_value);
+        self
+    }
+
+    pub fn with_boolean_value(mut self, boolean_value: bool) -> ReportedValueBuilder {
+        self.boolean_value = Some(boolean_value);
+        self
+    }
+
+    pub fn with_number_value(mut self, number_value: i64) -> ReportedValueBuilder {
+        self.number_value = Some(number_value);
+        self
+    }
+
+    pub fn with_string_value(mut self, string_value: String) -> ReportedValueBuilder {
+        self.string_value = Some(string_value);
+        self
+    }
+
+    pub fn with_enum_value(mut self, enum_value: u32) -> ReportedValueBuilder {
+        self.enum_value = Some(enum_value);
+        self
+    }
+
+    pub fn with_struct_values(mut self, struct_values: Vec<PropertyValue>) -> ReportedValueBuilder {
+        self.struct_values = struct_values;
+        self
+    }
+
+    pub fn with_location_value(mut self, location_value: Location) -> ReportedValueBuilder {
+        self.location_value = Some(location_value);
+        self
+    }
+
+    pub fn build(self) -> Result<ReportedValue, ReportedValueBuildError> {
+        let reporter_index = self.reporter_index.ok_or_else(|| {
+            ReportedValueBuildError::MissingField("'reporter_index' field is required".to_string())
+        })?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            ReportedValueBuildError::MissingField("'timestamp' field is required".to_string())
+        })?;
+
+        let bytes_value = self.bytes_value.unwrap_or_default();
+
+        let boolean_value = self.boolean_value.unwrap_or_default();
+
+        let number_value = self.number_value.unwrap_or_default();
+
+        let string_value = self.string_value.unwrap_or_default();
+
+        let enum_value = self.enum_value.unwrap_or_default();
+
+        let struct_values = self.struct_values;
+
+        let location_value = self.location_value;
+
+        Ok(ReportedValue {
+            reporter_index,
+            timestamp,
+            bytes_value,
+            boolean_value,
+            number_value,
+            string_value,
+            enum_value,
+            struct_values,
+            location_value,
+        })
+    }
+}
+
+/// Native implementation of PropertyPage
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyPage {
+    name: String,
+    record_id: String,
+    reported_values: Vec<ReportedValue>,
+}
+
+impl PropertyPage {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn reported_values(&self) -> &[ReportedValue] {
+        &self.reported_values
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::PropertyPage> for PropertyPage {
+    fn from_proto(
+        property_page: protos::track_and_trace_property::PropertyPage,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PropertyPage {
+            name: property_page.get_name().to_string(),
+            record_id: property_page.get_record_id().to_string(),
+            reported_values: property_page
+                .get_reported_values()
+                .to_vec()
+                .into_iter()
+                .map(ReportedValue::from_proto)
+                .collect::<Result<Vec<ReportedValue>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<PropertyPage> for protos::track_and_trace_property::PropertyPage {
+    fn from_native(property_page: PropertyPage) -> Result<Self, ProtoConversionError> {
+        let mut property_page_proto = protos::track_and_trace_property::PropertyPage::new();
+
+        property_page_proto.set_name(property_page.name().to_string());
+        property_page_proto.set_record_id(property_page.record_id().to_string());
+        property_page_proto.set_reported_values(RepeatedField::from_vec(
+            property_page
+                .reported_values()
+                .to_vec()
+                .into_iter()
+                .map(ReportedValue::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::PropertyPage_ReportedValue>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(property_page_proto)
+    }
+}
+
+impl FromBytes<PropertyPage> for PropertyPage {
+    fn from_bytes(bytes: &[u8]) -> Result<PropertyPage, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::PropertyPage =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PropertyPage from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PropertyPage {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PropertyPage".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertyPage> for PropertyPage {}
+impl IntoNative<PropertyPage> for protos::track_and_trace_property::PropertyPage {}
+
+#[derive(Debug)]
+pub enum PropertyPageBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertyPageBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyPageBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertyPageBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyPageBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyPageBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a PropertyPage
+#[derive(Default, Clone)]
+pub struct PropertyPageBuilder {
+    pub name: Option<String>,
+    pub record_id: Option<String>,
+    pub reported_values: Vec<ReportedValue>,
+}
+
+impl PropertyPageBuilder {
+    pub fn new() -> Self {
+        PropertyPageBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> PropertyPageBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> PropertyPageBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_reported_values(
+        mut self,
+        reported_values: Vec<ReportedValue>,
+    ) -> PropertyPageBuilder {
+        self.reported_values = reported_values;
+        self
+    }
+
+    pub fn build(self) -> Result<PropertyPage, PropertyPageBuildError> {
+        let name = self.name.ok_or_else(|| {
+            PropertyPageBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let record_id = self.record_id.ok_or_else(|| {
+            PropertyPageBuildError::MissingField("'record_id' field is required".to_string())
+        })?;
+
+        let reported_values = self.reported_values;
+
+        Ok(PropertyPage {
+            name,
+            record_id,
+            reported_values,
+        })
+    }
+}
+
+/// Native implementation of PropertyPageContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyPageContainer {
+    entries: Vec<PropertyPage>,
+}
+
+impl PropertyPageContainer {
+    pub fn entries(&self) -> &[PropertyPage] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_property::PropertyPageContainer> for PropertyPageContainer {
+    fn from_proto(
+        property_page_container: protos::track_and_trace_property::PropertyPageContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(PropertyPageContainer {
+            entries: property_page_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(PropertyPage::from_proto)
+                .collect::<Result<Vec<PropertyPage>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<PropertyPageContainer> for protos::track_and_trace_property::PropertyPageContainer {
+    fn from_native(
+        property_page_container: PropertyPageContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut property_page_container_proto =
+            protos::track_and_trace_property::PropertyPageContainer::new();
+
+        property_page_container_proto.set_entries(
+            RepeatedField::from_vec(
+                property_page_container
+                    .entries()
+                    .to_vec()
+                    .into_iter()
+                    .map(PropertyPage::into_proto)
+                    .collect::<Result<
+                        Vec<protos::track_and_trace_property::PropertyPage>,
+                        ProtoConversionError,
+                    >>()?,
+            ),
+        );
+
+        Ok(property_page_container_proto)
+    }
+}
+
+impl FromBytes<PropertyPageContainer> for PropertyPageContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<PropertyPageContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_property::PropertyPageContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get PropertyPageContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for PropertyPageContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from PropertyPageContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_property::PropertyPageContainer> for PropertyPageContainer {}
+impl IntoNative<PropertyPageContainer> for protos::track_and_trace_property::PropertyPageContainer {}
+
+#[derive(Debug)]
+pub enum PropertyPageContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for PropertyPageContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            PropertyPageContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            PropertyPageContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PropertyPageContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            PropertyPageContainerBuildError::MissingField(ref s) => {
+                write!(f, "MissingField: {}", s)
+            }
+        }
+    }
+}
+
+/// Builder used to create a PropertyPageContainer
+#[derive(Default, Clone)]
+pub struct PropertyPageContainerBuilder {
+    pub entries: Vec<PropertyPage>,
+}
+
+impl PropertyPageContainerBuilder {
+    pub fn new() -> Self {
+        PropertyPageContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<PropertyPage>) -> PropertyPageContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<PropertyPageContainer, PropertyPageContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(PropertyPageContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(PropertyPageContainer { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that a property schema with a struct data type requires struct properties
+    fn check_property_schema_builder_struct() {
+        let result = PropertySchemaBuilder::new()
+            .with_name("TEST".to_string())
+            .with_data_type(DataType::Struct)
+            .build();
+        assert!(result.is_err());
+
+        let result = PropertySchemaBuilder::new()
+            .with_name("".to_string())
+            .with_data_type(DataType::String)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    // check that a property is built correctly and starts on the first page
+    fn check_property_builder() {
+        let reporter = ReporterBuilder::new()
+            .with_public_key("public_key".to_string())
+            .with_authorized(true)
+            .with_index(0)
+            .build()
+            .unwrap();
+        let property = PropertyBuilder::new()
+            .with_name("weight".to_string())
+            .with_record_id("record".to_string())
+            .with_data_type(DataType::Number)
+            .with_number_exponent(-3)
+            .with_reporters(vec![reporter.clone()])
+            .build()
+            .unwrap();
+
+        assert_eq!(property.name, "weight");
+        assert_eq!(property.record_id, "record");
+        assert_eq!(property.data_type, DataType::Number);
+        assert_eq!(property.number_exponent, -3);
+        assert_eq!(property.reporters, vec![reporter]);
+        assert_eq!(property.current_page, 1);
+    }
+
+    #[test]
+    // check that a location property value requires a location and survives conversion
+    fn check_property_value_location() {
+        let result = PropertyValueBuilder::new()
+            .with_name("position".to_string())
+            .with_data_type(DataType::Location)
+            .build();
+        assert!(result.is_err());
+
+        let location = LocationBuilder::new()
+            .with_latitude(44_977_753)
+            .with_longitude(-93_265_011)
+            .build()
+            .unwrap();
+        let original = PropertyValueBuilder::new()
+            .with_name("position".to_string())
+            .with_data_type(DataType::Location)
+            .with_location_value(location.clone())
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let property_value = PropertyValue::from_bytes(&bytes).unwrap();
+        assert_eq!(property_value, original);
+        assert_eq!(property_value.location_value(), Some(&location));
+    }
+
+    #[test]
+    // check that a property page can be converted to bytes and back
+    fn check_property_page_bytes() {
+        let reported_value = ReportedValueBuilder::new()
+            .with_reporter_index(0)
+            .with_timestamp(1)
+            .with_number_value(12345)
+            .build()
+            .unwrap();
+        let original = PropertyPageBuilder::new()
+            .with_name("weight".to_string())
+            .with_record_id("record".to_string())
+            .with_reported_values(vec![reported_value])
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let page = PropertyPage::from_bytes(&bytes).unwrap();
+        assert_eq!(page, original);
+        assert_eq!(page.reported_values()[0].location_value(), None);
+    }
+}
@@ -0,0 +1,560 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf::Message;
+use protobuf::RepeatedField;
+
+use std::error::Error as StdError;
+
+use crate::protos;
+use crate::protos::{
+    FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
+};
+
+/// Native implementation of Proposal_Role enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum Role {
+    Owner,
+    Custodian,
+    Reporter,
+}
+
+impl Default for Role {
+    fn default() -> Self {
+        Role::Owner
+    }
+}
+
+impl FromProto<protos::track_and_trace_proposal::Proposal_Role> for Role {
+    fn from_proto(
+        role: protos::track_and_trace_proposal::Proposal_Role,
+    ) -> Result<Self, ProtoConversionError> {
+        match role {
+            protos::track_and_trace_proposal::Proposal_Role::OWNER => Ok(Role::Owner),
+            protos::track_and_trace_proposal::Proposal_Role::CUSTODIAN => Ok(Role::Custodian),
+            protos::track_and_trace_proposal::Proposal_Role::REPORTER => Ok(Role::Reporter),
+        }
+    }
+}
+
+impl FromNative<Role> for protos::track_and_trace_proposal::Proposal_Role {
+    fn from_native(role: Role) -> Result<Self, ProtoConversionError> {
+        match role {
+            Role::Owner => Ok(protos::track_and_trace_proposal::Proposal_Role::OWNER),
+            Role::Custodian => Ok(protos::track_and_trace_proposal::Proposal_Role::CUSTODIAN),
+            Role::Reporter => Ok(protos::track_and_trace_proposal::Proposal_Role::REPORTER),
+        }
+    }
+}
+
+impl IntoProto<protos::track_and_trace_proposal::Proposal_Role> for Role {}
+impl IntoNative<Role> for protos::track_and_trace_proposal::Proposal_Role {}
+
+/// Native implementation of Proposal_Status enum
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Open,
+    Accepted,
+    Rejected,
+    Canceled,
+    Expired,
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::Open
+    }
+}
+
+impl FromProto<protos::track_and_trace_proposal::Proposal_Status> for Status {
+    fn from_proto(
+        status: protos::track_and_trace_proposal::Proposal_Status,
+    ) -> Result<Self, ProtoConversionError> {
+        match status {
+            protos::track_and_trace_proposal::Proposal_Status::OPEN => Ok(Status::Open),
+            protos::track_and_trace_proposal::Proposal_Status::ACCEPTED => Ok(Status::Accepted),
+            protos::track_and_trace_proposal::Proposal_Status::REJECTED => Ok(Status::Rejected),
+            protos::track_and_trace_proposal::Proposal_Status::CANCELED => Ok(Status::Canceled),
+            protos::track_and_trace_proposal::Proposal_Status::EXPIRED => Ok(Status::Expired),
+        }
+    }
+}
+
+impl FromNative<Status> for protos::track_and_trace_proposal::Proposal_Status {
+    fn from_native(status: Status) -> Result<Self, ProtoConversionError> {
+        match status {
+            Status::Open => Ok(protos::track_and_trace_proposal::Proposal_Status::OPEN),
+            Status::Accepted => Ok(protos::track_and_trace_proposal::Proposal_Status::ACCEPTED),
+            Status::Rejected => Ok(protos::track_and_trace_proposal::Proposal_Status::REJECTED),
+            Status::Canceled => Ok(protos::track_and_trace_proposal::Proposal_Status::CANCELED),
+            Status::Expired => Ok(protos::track_and_trace_proposal::Proposal_Status::EXPIRED),
+        }
+    }
+}
+
+impl IntoProto<protos::track_and_trace_proposal::Proposal_Status> for Status {}
+impl IntoNative<Status> for protos::track_and_trace_proposal::Proposal_Status {}
+
+/// Native implementation of Proposal
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proposal {
+    record_id: String,
+    timestamp: u64,
+    issuing_agent: String,
+    receiving_agent: String,
+    role: Role,
+    properties: Vec<String>,
+    status: Status,
+    terms: String,
+    expiry: u64,
+}
+
+impl Proposal {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+
+    pub fn issuing_agent(&self) -> &str {
+        &self.issuing_agent
+    }
+
+    pub fn receiving_agent(&self) -> &str {
+        &self.receiving_agent
+    }
+
+    pub fn role(&self) -> &Role {
+        &self.role
+    }
+
+    pub fn properties(&self) -> &[String] {
+        &self.properties
+    }
+
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    pub fn terms(&self) -> &str {
+        &self.terms
+    }
+
+    pub fn expiry(&self) -> &u64 {
+        &self.expiry
+    }
+
+    /// Returns whether the proposal has an expiry that is earlier than `timestamp`
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        self.expiry != 0 && self.expiry < timestamp
+    }
+}
+
+impl FromProto<protos::track_and_trace_proposal::Proposal> for Proposal {
+    fn from_proto(
+        proposal: protos::track_and_trace_proposal::Proposal,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Proposal {
+            record_id: proposal.get_record_id().to_string(),
+            timestamp: proposal.get_timestamp(),
+            issuing_agent: proposal.get_issuing_agent().to_string(),
+            receiving_agent: proposal.get_receiving_agent().to_string(),
+            role: Role::from_proto(proposal.get_role())?,
+            properties: proposal.get_properties().to_vec(),
+            status: Status::from_proto(proposal.get_status())?,
+            terms: proposal.get_terms().to_string(),
+            expiry: proposal.get_expiry(),
+        })
+    }
+}
+
+impl FromNative<Proposal> for protos::track_and_trace_proposal::Proposal {
+    fn from_native(proposal: Proposal) -> Result<Self, ProtoConversionError> {
+        let mut proposal_proto = protos::track_and_trace_proposal::Proposal::new();
+
+        proposal_proto.set_record_id(proposal.record_id().to_string());
+        proposal_proto.set_timestamp(*proposal.timestamp());
+        proposal_proto.set_issuing_agent(proposal.issuing_agent().to_string());
+        proposal_proto.set_receiving_agent(proposal.receiving_agent().to_string());
+        proposal_proto.set_role(proposal.role().clone().into_proto()?);
+        proposal_proto.set_properties(RepeatedField::from_vec(proposal.properties().to_vec()));
+        proposal_proto.set_status(proposal.status().clone().into_proto()?);
+        proposal_proto.set_terms(proposal.terms().to_string());
+        proposal_proto.set_expiry(*proposal.expiry());
+
+        Ok(proposal_proto)
+    }
+}
+
+impl FromBytes<Proposal> for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<Proposal, ProtoConversionError> {
+        let proto: protos::track_and_trace_proposal::Proposal = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get Proposal from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Proposal {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from Proposal".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_proposal::Proposal> for Proposal {}
+impl IntoNative<Proposal> for protos::track_and_trace_proposal::Proposal {}
+
+#[derive(Debug)]
+pub enum ProposalBuildError {
+    MissingField(String),
+}
+
+impl StdError for ProposalBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            ProposalBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            ProposalBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ProposalBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ProposalBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Proposal
+#[derive(Default, Clone)]
+pub struct ProposalBuilder {
+    pub record_id: Option<String>,
+    pub timestamp: Option<u64>,
+    pub issuing_agent: Option<String>,
+    pub receiving_agent: Option<String>,
+    pub role: Option<Role>,
+    pub properties: Vec<String>,
+    pub status: Option<Status>,
+    pub terms: Option<String>,
+    pub expiry: Option<u64>,
+}
+
+impl ProposalBuilder {
+    pub fn new() -> Self {
+        ProposalBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> ProposalBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> ProposalBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn with_issuing_agent(mut self, issuing_agent: String) -> ProposalBuilder {
+        self.issuing_agent = Some(issuing_agent);
+        self
+    }
+
+    pub fn with_receiving_agent(mut self, receiving_agent: String) -> ProposalBuilder {
+        self.receiving_agent = Some(receiving_agent);
+        self
+    }
+
+    pub fn with_role(mut self, role: Role) -> ProposalBuilder {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Vec<String>) -> ProposalBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn with_status(mut self, status: Status) -> ProposalBuilder {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn with_terms(mut self, terms: String) -> ProposalBuilder {
+        self.terms = Some(terms);
+        self
+    }
+
+    pub fn with_expiry(mut self, expiry: u64) -> ProposalBuilder {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    pub fn build(self) -> Result<Proposal, ProposalBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            ProposalBuildError::MissingField("'record_id' field is required".to_string())
+        })?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            ProposalBuildError::MissingField("'timestamp' field is required".to_string())
+        })?;
+
+        let issuing_agent = self.issuing_agent.ok_or_else(|| {
+            ProposalBuildError::MissingField("'issuing_agent' field is required".to_string())
+        })?;
+
+        let receiving_agent = self.receiving_agent.ok_or_else(|| {
+            ProposalBuildError::MissingField("'receiving_agent' field is required".to_string())
+        })?;
+
+        let role = self.role.ok_or_else(|| {
+            ProposalBuildError::MissingField("'role' field is required".to_string())
+        })?;
+
+        let properties = self.properties;
+
+        let status = self.status.unwrap_or_default();
+
+        let terms = self.terms.unwrap_or_default();
+
+        let expiry = self.expiry.unwrap_or_default();
+
+        Ok(Proposal {
+            record_id,
+            timestamp,
+            issuing_agent,
+            receiving_agent,
+            role,
+            properties,
+            status,
+            terms,
+            expiry,
+        })
+    }
+}
+
+/// Native implementation of ProposalContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposalContainer {
+    entries: Vec<Proposal>,
+}
+
+impl ProposalContainer {
+    pub fn entries(&self) -> &[Proposal] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_proposal::ProposalContainer> for ProposalContainer {
+    fn from_proto(
+        proposal_container: protos::track_and_trace_proposal::ProposalContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(ProposalContainer {
+            entries: proposal_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(Proposal::from_proto)
+                .collect::<Result<Vec<Proposal>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<ProposalContainer> for protos::track_and_trace_proposal::ProposalContainer {
+    fn from_native(proposal_container: ProposalContainer) -> Result<Self, ProtoConversionError> {
+        let mut proposal_container_proto =
+            protos::track_and_trace_proposal::ProposalContainer::new();
+
+        proposal_container_proto.set_entries(RepeatedField::from_vec(
+            proposal_container
+                .entries()
+                .to_vec()
+                .into_iter()
+                .map(Proposal::into_proto)
+                .collect::<Result<Vec<protos::track_and_trace_proposal::Proposal>, ProtoConversionError>>()?,
+        ));
+
+        Ok(proposal_container_proto)
+    }
+}
+
+impl FromBytes<ProposalContainer> for ProposalContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<ProposalContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_proposal::ProposalContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get ProposalContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for ProposalContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from ProposalContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_proposal::ProposalContainer> for ProposalContainer {}
+impl IntoNative<ProposalContainer> for protos::track_and_trace_proposal::ProposalContainer {}
+
+#[derive(Debug)]
+pub enum ProposalContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for ProposalContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            ProposalContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            ProposalContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ProposalContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            ProposalContainerBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a ProposalContainer
+#[derive(Default, Clone)]
+pub struct ProposalContainerBuilder {
+    pub entries: Vec<Proposal>,
+}
+
+impl ProposalContainerBuilder {
+    pub fn new() -> Self {
+        ProposalContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<Proposal>) -> ProposalContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<ProposalContainer, ProposalContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(ProposalContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(ProposalContainer { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // check that a proposal is built correctly and defaults to open
+    fn check_proposal_builder() {
+        let proposal = ProposalBuilder::new()
+            .with_record_id("record".to_string())
+            .with_timestamp(1)
+            .with_issuing_agent("issuer".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_role(Role::Reporter)
+            .with_properties(vec!["weight".to_string()])
+            .build()
+            .unwrap();
+
+        assert_eq!(proposal.record_id, "record");
+        assert_eq!(proposal.role, Role::Reporter);
+        assert_eq!(proposal.properties, vec!["weight".to_string()]);
+        assert_eq!(proposal.status, Status::Open);
+        assert_eq!(proposal.terms, "");
+        assert_eq!(proposal.expiry, 0);
+        assert!(!proposal.is_expired(u64::max_value()));
+    }
+
+    #[test]
+    // check that a proposal expires only after its expiry
+    fn check_proposal_expiry() {
+        let proposal = ProposalBuilder::new()
+            .with_record_id("record".to_string())
+            .with_timestamp(1)
+            .with_issuing_agent("issuer".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_role(Role::Owner)
+            .with_terms("terms".to_string())
+            .with_expiry(10)
+            .build()
+            .unwrap();
+
+        assert_eq!(proposal.terms, "terms");
+        assert!(!proposal.is_expired(10));
+        assert!(proposal.is_expired(11));
+    }
+
+    #[test]
+    // check that a proposal container can be converted to bytes and back
+    fn check_proposal_container_bytes() {
+        let proposal = ProposalBuilder::new()
+            .with_record_id("record".to_string())
+            .with_timestamp(1)
+            .with_issuing_agent("issuer".to_string())
+            .with_receiving_agent("receiver".to_string())
+            .with_role(Role::Custodian)
+            .with_status(Status::Accepted)
+            .build()
+            .unwrap();
+        let original = ProposalContainerBuilder::new()
+            .with_entries(vec![proposal])
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let container = ProposalContainer::from_bytes(&bytes).unwrap();
+        assert_eq!(container, original);
+    }
+}
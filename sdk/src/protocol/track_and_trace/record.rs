@@ -0,0 +1,847 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protobuf::Message;
+use protobuf::RepeatedField;
+
+use std::error::Error as StdError;
+
+use crate::protocol::track_and_trace::property::PropertySchema;
+use crate::protos;
+use crate::protos::{
+    FromBytes, FromNative, FromProto, IntoBytes, IntoNative, IntoProto, ProtoConversionError,
+};
+
+/// Native implementation of Record_AssociatedAgent
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssociatedAgent {
+    agent_id: String,
+    timestamp: u64,
+    org_id: String,
+}
+
+impl AssociatedAgent {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+
+    pub fn timestamp(&self) -> &u64 {
+        &self.timestamp
+    }
+
+    pub fn org_id(&self) -> &str {
+        &self.org_id
+    }
+}
+
+impl FromProto<protos::track_and_trace_record::Record_AssociatedAgent> for AssociatedAgent {
+    fn from_proto(
+        associated_agent: protos::track_and_trace_record::Record_AssociatedAgent,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(AssociatedAgent {
+            agent_id: associated_agent.get_agent_id().to_string(),
+            timestamp: associated_agent.get_timestamp(),
+            org_id: associated_agent.get_org_id().to_string(),
+        })
+    }
+}
+
+impl FromNative<AssociatedAgent> for protos::track_and_trace_record::Record_AssociatedAgent {
+    fn from_native(associated_agent: AssociatedAgent) -> Result<Self, ProtoConversionError> {
+        let mut associated_agent_proto =
+            protos::track_and_trace_record::Record_AssociatedAgent::new();
+
+        associated_agent_proto.set_agent_id(associated_agent.agent_id().to_string());
+        associated_agent_proto.set_timestamp(*associated_agent.timestamp());
+        associated_agent_proto.set_org_id(associated_agent.org_id().to_string());
+
+        Ok(associated_agent_proto)
+    }
+}
+
+impl FromBytes<AssociatedAgent> for AssociatedAgent {
+    fn from_bytes(bytes: &[u8]) -> Result<AssociatedAgent, ProtoConversionError> {
+        let proto: protos::track_and_trace_record::Record_AssociatedAgent =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get AssociatedAgent from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for AssociatedAgent {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from AssociatedAgent".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_record::Record_AssociatedAgent> for AssociatedAgent {}
+impl IntoNative<AssociatedAgent> for protos::track_and_trace_record::Record_AssociatedAgent {}
+
+#[derive(Debug)]
+pub enum AssociatedAgentBuildError {
+    MissingField(String),
+}
+
+impl StdError for AssociatedAgentBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            AssociatedAgentBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            AssociatedAgentBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AssociatedAgentBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            AssociatedAgentBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a AssociatedAgent
+#[derive(Default, Clone)]
+pub struct AssociatedAgentBuilder {
+    pub agent_id: Option<String>,
+    pub timestamp: Option<u64>,
+    pub org_id: Option<String>,
+}
+
+impl AssociatedAgentBuilder {
+    pub fn new() -> Self {
+        AssociatedAgentBuilder::default()
+    }
+
+    pub fn with_agent_id(mut self, agent_id: String) -> AssociatedAgentBuilder {
+        self.agent_id = Some(agent_id);
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: u64) -> AssociatedAgentBuilder {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn with_org_id(mut self, org_id: String) -> AssociatedAgentBuilder {
+        self.org_id = Some(org_id);
+        self
+    }
+
+    pub fn build(self) -> Result<AssociatedAgent, AssociatedAgentBuildError> {
+        let agent_id = self.agent_id.ok_or_else(|| {
+            AssociatedAgentBuildError::MissingField("'agent_id' field is required".to_string())
+        })?;
+
+        let timestamp = self.timestamp.ok_or_else(|| {
+            AssociatedAgentBuildError::MissingField("'timestamp' field is required".to_string())
+        })?;
+
+        let org_id = self.org_id.ok_or_else(|| {
+            AssociatedAgentBuildError::MissingField("'org_id' field is required".to_string())
+        })?;
+
+        Ok(AssociatedAgent {
+            agent_id,
+            timestamp,
+            org_id,
+        })
+    }
+}
+
+/// Native implementation of Record
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    record_id: String,
+    record_type: String,
+    owners: Vec<AssociatedAgent>,
+    custodians: Vec<AssociatedAgent>,
+    finalized: bool,
+}
+
+impl Record {
+    pub fn record_id(&self) -> &str {
+        &self.record_id
+    }
+
+    pub fn record_type(&self) -> &str {
+        &self.record_type
+    }
+
+    pub fn owners(&self) -> &[AssociatedAgent] {
+        &self.owners
+    }
+
+    pub fn custodians(&self) -> &[AssociatedAgent] {
+        &self.custodians
+    }
+
+    pub fn finalized(&self) -> &bool {
+        &self.finalized
+    }
+}
+
+impl FromProto<protos::track_and_trace_record::Record> for Record {
+    fn from_proto(
+        record: protos::track_and_trace_record::Record,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(Record {
+            record_id: record.get_record_id().to_string(),
+            record_type: record.get_record_type().to_string(),
+            owners: record
+                .get_owners()
+                .to_vec()
+                .into_iter()
+                .map(AssociatedAgent::from_proto)
+                .collect::<Result<Vec<AssociatedAgent>, ProtoConversionError>>()?,
+            custodians: record
+                .get_custodians()
+                .to_vec()
+                .into_iter()
+                .map(AssociatedAgent::from_proto)
+                .collect::<Result<Vec<AssociatedAgent>, ProtoConversionError>>()?,
+            finalized: record.get_field_final(),
+        })
+    }
+}
+
+impl FromNative<Record> for protos::track_and_trace_record::Record {
+    fn from_native(record: Record) -> Result<Self, ProtoConversionError> {
+        let mut record_proto = protos::track_and_trace_record::Record::new();
+
+        record_proto.set_record_id(record.record_id().to_string());
+        record_proto.set_record_type(record.record_type().to_string());
+        record_proto.set_owners(RepeatedField::from_vec(
+            record
+                .owners()
+                .to_vec()
+                .into_iter()
+                .map(AssociatedAgent::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_record::Record_AssociatedAgent>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        record_proto.set_custodians(RepeatedField::from_vec(
+            record
+                .custodians()
+                .to_vec()
+                .into_iter()
+                .map(AssociatedAgent::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_record::Record_AssociatedAgent>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+        record_proto.set_field_final(*record.finalized());
+
+        Ok(record_proto)
+    }
+}
+
+impl FromBytes<Record> for Record {
+    fn from_bytes(bytes: &[u8]) -> Result<Record, ProtoConversionError> {
+        let proto: protos::track_and_trace_record::Record = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get Record from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for Record {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError("Unable to get bytes from Record".to_string())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_record::Record> for Record {}
+impl IntoNative<Record> for protos::track_and_trace_record::Record {}
+
+#[derive(Debug)]
+pub enum RecordBuildError {
+    MissingField(String),
+}
+
+impl StdError for RecordBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            RecordBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RecordBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a Record
+#[derive(Default, Clone)]
+pub struct RecordBuilder {
+    pub record_id: Option<String>,
+    pub record_type: Option<String>,
+    pub owners: Vec<AssociatedAgent>,
+    pub custodians: Vec<AssociatedAgent>,
+    pub finalized: Option<bool>,
+}
+
+impl RecordBuilder {
+    pub fn new() -> Self {
+        RecordBuilder::default()
+    }
+
+    pub fn with_record_id(mut self, record_id: String) -> RecordBuilder {
+        self.record_id = Some(record_id);
+        self
+    }
+
+    pub fn with_record_type(mut self, record_type: String) -> RecordBuilder {
+        self.record_type = Some(record_type);
+        self
+    }
+
+    pub fn with_owners(mut self, owners: Vec<AssociatedAgent>) -> RecordBuilder {
+        self.owners = owners;
+        self
+    }
+
+    pub fn with_custodians(mut self, custodians: Vec<AssociatedAgent>) -> RecordBuilder {
+        self.custodians = custodians;
+        self
+    }
+
+    pub fn with_finalized(mut self, finalized: bool) -> RecordBuilder {
+        self.finalized = Some(finalized);
+        self
+    }
+
+    pub fn build(self) -> Result<Record, RecordBuildError> {
+        let record_id = self.record_id.ok_or_else(|| {
+            RecordBuildError::MissingField("'record_id' field is required".to_string())
+        })?;
+
+        let record_type = self.record_type.ok_or_else(|| {
+            RecordBuildError::MissingField("'record_type' field is required".to_string())
+        })?;
+
+        let owners = self.owners;
+
+        let custodians = self.custodians;
+
+        let finalized = self.finalized.unwrap_or_default();
+
+        Ok(Record {
+            record_id,
+            record_type,
+            owners,
+            custodians,
+            finalized,
+        })
+    }
+}
+
+/// Native implementation of RecordContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordContainer {
+    entries: Vec<Record>,
+}
+
+impl RecordContainer {
+    pub fn entries(&self) -> &[Record] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_record::RecordContainer> for RecordContainer {
+    fn from_proto(
+        record_container: protos::track_and_trace_record::RecordContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(RecordContainer {
+            entries: record_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(Record::from_proto)
+                .collect::<Result<Vec<Record>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<RecordContainer> for protos::track_and_trace_record::RecordContainer {
+    fn from_native(record_container: RecordContainer) -> Result<Self, ProtoConversionError> {
+        let mut record_container_proto = protos::track_and_trace_record::RecordContainer::new();
+
+        record_container_proto.set_entries(RepeatedField::from_vec(
+            record_container
+                .entries()
+                .to_vec()
+                .into_iter()
+                .map(Record::into_proto)
+                .collect::<Result<Vec<protos::track_and_trace_record::Record>, ProtoConversionError>>()?,
+        ));
+
+        Ok(record_container_proto)
+    }
+}
+
+impl FromBytes<RecordContainer> for RecordContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<RecordContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_record::RecordContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get RecordContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RecordContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RecordContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_record::RecordContainer> for RecordContainer {}
+impl IntoNative<RecordContainer> for protos::track_and_trace_record::RecordContainer {}
+
+#[derive(Debug)]
+pub enum RecordContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for RecordContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            RecordContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RecordContainerBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RecordContainer
+#[derive(Default, Clone)]
+pub struct RecordContainerBuilder {
+    pub entries: Vec<Record>,
+}
+
+impl RecordContainerBuilder {
+    pub fn new() -> Self {
+        RecordContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<Record>) -> RecordContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<RecordContainer, RecordContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(RecordContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(RecordContainer { entries })
+    }
+}
+
+/// Native implementation of RecordType
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordType {
+    name: String,
+    properties: Vec<PropertySchema>,
+}
+
+impl RecordType {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn properties(&self) -> &[PropertySchema] {
+        &self.properties
+    }
+}
+
+impl FromProto<protos::track_and_trace_record::RecordType> for RecordType {
+    fn from_proto(
+        record_type: protos::track_and_trace_record::RecordType,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(RecordType {
+            name: record_type.get_name().to_string(),
+            properties: record_type
+                .get_properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::from_proto)
+                .collect::<Result<Vec<PropertySchema>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<RecordType> for protos::track_and_trace_record::RecordType {
+    fn from_native(record_type: RecordType) -> Result<Self, ProtoConversionError> {
+        let mut record_type_proto = protos::track_and_trace_record::RecordType::new();
+
+        record_type_proto.set_name(record_type.name().to_string());
+        record_type_proto.set_properties(RepeatedField::from_vec(
+            record_type
+                .properties()
+                .to_vec()
+                .into_iter()
+                .map(PropertySchema::into_proto)
+                .collect::<Result<
+                    Vec<protos::track_and_trace_property::PropertySchema>,
+                    ProtoConversionError,
+                >>()?,
+        ));
+
+        Ok(record_type_proto)
+    }
+}
+
+impl FromBytes<RecordType> for RecordType {
+    fn from_bytes(bytes: &[u8]) -> Result<RecordType, ProtoConversionError> {
+        let proto: protos::track_and_trace_record::RecordType = protobuf::parse_from_bytes(bytes)
+            .map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get RecordType from bytes".to_string(),
+            )
+        })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RecordType {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RecordType".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_record::RecordType> for RecordType {}
+impl IntoNative<RecordType> for protos::track_and_trace_record::RecordType {}
+
+#[derive(Debug)]
+pub enum RecordTypeBuildError {
+    MissingField(String),
+}
+
+impl StdError for RecordTypeBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordTypeBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            RecordTypeBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordTypeBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RecordTypeBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RecordType
+#[derive(Default, Clone)]
+pub struct RecordTypeBuilder {
+    pub name: Option<String>,
+    pub properties: Vec<PropertySchema>,
+}
+
+impl RecordTypeBuilder {
+    pub fn new() -> Self {
+        RecordTypeBuilder::default()
+    }
+
+    pub fn with_name(mut self, name: String) -> RecordTypeBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Vec<PropertySchema>) -> RecordTypeBuilder {
+        self.properties = properties;
+        self
+    }
+
+    pub fn build(self) -> Result<RecordType, RecordTypeBuildError> {
+        let name = self.name.ok_or_else(|| {
+            RecordTypeBuildError::MissingField("'name' field is required".to_string())
+        })?;
+
+        let properties = {
+            if self.properties.is_empty() {
+                return Err(RecordTypeBuildError::MissingField(
+                    "'properties' cannot be empty".to_string(),
+                ));
+            } else {
+                self.properties
+            }
+        };
+
+        Ok(RecordType { name, properties })
+    }
+}
+
+/// Native implementation of RecordTypeContainer
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordTypeContainer {
+    entries: Vec<RecordType>,
+}
+
+impl RecordTypeContainer {
+    pub fn entries(&self) -> &[RecordType] {
+        &self.entries
+    }
+}
+
+impl FromProto<protos::track_and_trace_record::RecordTypeContainer> for RecordTypeContainer {
+    fn from_proto(
+        record_type_container: protos::track_and_trace_record::RecordTypeContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        Ok(RecordTypeContainer {
+            entries: record_type_container
+                .get_entries()
+                .to_vec()
+                .into_iter()
+                .map(RecordType::from_proto)
+                .collect::<Result<Vec<RecordType>, ProtoConversionError>>()?,
+        })
+    }
+}
+
+impl FromNative<RecordTypeContainer> for protos::track_and_trace_record::RecordTypeContainer {
+    fn from_native(
+        record_type_container: RecordTypeContainer,
+    ) -> Result<Self, ProtoConversionError> {
+        let mut record_type_container_proto =
+            protos::track_and_trace_record::RecordTypeContainer::new();
+
+        record_type_container_proto.set_entries(RepeatedField::from_vec(
+            record_type_container
+                .entries()
+                .to_vec()
+                .into_iter()
+                .map(RecordType::into_proto)
+                .collect::<Result<Vec<protos::track_and_trace_record::RecordType>, ProtoConversionError>>()?,
+        ));
+
+        Ok(record_type_container_proto)
+    }
+}
+
+impl FromBytes<RecordTypeContainer> for RecordTypeContainer {
+    fn from_bytes(bytes: &[u8]) -> Result<RecordTypeContainer, ProtoConversionError> {
+        let proto: protos::track_and_trace_record::RecordTypeContainer =
+            protobuf::parse_from_bytes(bytes).map_err(|_| {
+                ProtoConversionError::SerializationError(
+                    "Unable to get RecordTypeContainer from bytes".to_string(),
+                )
+            })?;
+        proto.into_native()
+    }
+}
+
+impl IntoBytes for RecordTypeContainer {
+    fn into_bytes(self) -> Result<Vec<u8>, ProtoConversionError> {
+        let proto = self.into_proto()?;
+        let bytes = proto.write_to_bytes().map_err(|_| {
+            ProtoConversionError::SerializationError(
+                "Unable to get bytes from RecordTypeContainer".to_string(),
+            )
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl IntoProto<protos::track_and_trace_record::RecordTypeContainer> for RecordTypeContainer {}
+impl IntoNative<RecordTypeContainer> for protos::track_and_trace_record::RecordTypeContainer {}
+
+#[derive(Debug)]
+pub enum RecordTypeContainerBuildError {
+    MissingField(String),
+}
+
+impl StdError for RecordTypeContainerBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordTypeContainerBuildError::MissingField(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            RecordTypeContainerBuildError::MissingField(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordTypeContainerBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            RecordTypeContainerBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+        }
+    }
+}
+
+/// Builder used to create a RecordTypeContainer
+#[derive(Default, Clone)]
+pub struct RecordTypeContainerBuilder {
+    pub entries: Vec<RecordType>,
+}
+
+impl RecordTypeContainerBuilder {
+    pub fn new() -> Self {
+        RecordTypeContainerBuilder::default()
+    }
+
+    pub fn with_entries(mut self, entries: Vec<RecordType>) -> RecordTypeContainerBuilder {
+        self.entries = entries;
+        self
+    }
+
+    pub fn build(self) -> Result<RecordTypeContainer, RecordTypeContainerBuildError> {
+        let entries = {
+            if self.entries.is_empty() {
+                return Err(RecordTypeContainerBuildError::MissingField(
+                    "'entries' cannot be empty".to_string(),
+                ));
+            } else {
+                self.entries
+            }
+        };
+
+        Ok(RecordTypeContainer { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::track_and_trace::property::{DataType, PropertySchemaBuilder};
+
+    #[test]
+    // check that a record can be converted to bytes and back
+    fn check_record_bytes() {
+        let owner = AssociatedAgentBuilder::new()
+            .with_agent_id("owner".to_string())
+            .with_timestamp(1)
+            .with_org_id("org".to_string())
+            .build()
+            .unwrap();
+        let original = RecordBuilder::new()
+            .with_record_id("record".to_string())
+            .with_record_type("type".to_string())
+            .with_owners(vec![owner.clone()])
+            .with_custodians(vec![owner])
+            .with_finalized(true)
+            .build()
+            .unwrap();
+
+        let bytes = original.clone().into_bytes().unwrap();
+
+        let record = Record::from_bytes(&bytes).unwrap();
+        assert_eq!(record, original);
+        assert!(record.finalized());
+        assert_eq!(record.owners()[0].org_id(), "org");
+    }
+
+    #[test]
+    // check that a record type requires at least one property
+    fn check_record_type_builder() {
+        let result = RecordTypeBuilder::new()
+            .with_name("type".to_string())
+            .build();
+        assert!(result.is_err());
+
+        let property = PropertySchemaBuilder::new()
+            .with_name("weight".to_string())
+            .with_data_type(DataType::String)
+            .build()
+            .unwrap();
+        let record_type = RecordTypeBuilder::new()
+            .with_name("type".to_string())
+            .with_properties(vec![property.clone()])
+            .build()
+            .unwrap();
+
+        assert_eq!(record_type.name, "type");
+        assert_eq!(record_type.properties, vec![property]);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error as StdError;
+
+/// Signs transaction and batch headers. Implement this to plug a key store or signing library
+/// into the transaction builders.
+pub trait Signer {
+    /// Signs `message`, returning the hex encoded signature
+    fn sign(&self, message: &[u8]) -> Result<String, SigningError>;
+
+    /// Returns the hex encoded public key matching the key used to sign
+    fn public_key(&self) -> Result<String, SigningError>;
+}
+
+#[derive(Debug)]
+pub enum SigningError {
+    /// The signer's key could not be loaded or is invalid
+    KeyError(String),
+    /// The message could not be signed
+    SigningFailed(String),
+}
+
+impl StdError for SigningError {
+    fn description(&self) -> &str {
+        match *self {
+            SigningError::KeyError(ref msg) => msg,
+            SigningError::SigningFailed(ref msg) => msg,
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            SigningError::KeyError(_) => None,
+            SigningError::SigningFailed(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for SigningError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SigningError::KeyError(ref s) => write!(f, "KeyError: {}", s),
+            SigningError::SigningFailed(ref s) => write!(f, "SigningFailed: {}", s),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for testing transaction handlers against in-memory state
+
+use std::collections::HashMap;
+
+use sawtooth_sdk::processor::handler::{ContextError, TransactionContext};
+
+use crate::addressing::{compute_pike_agent_address, compute_pike_organization_address};
+use crate::protocol::pike::state::{
+    AgentBuilder, AgentListBuilder, OrganizationBuilder, OrganizationListBuilder,
+    OrganizationStatus,
+};
+use crate::protos::IntoBytes;
+
+/// A transaction context that keeps state in memory
+///
+/// Receipt data and events are accepted and discarded.
+#[derive(Default)]
+pub struct MockTransactionContext {
+    state: HashMap<String, Vec<u8>>,
+}
+
+impl TransactionContext for MockTransactionContext {
+    fn get_state(&mut self, addresses: Vec<String>) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(addresses
+            .first()
+            .and_then(|address| self.state.get(address).cloned()))
+    }
+
+    fn set_state(&mut self, entries: HashMap<String, Vec<u8>>) -> Result<(), ContextError> {
+        self.state.extend(entries);
+        Ok(())
+    }
+
+    fn delete_state(
+        &mut self,
+        addresses: Vec<String>,
+    ) -> Result<Option<Vec<String>>, ContextError> {
+        let deleted = addresses
+            .into_iter()
+            .filter(|address| self.state.remove(address).is_some())
+            .collect();
+        Ok(Some(deleted))
+    }
+
+    fn add_receipt_data(&mut self, _data: &[u8]) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn add_event(
+        &mut self,
+        _event_type: String,
+        _attributes: Vec<(String, String)>,
+        _data: &[u8],
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+}
+
+/// Stores a Pike agent with the given organization, status and roles
+pub fn add_agent(
+    context: &mut dyn TransactionContext,
+    public_key: &str,
+    org_id: &str,
+    active: bool,
+    roles: Vec<String>,
+) {
+    let agent = AgentBuilder::new()
+        .with_org_id(org_id.to_string())
+        .with_public_key(public_key.to_string())
+        .with_active(active)
+        .with_roles(roles)
+        .build()
+        .unwrap();
+    let agent_list = AgentListBuilder::new()
+        .with_agents(vec![agent])
+        .build()
+        .unwrap();
+
+    let mut entries = HashMap::new();
+    entries.insert(
+        compute_pike_agent_address(public_key),
+        agent_list.into_bytes().unwrap(),
+    );
+    context.set_state(entries).unwrap();
+}
+
+/// Stores a Pike organization with the given status
+pub fn add_organization(
+    context: &mut dyn TransactionContext,
+    org_id: &str,
+    status: OrganizationStatus,
+) {
+    let organization = OrganizationBuilder::new()
+        .with_org_id(org_id.to_string())
+        .with_name("Organization".to_string())
+        .with_address("address".to_string())
+        .with_status(status)
+        .build()
+        .unwrap();
+    let organization_list = OrganizationListBuilder::new()
+        .with_organizations(vec![organization])
+        .build()
+        .unwrap();
+
+    let mut entries = HashMap::new();
+    entries.insert(
+        compute_pike_organization_address(org_id),
+        organization_list.into_bytes().unwrap(),
+    );
+    context.set_state(entries).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::pike::state::{AgentList, OrganizationList};
+    use crate::protos::FromBytes;
+
+    #[test]
+    // check that the mock context stores, reads and deletes the Pike helpers' state
+    fn check_mock_transaction_context() {
+        let mut context = MockTransactionContext::default();
+        add_agent(&mut context, "agent_key", "org_01", true, vec![]);
+        add_organization(&mut context, "org_01", OrganizationStatus::Active);
+
+        let agent_address = compute_pike_agent_address("agent_key");
+        let bytes = context
+            .get_state(vec![agent_address.clone()])
+            .unwrap()
+            .unwrap();
+        let agents = AgentList::from_bytes(&bytes).unwrap();
+        assert_eq!(agents.agents()[0].org_id(), "org_01");
+
+        let bytes = context
+            .get_state(vec![compute_pike_organization_address("org_01")])
+            .unwrap()
+            .unwrap();
+        let organizations = OrganizationList::from_bytes(&bytes).unwrap();
+        assert_eq!(organizations.organizations()[0].org_id(), "org_01");
+
+        assert!(context.add_receipt_data(b"data").is_ok());
+        assert!(context.add_event("event".into(), vec![], b"data").is_ok());
+
+        assert_eq!(
+            context.delete_state(vec![agent_address.clone()]).unwrap(),
+            Some(vec![agent_address.clone()])
+        );
+        assert_eq!(context.get_state(vec![agent_address]).unwrap(), None);
+    }
+}
@@ -0,0 +1,455 @@
+// Copyright 2019 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error as StdError;
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha512;
+use protobuf::{Message, RepeatedField};
+use rand::Rng;
+
+use crate::protos::batch::{Batch, BatchHeader, BatchList};
+use crate::protos::transaction::{Transaction, TransactionHeader};
+use crate::protos::IntoBytes;
+use crate::signing::{Signer, SigningError};
+
+#[derive(Debug)]
+pub enum TransactionBuildError {
+    MissingField(String),
+    /// The payload or transaction header could not be serialized
+    SerializationError(String),
+    SigningError(SigningError),
+}
+
+impl StdError for TransactionBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            TransactionBuildError::MissingField(ref msg) => msg,
+            TransactionBuildError::SerializationError(ref msg) => msg,
+            TransactionBuildError::SigningError(ref err) => err.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            TransactionBuildError::MissingField(_) => None,
+            TransactionBuildError::SerializationError(_) => None,
+            TransactionBuildError::SigningError(ref err) => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for TransactionBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            TransactionBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+            TransactionBuildError::SerializationError(ref s) => {
+                write!(f, "SerializationError: {}", s)
+            }
+            TransactionBuildError::SigningError(ref err) => write!(f, "SigningError: {}", err),
+        }
+    }
+}
+
+impl From<SigningError> for TransactionBuildError {
+    fn from(err: SigningError) -> Self {
+        TransactionBuildError::SigningError(err)
+    }
+}
+
+/// Builder used to create a signed Transaction from any payload that can be serialized
+#[derive(Clone)]
+pub struct TransactionBuilder<P: IntoBytes> {
+    family_name: Option<String>,
+    family_version: Option<String>,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    dependencies: Vec<String>,
+    nonce: Option<String>,
+    batcher_public_key: Option<String>,
+    payload: Option<P>,
+}
+
+impl<P: IntoBytes> Default for TransactionBuilder<P> {
+    fn default() -> Self {
+        TransactionBuilder {
+            family_name: None,
+            family_version: None,
+            inputs: vec![],
+            outputs: vec![],
+            dependencies: vec![],
+            nonce: None,
+            batcher_public_key: None,
+            payload: None,
+        }
+    }
+}
+
+impl<P: IntoBytes> TransactionBuilder<P> {
+    pub fn new() -> Self {
+        TransactionBuilder::default()
+    }
+
+    pub fn with_family_name(mut self, family_name: String) -> Self {
+        self.family_name = Some(family_name);
+        self
+    }
+
+    pub fn with_family_version(mut self, family_version: String) -> Self {
+        self.family_version = Some(family_version);
+        self
+    }
+
+    /// Sets the addresses the transaction may read from
+    pub fn with_inputs(mut self, inputs: Vec<String>) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Sets the addresses the transaction may write to
+    pub fn with_outputs(mut self, outputs: Vec<String>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    /// Sets the header signatures of transactions that must be committed before this one
+    pub fn with_dependencies(mut self, dependencies: Vec<String>) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Sets the nonce; if not set, a random one is generated
+    pub fn with_nonce(mut self, nonce: String) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Sets the public key of the batch signer; if not set, the transaction signer is expected to
+    /// sign the batch as well
+    pub fn with_batcher_public_key(mut self, batcher_public_key: String) -> Self {
+        self.batcher_public_key = Some(batcher_public_key);
+        self
+    }
+
+    pub fn with_payload(mut self, payload: P) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn build(self, signer: &dyn Signer) -> Result<Transaction, TransactionBuildError> {
+        let family_name = self.family_name.ok_or_else(|| {
+            TransactionBuildError::MissingField("'family_name' field is required".to_string())
+        })?;
+
+        let family_version = self.family_version.ok_or_else(|| {
+            TransactionBuildError::MissingField("'family_version' field is required".to_string())
+        })?;
+
+        let payload_bytes = self
+            .payload
+            .ok_or_else(|| {
+                TransactionBuildError::MissingField("'payload' field is required".to_string())
+            })?
+            .into_bytes()
+            .map_err(|err| TransactionBuildError::SerializationError(err.to_string()))?;
+
+        let nonce = self.nonce.unwrap_or_else(create_nonce);
+
+        let signer_public_key = signer.public_key()?;
+        let batcher_public_key = self
+            .batcher_public_key
+            .unwrap_or_else(|| signer_public_key.clone());
+
+        let mut sha = Sha512::new();
+        sha.input(&payload_bytes);
+
+        let mut header = TransactionHeader::new();
+        header.set_family_name(family_name);
+        header.set_family_version(family_version);
+        header.set_inputs(RepeatedField::from_vec(self.inputs));
+        header.set_outputs(RepeatedField::from_vec(self.outputs));
+        header.set_dependencies(RepeatedField::from_vec(self.dependencies));
+        header.set_nonce(nonce);
+        header.set_signer_public_key(signer_public_key);
+        header.set_batcher_public_key(batcher_public_key);
+        header.set_payload_sha512(sha.result_str());
+
+        let header_bytes = header
+            .write_to_bytes()
+            .map_err(|err| TransactionBuildError::SerializationError(err.to_string()))?;
+
+        let mut transaction = Transaction::new();
+        transaction.set_header_signature(signer.sign(&header_bytes)?);
+        transaction.set_header(header_bytes);
+        transaction.set_payload(payload_bytes);
+
+        Ok(transaction)
+    }
+}
+
+#[derive(Debug)]
+pub enum BatchBuildError {
+    MissingField(String),
+    /// The batch header could not be serialized
+    SerializationError(String),
+    SigningError(SigningError),
+}
+
+impl StdError for BatchBuildError {
+    fn description(&self) -> &str {
+        match *self {
+            BatchBuildError::MissingField(ref msg) => msg,
+            BatchBuildError::SerializationError(ref msg) => msg,
+            BatchBuildError::SigningError(ref err) => err.description(),
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            BatchBuildError::MissingField(_) => None,
+            BatchBuildError::SerializationError(_) => None,
+            BatchBuildError::SigningError(ref err) => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for BatchBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            BatchBuildError::MissingField(ref s) => write!(f, "MissingField: {}", s),
+            BatchBuildError::SerializationError(ref s) => write!(f, "SerializationError: {}", s),
+            BatchBuildError::SigningError(ref err) => write!(f, "SigningError: {}", err),
+        }
+    }
+}
+
+impl From<SigningError> for BatchBuildError {
+    fn from(err: SigningError) -> Self {
+        BatchBuildError::SigningError(err)
+    }
+}
+
+/// Builder used to create a signed Batch of transactions
+#[derive(Default, Clone)]
+pub struct BatchBuilder {
+    transactions: Vec<Transaction>,
+    trace: bool,
+}
+
+impl BatchBuilder {
+    pub fn new() -> Self {
+        BatchBuilder::default()
+    }
+
+    /// Sets the transactions of the batch; they are committed in the given order
+    pub fn with_transactions(mut self, transactions: Vec<Transaction>) -> Self {
+        self.transactions = transactions;
+        self
+    }
+
+    /// Asks the validator to log the batch's progress through the network
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    pub fn build(self, signer: &dyn Signer) -> Result<Batch, BatchBuildError> {
+        if self.transactions.is_empty() {
+            return Err(BatchBuildError::MissingField(
+                "'transactions' field cannot be empty".to_string(),
+            ));
+        }
+
+        let mut header = BatchHeader::new();
+        header.set_signer_public_key(signer.public_key()?);
+        header.set_transaction_ids(RepeatedField::from_vec(
+            self.transactions
+                .iter()
+                .map(|transaction| transaction.get_header_signature().to_string())
+                .collect(),
+        ));
+
+        let header_bytes = header
+            .write_to_bytes()
+            .map_err(|err| BatchBuildError::SerializationError(err.to_string()))?;
+
+        let mut batch = Batch::new();
+        batch.set_header_signature(signer.sign(&header_bytes)?);
+        batch.set_header(header_bytes);
+        batch.set_transactions(RepeatedField::from_vec(self.transactions));
+        batch.set_trace(self.trace);
+
+        Ok(batch)
+    }
+
+    /// Builds the batch and wraps it in a BatchList, ready to be submitted to `/batches`
+    pub fn build_batch_list(self, signer: &dyn Signer) -> Result<BatchList, BatchBuildError> {
+        let mut batch_list = BatchList::new();
+        batch_list.set_batches(RepeatedField::from_vec(vec![self.build(signer)?]));
+        Ok(batch_list)
+    }
+}
+
+/// Returns a random hex nonce, so that identical transactions built at the same time still get
+/// distinct header signatures
+fn create_nonce() -> String {
+    let bytes: [u8; 16] = rand::thread_rng().gen();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::protocol::pike::payload::{
+        Action, CreateOrganizationActionBuilder, PikePayload, PikePayloadBuilder,
+    };
+    use crate::protos::FromBytes;
+
+    /// Signs by hashing the message with the key, which is enough to check what was signed
+    struct HashSigner {
+        key: String,
+    }
+
+    impl Signer for HashSigner {
+        fn sign(&self, message: &[u8]) -> Result<String, SigningError> {
+            let mut sha = Sha512::new();
+            sha.input_str(&self.key);
+            sha.input(message);
+            Ok(sha.result_str())
+        }
+
+        fn public_key(&self) -> Result<String, SigningError> {
+            Ok(self.key.clone())
+        }
+    }
+
+    fn payload() -> PikePayload {
+        let action = CreateOrganizationActionBuilder::new()
+            .with_org_id("org_01".to_string())
+            .with_name("Organization".to_string())
+            .with_address("address".to_string())
+            .build()
+            .unwrap();
+
+        PikePayloadBuilder::new()
+            .with_action(Action::CreateOrganization)
+            .with_create_organization(action)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    // check that a transaction is signed by the signer and carries the payload and its hash
+    fn check_transaction_builder() {
+        let signer = HashSigner {
+            key: "signer".to_string(),
+        };
+        let transaction = TransactionBuilder::new()
+            .with_family_name("pike".to_string())
+            .with_family_version("0.1".to_string())
+            .with_inputs(vec!["cad11d".to_string()])
+            .with_outputs(vec!["cad11d01".to_string()])
+            .with_nonce("nonce".to_string())
+            .with_payload(payload())
+            .build(&signer)
+            .unwrap();
+
+        let header: TransactionHeader =
+            protobuf::parse_from_bytes(transaction.get_header()).unwrap();
+        assert_eq!(header.get_family_name(), "pike");
+        assert_eq!(header.get_family_version(), "0.1");
+        assert_eq!(header.get_inputs(), ["cad11d".to_string()]);
+        assert_eq!(header.get_outputs(), ["cad11d01".to_string()]);
+        assert_eq!(header.get_nonce(), "nonce");
+        assert_eq!(header.get_signer_public_key(), "signer");
+        assert_eq!(header.get_batcher_public_key(), "signer");
+
+        let mut sha = Sha512::new();
+        sha.input(transaction.get_payload());
+        assert_eq!(header.get_payload_sha512(), sha.result_str());
+        assert_eq!(
+            transaction.get_header_signature(),
+            signer.sign(transaction.get_header()).unwrap()
+        );
+
+        let payload = PikePayload::from_bytes(transaction.get_payload()).unwrap();
+        assert_eq!(payload.create_organization().org_id(), "org_01");
+    }
+
+    #[test]
+    // check that a transaction cannot be built without a payload
+    fn check_transaction_builder_missing_payload() {
+        let signer = HashSigner {
+            key: "signer".to_string(),
+        };
+        let result = TransactionBuilder::<PikePayload>::new()
+            .with_family_name("pike".to_string())
+            .with_family_version("0.1".to_string())
+            .build(&signer);
+
+        match result {
+            Err(TransactionBuildError::MissingField(_)) => (),
+            res => panic!("Expected MissingField, got {:?}", res),
+        }
+    }
+
+    #[test]
+    // check that generated nonces are 16 random bytes in hex, distinct between transactions
+    fn check_create_nonce() {
+        let nonce = create_nonce();
+        assert_eq!(nonce.len(), 32);
+        assert!(nonce.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(nonce, create_nonce());
+    }
+
+    #[test]
+    // check that a batch lists its transactions' ids and is signed by the batcher
+    fn check_batch_builder() {
+        let transaction_signer = HashSigner {
+            key: "signer".to_string(),
+        };
+        let batcher = HashSigner {
+            key: "batcher".to_string(),
+        };
+        let transaction = TransactionBuilder::new()
+            .with_family_name("pike".to_string())
+            .with_family_version("0.1".to_string())
+            .with_batcher_public_key("batcher".to_string())
+            .with_payload(payload())
+            .build(&transaction_signer)
+            .unwrap();
+        let transaction_id = transaction.get_header_signature().to_string();
+
+        let batch_list = BatchBuilder::new()
+            .with_transactions(vec![transaction])
+            .build_batch_list(&batcher)
+            .unwrap();
+
+        assert_eq!(batch_list.get_batches().len(), 1);
+        let batch = &batch_list.get_batches()[0];
+        let header: BatchHeader = protobuf::parse_from_bytes(batch.get_header()).unwrap();
+        assert_eq!(header.get_signer_public_key(), "batcher");
+        assert_eq!(header.get_transaction_ids(), [transaction_id]);
+        assert_eq!(
+            batch.get_header_signature(),
+            batcher.sign(batch.get_header()).unwrap()
+        );
+
+        match BatchBuilder::new().build(&batcher) {
+            Err(BatchBuildError::MissingField(_)) => (),
+            res => panic!("Expected MissingField, got {:?}", res),
+        }
+    }
+}